@@ -16,10 +16,12 @@ import (
 type Config struct {
 	spec revm.SpecId
 
-	NoBaseFee         bool
-	hasCompiler       bool
-	thershold         uint64
-	maxConcurrentSize uint
+	NoBaseFee      bool
+	hasCompiler    bool
+	// encoded `CompilerConfig` proto of the AOT compiler, nil keeps the defaults
+	compilerConfig []byte
+	// encoded `ChainConfig` proto of the rules the VM follows, nil keeps the defaults
+	chainConfig    []byte
 }
 
 // VM struct is the core of initiavm.
@@ -32,19 +34,26 @@ type EVM struct {
 }
 
 // NewVM return VM instance
-func NewEVM(blockCtx vm.BlockContext, statedb state.ExtendedStateDB, config Config) EVM {
+func NewEVM(blockCtx vm.BlockContext, statedb state.ExtendedStateDB, config Config) (EVM, error) {
 	var inner revm.EVM
-	if config.hasCompiler {
-		inner = revm.NewEVMWithCompiler(statedb, config.thershold, config.maxConcurrentSize, config.spec)
-	} else {
+	var err error
+	switch {
+	case config.hasCompiler:
+		inner, err = revm.NewEVMWithCompiler(statedb, config.compilerConfig, config.chainConfig, config.spec)
+	case config.chainConfig != nil:
+		inner, err = revm.NewEVMWithChainConfig(statedb, config.chainConfig, config.spec)
+	default:
 		inner = revm.NewEVM(statedb, config.spec)
 	}
+	if err != nil {
+		return EVM{}, err
+	}
 
 	return EVM{
 		Inner:   inner,
 		Context: blockCtx,
 		Config:  config,
-	}
+	}, nil
 }
 
 func (evm *EVM) GetSpecId() revm.SpecId {
@@ -55,8 +64,8 @@ func (evm *EVM) SetTxContext(txCtx vm.TxContext) {
 	evm.TxContext = txCtx
 }
 
-func (evm *EVM) Destroy() {
-	revm.DestroyVM(evm.Inner)
+func (evm *EVM) Destroy() error {
+	return revm.DestroyVM(evm.Inner)
 }
 
 func (evm *EVM) SetBlockHashFn(hashFn vm.GetHashFunc) {
@@ -97,9 +97,20 @@ func (state *ExtendedStateDB) GetStorage(addr []byte, k []byte) []byte {
 }
 
 func (state *ExtendedStateDB) UpdateAndCommit(s []byte, acc []byte, del []byte) (common.Hash, error) {
+	// wipe the destroyed accounts, and the created ones before they are written again
+	var deletedbuf revmtypes.Deleted
+	err := proto.Unmarshal(del, &deletedbuf)
+	if err != nil {
+		return common.Hash{}, err
+	}
+	for _, del := range deletedbuf.GetDeleted() {
+		state.SelfDestruct(common.BytesToAddress(del))
+	}
+	state.Finalise(true)
+
 	// set storages
 	var storagesbuf revmtypes.Storages
-	err := proto.Unmarshal(s, &storagesbuf)
+	err = proto.Unmarshal(s, &storagesbuf)
 	if err != nil {
 		return common.Hash{}, err
 	}
@@ -121,15 +132,6 @@ func (state *ExtendedStateDB) UpdateAndCommit(s []byte, acc []byte, del []byte)
 		state.SetAccount(common.HexToAddress(addr), &stateacc)
 		state.SetCode(common.HexToAddress(addr), code)
 	}
-	// delete contract by self destructed opcodes
-	var deletedbuf revmtypes.Deleted
-	err = proto.Unmarshal(del, &deletedbuf)
-	if err != nil {
-		return common.Hash{}, err
-	}
-	for _, del := range deletedbuf.GetDeleted() {
-		state.SelfDestruct6780(common.BytesToAddress(del))
-	}
 
 	// commit updated codes
 	root, err := state.Commit(state.blockNumber, true)
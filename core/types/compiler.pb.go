@@ -0,0 +1,715 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.35.2
+// 	protoc        v6.31.1
+// source: compiler.proto
+
+package types
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+type OptLevel int32
+
+const (
+	OptLevel_OPT_LEVEL_UNSPECIFIED OptLevel = 0 // OPT_LEVEL_AGGRESSIVE
+	OptLevel_OPT_LEVEL_NONE        OptLevel = 1 // -O0
+	OptLevel_OPT_LEVEL_LESS        OptLevel = 2 // -O1
+	OptLevel_OPT_LEVEL_DEFAULT     OptLevel = 3 // -O2
+	OptLevel_OPT_LEVEL_AGGRESSIVE  OptLevel = 4 // -O3
+)
+
+// Enum value maps for OptLevel.
+var (
+	OptLevel_name = map[int32]string{
+		0: "OPT_LEVEL_UNSPECIFIED",
+		1: "OPT_LEVEL_NONE",
+		2: "OPT_LEVEL_LESS",
+		3: "OPT_LEVEL_DEFAULT",
+		4: "OPT_LEVEL_AGGRESSIVE",
+	}
+	OptLevel_value = map[string]int32{
+		"OPT_LEVEL_UNSPECIFIED": 0,
+		"OPT_LEVEL_NONE":        1,
+		"OPT_LEVEL_LESS":        2,
+		"OPT_LEVEL_DEFAULT":     3,
+		"OPT_LEVEL_AGGRESSIVE":  4,
+	}
+)
+
+func (x OptLevel) Enum() *OptLevel {
+	p := new(OptLevel)
+	*p = x
+	return p
+}
+
+func (x OptLevel) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (OptLevel) Descriptor() protoreflect.EnumDescriptor {
+	return file_compiler_proto_enumTypes[0].Descriptor()
+}
+
+func (OptLevel) Type() protoreflect.EnumType {
+	return &file_compiler_proto_enumTypes[0]
+}
+
+func (x OptLevel) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use OptLevel.Descriptor instead.
+func (OptLevel) EnumDescriptor() ([]byte, []int) {
+	return file_compiler_proto_rawDescGZIP(), []int{0}
+}
+
+// Specs the contracts are compiled for, they are interpreted under the other ones
+type SpecIdPolicy int32
+
+const (
+	SpecIdPolicy_SPEC_ID_POLICY_UNSPECIFIED SpecIdPolicy = 0 // SPEC_ID_POLICY_ANY
+	SpecIdPolicy_SPEC_ID_POLICY_EXACT       SpecIdPolicy = 1 // only `spec_id`
+	SpecIdPolicy_SPEC_ID_POLICY_AT_LEAST    SpecIdPolicy = 2 // `spec_id` and the later ones
+	SpecIdPolicy_SPEC_ID_POLICY_ANY         SpecIdPolicy = 3
+)
+
+// Enum value maps for SpecIdPolicy.
+var (
+	SpecIdPolicy_name = map[int32]string{
+		0: "SPEC_ID_POLICY_UNSPECIFIED",
+		1: "SPEC_ID_POLICY_EXACT",
+		2: "SPEC_ID_POLICY_AT_LEAST",
+		3: "SPEC_ID_POLICY_ANY",
+	}
+	SpecIdPolicy_value = map[string]int32{
+		"SPEC_ID_POLICY_UNSPECIFIED": 0,
+		"SPEC_ID_POLICY_EXACT":       1,
+		"SPEC_ID_POLICY_AT_LEAST":    2,
+		"SPEC_ID_POLICY_ANY":         3,
+	}
+)
+
+func (x SpecIdPolicy) Enum() *SpecIdPolicy {
+	p := new(SpecIdPolicy)
+	*p = x
+	return p
+}
+
+func (x SpecIdPolicy) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (SpecIdPolicy) Descriptor() protoreflect.EnumDescriptor {
+	return file_compiler_proto_enumTypes[1].Descriptor()
+}
+
+func (SpecIdPolicy) Type() protoreflect.EnumType {
+	return &file_compiler_proto_enumTypes[1]
+}
+
+func (x SpecIdPolicy) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use SpecIdPolicy.Descriptor instead.
+func (SpecIdPolicy) EnumDescriptor() ([]byte, []int) {
+	return file_compiler_proto_rawDescGZIP(), []int{1}
+}
+
+// Where a contract is in the AOT compile pipeline, for the spec of the VM
+type CompileStatus int32
+
+const (
+	CompileStatus_COMPILE_STATUS_NOT_SEEN  CompileStatus = 0
+	CompileStatus_COMPILE_STATUS_COUNTING  CompileStatus = 1 // called fewer times than the threshold
+	CompileStatus_COMPILE_STATUS_QUEUED    CompileStatus = 2
+	CompileStatus_COMPILE_STATUS_COMPILING CompileStatus = 3
+	CompileStatus_COMPILE_STATUS_COMPILED  CompileStatus = 4
+	CompileStatus_COMPILE_STATUS_FAILED    CompileStatus = 5
+)
+
+// Enum value maps for CompileStatus.
+var (
+	CompileStatus_name = map[int32]string{
+		0: "COMPILE_STATUS_NOT_SEEN",
+		1: "COMPILE_STATUS_COUNTING",
+		2: "COMPILE_STATUS_QUEUED",
+		3: "COMPILE_STATUS_COMPILING",
+		4: "COMPILE_STATUS_COMPILED",
+		5: "COMPILE_STATUS_FAILED",
+	}
+	CompileStatus_value = map[string]int32{
+		"COMPILE_STATUS_NOT_SEEN":  0,
+		"COMPILE_STATUS_COUNTING":  1,
+		"COMPILE_STATUS_QUEUED":    2,
+		"COMPILE_STATUS_COMPILING": 3,
+		"COMPILE_STATUS_COMPILED":  4,
+		"COMPILE_STATUS_FAILED":    5,
+	}
+)
+
+func (x CompileStatus) Enum() *CompileStatus {
+	p := new(CompileStatus)
+	*p = x
+	return p
+}
+
+func (x CompileStatus) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (CompileStatus) Descriptor() protoreflect.EnumDescriptor {
+	return file_compiler_proto_enumTypes[2].Descriptor()
+}
+
+func (CompileStatus) Type() protoreflect.EnumType {
+	return &file_compiler_proto_enumTypes[2]
+}
+
+func (x CompileStatus) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use CompileStatus.Descriptor instead.
+func (CompileStatus) EnumDescriptor() ([]byte, []int) {
+	return file_compiler_proto_rawDescGZIP(), []int{2}
+}
+
+// Options of the AOT compiler. The default compiles every contract on its first call.
+type CompilerConfig struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Threshold               uint64   `protobuf:"varint,1,opt,name=threshold,proto3" json:"threshold,omitempty"`                                                        // calls after which a contract is compiled
+	MaxConcurrentCompiles   uint64   `protobuf:"varint,2,opt,name=max_concurrent_compiles,json=maxConcurrentCompiles,proto3" json:"max_concurrent_compiles,omitempty"` // at least one
+	StoreDir                string   `protobuf:"bytes,3,opt,name=store_dir,json=storeDir,proto3" json:"store_dir,omitempty"`                                           // ~/.aotstore when empty
+	OptLevel                OptLevel `protobuf:"varint,4,opt,name=opt_level,json=optLevel,proto3,enum=evm.v1.OptLevel" json:"opt_level,omitempty"`
+	DisableGasMetering      bool     `protobuf:"varint,5,opt,name=disable_gas_metering,json=disableGasMetering,proto3" json:"disable_gas_metering,omitempty"`
+	DisableStackBoundChecks bool     `protobuf:"varint,6,opt,name=disable_stack_bound_checks,json=disableStackBoundChecks,proto3" json:"disable_stack_bound_checks,omitempty"`
+	DebugAssertions         bool     `protobuf:"varint,7,opt,name=debug_assertions,json=debugAssertions,proto3" json:"debug_assertions,omitempty"`
+	MaxCodeSize             uint64   `protobuf:"varint,8,opt,name=max_code_size,json=maxCodeSize,proto3" json:"max_code_size,omitempty"` // larger contracts are always interpreted, 0 means no cap
+	// Compilations taking longer are discarded and marked failed, 0 means no timeout. LLVM cannot
+	// be interrupted, so the time is spent nonetheless.
+	CompileTimeoutMs uint64       `protobuf:"varint,9,opt,name=compile_timeout_ms,json=compileTimeoutMs,proto3" json:"compile_timeout_ms,omitempty"`
+	SpecIdPolicy     SpecIdPolicy `protobuf:"varint,10,opt,name=spec_id_policy,json=specIdPolicy,proto3,enum=evm.v1.SpecIdPolicy" json:"spec_id_policy,omitempty"`
+	SpecId           *uint32      `protobuf:"varint,11,opt,name=spec_id,json=specId,proto3,oneof" json:"spec_id,omitempty"` // the spec the VM was created with when unset
+}
+
+func (x *CompilerConfig) Reset() {
+	*x = CompilerConfig{}
+	mi := &file_compiler_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *CompilerConfig) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CompilerConfig) ProtoMessage() {}
+
+func (x *CompilerConfig) ProtoReflect() protoreflect.Message {
+	mi := &file_compiler_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CompilerConfig.ProtoReflect.Descriptor instead.
+func (*CompilerConfig) Descriptor() ([]byte, []int) {
+	return file_compiler_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *CompilerConfig) GetThreshold() uint64 {
+	if x != nil {
+		return x.Threshold
+	}
+	return 0
+}
+
+func (x *CompilerConfig) GetMaxConcurrentCompiles() uint64 {
+	if x != nil {
+		return x.MaxConcurrentCompiles
+	}
+	return 0
+}
+
+func (x *CompilerConfig) GetStoreDir() string {
+	if x != nil {
+		return x.StoreDir
+	}
+	return ""
+}
+
+func (x *CompilerConfig) GetOptLevel() OptLevel {
+	if x != nil {
+		return x.OptLevel
+	}
+	return OptLevel_OPT_LEVEL_UNSPECIFIED
+}
+
+func (x *CompilerConfig) GetDisableGasMetering() bool {
+	if x != nil {
+		return x.DisableGasMetering
+	}
+	return false
+}
+
+func (x *CompilerConfig) GetDisableStackBoundChecks() bool {
+	if x != nil {
+		return x.DisableStackBoundChecks
+	}
+	return false
+}
+
+func (x *CompilerConfig) GetDebugAssertions() bool {
+	if x != nil {
+		return x.DebugAssertions
+	}
+	return false
+}
+
+func (x *CompilerConfig) GetMaxCodeSize() uint64 {
+	if x != nil {
+		return x.MaxCodeSize
+	}
+	return 0
+}
+
+func (x *CompilerConfig) GetCompileTimeoutMs() uint64 {
+	if x != nil {
+		return x.CompileTimeoutMs
+	}
+	return 0
+}
+
+func (x *CompilerConfig) GetSpecIdPolicy() SpecIdPolicy {
+	if x != nil {
+		return x.SpecIdPolicy
+	}
+	return SpecIdPolicy_SPEC_ID_POLICY_UNSPECIFIED
+}
+
+func (x *CompilerConfig) GetSpecId() uint32 {
+	if x != nil && x.SpecId != nil {
+		return *x.SpecId
+	}
+	return 0
+}
+
+type ContractCompileState struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	CodeHash      []byte        `protobuf:"bytes,1,opt,name=code_hash,json=codeHash,proto3" json:"code_hash,omitempty"`
+	Status        CompileStatus `protobuf:"varint,2,opt,name=status,proto3,enum=evm.v1.CompileStatus" json:"status,omitempty"`
+	Calls         uint64        `protobuf:"varint,3,opt,name=calls,proto3" json:"calls,omitempty"`                                        // calls counted against the threshold
+	CompileTimeUs uint64        `protobuf:"varint,4,opt,name=compile_time_us,json=compileTimeUs,proto3" json:"compile_time_us,omitempty"` // set once compiled or failed
+	Error         string        `protobuf:"bytes,5,opt,name=error,proto3" json:"error,omitempty"`                                         // why the compilation failed
+}
+
+func (x *ContractCompileState) Reset() {
+	*x = ContractCompileState{}
+	mi := &file_compiler_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ContractCompileState) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ContractCompileState) ProtoMessage() {}
+
+func (x *ContractCompileState) ProtoReflect() protoreflect.Message {
+	mi := &file_compiler_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ContractCompileState.ProtoReflect.Descriptor instead.
+func (*ContractCompileState) Descriptor() ([]byte, []int) {
+	return file_compiler_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *ContractCompileState) GetCodeHash() []byte {
+	if x != nil {
+		return x.CodeHash
+	}
+	return nil
+}
+
+func (x *ContractCompileState) GetStatus() CompileStatus {
+	if x != nil {
+		return x.Status
+	}
+	return CompileStatus_COMPILE_STATUS_NOT_SEEN
+}
+
+func (x *ContractCompileState) GetCalls() uint64 {
+	if x != nil {
+		return x.Calls
+	}
+	return 0
+}
+
+func (x *ContractCompileState) GetCompileTimeUs() uint64 {
+	if x != nil {
+		return x.CompileTimeUs
+	}
+	return 0
+}
+
+func (x *ContractCompileState) GetError() string {
+	if x != nil {
+		return x.Error
+	}
+	return ""
+}
+
+type ContractCompileStates struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Contracts []*ContractCompileState `protobuf:"bytes,1,rep,name=contracts,proto3" json:"contracts,omitempty"`
+}
+
+func (x *ContractCompileStates) Reset() {
+	*x = ContractCompileStates{}
+	mi := &file_compiler_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ContractCompileStates) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ContractCompileStates) ProtoMessage() {}
+
+func (x *ContractCompileStates) ProtoReflect() protoreflect.Message {
+	mi := &file_compiler_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ContractCompileStates.ProtoReflect.Descriptor instead.
+func (*ContractCompileStates) Descriptor() ([]byte, []int) {
+	return file_compiler_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *ContractCompileStates) GetContracts() []*ContractCompileState {
+	if x != nil {
+		return x.Contracts
+	}
+	return nil
+}
+
+type CompilerStats struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Threshold          uint64 `protobuf:"varint,1,opt,name=threshold,proto3" json:"threshold,omitempty"`
+	QueueLength        uint64 `protobuf:"varint,2,opt,name=queue_length,json=queueLength,proto3" json:"queue_length,omitempty"`
+	Compiling          uint64 `protobuf:"varint,3,opt,name=compiling,proto3" json:"compiling,omitempty"`
+	Compiled           uint64 `protobuf:"varint,4,opt,name=compiled,proto3" json:"compiled,omitempty"` // loaded in memory
+	Failed             uint64 `protobuf:"varint,5,opt,name=failed,proto3" json:"failed,omitempty"`
+	TotalCompileTimeUs uint64 `protobuf:"varint,6,opt,name=total_compile_time_us,json=totalCompileTimeUs,proto3" json:"total_compile_time_us,omitempty"`
+	MaxCompileTimeUs   uint64 `protobuf:"varint,7,opt,name=max_compile_time_us,json=maxCompileTimeUs,proto3" json:"max_compile_time_us,omitempty"`
+	StoreArtifacts     uint64 `protobuf:"varint,8,opt,name=store_artifacts,json=storeArtifacts,proto3" json:"store_artifacts,omitempty"`
+	StoreBytes         uint64 `protobuf:"varint,9,opt,name=store_bytes,json=storeBytes,proto3" json:"store_bytes,omitempty"`
+}
+
+func (x *CompilerStats) Reset() {
+	*x = CompilerStats{}
+	mi := &file_compiler_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *CompilerStats) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CompilerStats) ProtoMessage() {}
+
+func (x *CompilerStats) ProtoReflect() protoreflect.Message {
+	mi := &file_compiler_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CompilerStats.ProtoReflect.Descriptor instead.
+func (*CompilerStats) Descriptor() ([]byte, []int) {
+	return file_compiler_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *CompilerStats) GetThreshold() uint64 {
+	if x != nil {
+		return x.Threshold
+	}
+	return 0
+}
+
+func (x *CompilerStats) GetQueueLength() uint64 {
+	if x != nil {
+		return x.QueueLength
+	}
+	return 0
+}
+
+func (x *CompilerStats) GetCompiling() uint64 {
+	if x != nil {
+		return x.Compiling
+	}
+	return 0
+}
+
+func (x *CompilerStats) GetCompiled() uint64 {
+	if x != nil {
+		return x.Compiled
+	}
+	return 0
+}
+
+func (x *CompilerStats) GetFailed() uint64 {
+	if x != nil {
+		return x.Failed
+	}
+	return 0
+}
+
+func (x *CompilerStats) GetTotalCompileTimeUs() uint64 {
+	if x != nil {
+		return x.TotalCompileTimeUs
+	}
+	return 0
+}
+
+func (x *CompilerStats) GetMaxCompileTimeUs() uint64 {
+	if x != nil {
+		return x.MaxCompileTimeUs
+	}
+	return 0
+}
+
+func (x *CompilerStats) GetStoreArtifacts() uint64 {
+	if x != nil {
+		return x.StoreArtifacts
+	}
+	return 0
+}
+
+func (x *CompilerStats) GetStoreBytes() uint64 {
+	if x != nil {
+		return x.StoreBytes
+	}
+	return 0
+}
+
+var File_compiler_proto protoreflect.FileDescriptor
+
+var file_compiler_proto_rawDesc = []byte{
+	0x0a, 0x0e, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x12, 0x06, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x22, 0x84, 0x04, 0x0a, 0x0e, 0x43, 0x6f, 0x6d,
+	0x70, 0x69, 0x6c, 0x65, 0x72, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x1c, 0x0a, 0x09, 0x74,
+	0x68, 0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09,
+	0x74, 0x68, 0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x12, 0x36, 0x0a, 0x17, 0x6d, 0x61, 0x78,
+	0x5f, 0x63, 0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x5f, 0x63, 0x6f, 0x6d, 0x70,
+	0x69, 0x6c, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x15, 0x6d, 0x61, 0x78, 0x43,
+	0x6f, 0x6e, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65,
+	0x73, 0x12, 0x1b, 0x0a, 0x09, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x5f, 0x64, 0x69, 0x72, 0x18, 0x03,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x44, 0x69, 0x72, 0x12, 0x2d,
+	0x0a, 0x09, 0x6f, 0x70, 0x74, 0x5f, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28,
+	0x0e, 0x32, 0x10, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x4f, 0x70, 0x74, 0x4c, 0x65,
+	0x76, 0x65, 0x6c, 0x52, 0x08, 0x6f, 0x70, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x12, 0x30, 0x0a,
+	0x14, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x6d, 0x65, 0x74,
+	0x65, 0x72, 0x69, 0x6e, 0x67, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x12, 0x64, 0x69, 0x73,
+	0x61, 0x62, 0x6c, 0x65, 0x47, 0x61, 0x73, 0x4d, 0x65, 0x74, 0x65, 0x72, 0x69, 0x6e, 0x67, 0x12,
+	0x3b, 0x0a, 0x1a, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x63, 0x6b,
+	0x5f, 0x62, 0x6f, 0x75, 0x6e, 0x64, 0x5f, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x73, 0x18, 0x06, 0x20,
+	0x01, 0x28, 0x08, 0x52, 0x17, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x53, 0x74, 0x61, 0x63,
+	0x6b, 0x42, 0x6f, 0x75, 0x6e, 0x64, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x73, 0x12, 0x29, 0x0a, 0x10,
+	0x64, 0x65, 0x62, 0x75, 0x67, 0x5f, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74, 0x69, 0x6f, 0x6e, 0x73,
+	0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0f, 0x64, 0x65, 0x62, 0x75, 0x67, 0x41, 0x73, 0x73,
+	0x65, 0x72, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x22, 0x0a, 0x0d, 0x6d, 0x61, 0x78, 0x5f, 0x63,
+	0x6f, 0x64, 0x65, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b,
+	0x6d, 0x61, 0x78, 0x43, 0x6f, 0x64, 0x65, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x2c, 0x0a, 0x12, 0x63,
+	0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x5f, 0x6d,
+	0x73, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x10, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65,
+	0x54, 0x69, 0x6d, 0x65, 0x6f, 0x75, 0x74, 0x4d, 0x73, 0x12, 0x3a, 0x0a, 0x0e, 0x73, 0x70, 0x65,
+	0x63, 0x5f, 0x69, 0x64, 0x5f, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x18, 0x0a, 0x20, 0x01, 0x28,
+	0x0e, 0x32, 0x14, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x70, 0x65, 0x63, 0x49,
+	0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52, 0x0c, 0x73, 0x70, 0x65, 0x63, 0x49, 0x64, 0x50,
+	0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1c, 0x0a, 0x07, 0x73, 0x70, 0x65, 0x63, 0x5f, 0x69, 0x64,
+	0x18, 0x0b, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x00, 0x52, 0x06, 0x73, 0x70, 0x65, 0x63, 0x49, 0x64,
+	0x88, 0x01, 0x01, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x73, 0x70, 0x65, 0x63, 0x5f, 0x69, 0x64, 0x22,
+	0xb6, 0x01, 0x0a, 0x14, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x6f, 0x6d, 0x70,
+	0x69, 0x6c, 0x65, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x63, 0x6f, 0x64, 0x65,
+	0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x6f, 0x64,
+	0x65, 0x48, 0x61, 0x73, 0x68, 0x12, 0x2d, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x15, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x43,
+	0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73, 0x74,
+	0x61, 0x74, 0x75, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x05, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x12, 0x26, 0x0a, 0x0f, 0x63, 0x6f,
+	0x6d, 0x70, 0x69, 0x6c, 0x65, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x5f, 0x75, 0x73, 0x18, 0x04, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x0d, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x54, 0x69, 0x6d, 0x65,
+	0x55, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x53, 0x0a, 0x15, 0x43, 0x6f, 0x6e, 0x74,
+	0x72, 0x61, 0x63, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x53, 0x74, 0x61, 0x74, 0x65,
+	0x73, 0x12, 0x3a, 0x0a, 0x09, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x18, 0x01,
+	0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f,
+	0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x53, 0x74, 0x61,
+	0x74, 0x65, 0x52, 0x09, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x22, 0xce, 0x02,
+	0x0a, 0x0d, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x73, 0x12,
+	0x1c, 0x0a, 0x09, 0x74, 0x68, 0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x04, 0x52, 0x09, 0x74, 0x68, 0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x12, 0x21, 0x0a,
+	0x0c, 0x71, 0x75, 0x65, 0x75, 0x65, 0x5f, 0x6c, 0x65, 0x6e, 0x67, 0x74, 0x68, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x0b, 0x71, 0x75, 0x65, 0x75, 0x65, 0x4c, 0x65, 0x6e, 0x67, 0x74, 0x68,
+	0x12, 0x1c, 0x0a, 0x09, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x69, 0x6e, 0x67, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x09, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x69, 0x6e, 0x67, 0x12, 0x1a,
+	0x0a, 0x08, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04,
+	0x52, 0x08, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x66, 0x61,
+	0x69, 0x6c, 0x65, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x06, 0x66, 0x61, 0x69, 0x6c,
+	0x65, 0x64, 0x12, 0x31, 0x0a, 0x15, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x5f, 0x63, 0x6f, 0x6d, 0x70,
+	0x69, 0x6c, 0x65, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x5f, 0x75, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28,
+	0x04, 0x52, 0x12, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x54,
+	0x69, 0x6d, 0x65, 0x55, 0x73, 0x12, 0x2d, 0x0a, 0x13, 0x6d, 0x61, 0x78, 0x5f, 0x63, 0x6f, 0x6d,
+	0x70, 0x69, 0x6c, 0x65, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x5f, 0x75, 0x73, 0x18, 0x07, 0x20, 0x01,
+	0x28, 0x04, 0x52, 0x10, 0x6d, 0x61, 0x78, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x54, 0x69,
+	0x6d, 0x65, 0x55, 0x73, 0x12, 0x27, 0x0a, 0x0f, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x5f, 0x61, 0x72,
+	0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x73, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0e, 0x73,
+	0x74, 0x6f, 0x72, 0x65, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x73, 0x12, 0x1f, 0x0a,
+	0x0b, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x09, 0x20, 0x01,
+	0x28, 0x04, 0x52, 0x0a, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x42, 0x79, 0x74, 0x65, 0x73, 0x2a, 0x7e,
+	0x0a, 0x08, 0x4f, 0x70, 0x74, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x12, 0x19, 0x0a, 0x15, 0x4f, 0x50,
+	0x54, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46,
+	0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x12, 0x0a, 0x0e, 0x4f, 0x50, 0x54, 0x5f, 0x4c, 0x45, 0x56,
+	0x45, 0x4c, 0x5f, 0x4e, 0x4f, 0x4e, 0x45, 0x10, 0x01, 0x12, 0x12, 0x0a, 0x0e, 0x4f, 0x50, 0x54,
+	0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x5f, 0x4c, 0x45, 0x53, 0x53, 0x10, 0x02, 0x12, 0x15, 0x0a,
+	0x11, 0x4f, 0x50, 0x54, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x5f, 0x44, 0x45, 0x46, 0x41, 0x55,
+	0x4c, 0x54, 0x10, 0x03, 0x12, 0x18, 0x0a, 0x14, 0x4f, 0x50, 0x54, 0x5f, 0x4c, 0x45, 0x56, 0x45,
+	0x4c, 0x5f, 0x41, 0x47, 0x47, 0x52, 0x45, 0x53, 0x53, 0x49, 0x56, 0x45, 0x10, 0x04, 0x2a, 0x7d,
+	0x0a, 0x0c, 0x53, 0x70, 0x65, 0x63, 0x49, 0x64, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1e,
+	0x0a, 0x1a, 0x53, 0x50, 0x45, 0x43, 0x5f, 0x49, 0x44, 0x5f, 0x50, 0x4f, 0x4c, 0x49, 0x43, 0x59,
+	0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x18,
+	0x0a, 0x14, 0x53, 0x50, 0x45, 0x43, 0x5f, 0x49, 0x44, 0x5f, 0x50, 0x4f, 0x4c, 0x49, 0x43, 0x59,
+	0x5f, 0x45, 0x58, 0x41, 0x43, 0x54, 0x10, 0x01, 0x12, 0x1b, 0x0a, 0x17, 0x53, 0x50, 0x45, 0x43,
+	0x5f, 0x49, 0x44, 0x5f, 0x50, 0x4f, 0x4c, 0x49, 0x43, 0x59, 0x5f, 0x41, 0x54, 0x5f, 0x4c, 0x45,
+	0x41, 0x53, 0x54, 0x10, 0x02, 0x12, 0x16, 0x0a, 0x12, 0x53, 0x50, 0x45, 0x43, 0x5f, 0x49, 0x44,
+	0x5f, 0x50, 0x4f, 0x4c, 0x49, 0x43, 0x59, 0x5f, 0x41, 0x4e, 0x59, 0x10, 0x03, 0x2a, 0xba, 0x01,
+	0x0a, 0x0d, 0x43, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12,
+	0x1b, 0x0a, 0x17, 0x43, 0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x45, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55,
+	0x53, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x53, 0x45, 0x45, 0x4e, 0x10, 0x00, 0x12, 0x1b, 0x0a, 0x17,
+	0x43, 0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x45, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x43,
+	0x4f, 0x55, 0x4e, 0x54, 0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x4f, 0x4d,
+	0x50, 0x49, 0x4c, 0x45, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x51, 0x55, 0x45, 0x55,
+	0x45, 0x44, 0x10, 0x02, 0x12, 0x1c, 0x0a, 0x18, 0x43, 0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x45, 0x5f,
+	0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x43, 0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x49, 0x4e, 0x47,
+	0x10, 0x03, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x45, 0x5f, 0x53, 0x54,
+	0x41, 0x54, 0x55, 0x53, 0x5f, 0x43, 0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x04, 0x12,
+	0x19, 0x0a, 0x15, 0x43, 0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x45, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55,
+	0x53, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x05, 0x42, 0x0a, 0x5a, 0x08, 0x2e, 0x2e,
+	0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+}
+
+var (
+	file_compiler_proto_rawDescOnce sync.Once
+	file_compiler_proto_rawDescData = file_compiler_proto_rawDesc
+)
+
+func file_compiler_proto_rawDescGZIP() []byte {
+	file_compiler_proto_rawDescOnce.Do(func() {
+		file_compiler_proto_rawDescData = protoimpl.X.CompressGZIP(file_compiler_proto_rawDescData)
+	})
+	return file_compiler_proto_rawDescData
+}
+
+var file_compiler_proto_enumTypes = make([]protoimpl.EnumInfo, 3)
+var file_compiler_proto_msgTypes = make([]protoimpl.MessageInfo, 4)
+var file_compiler_proto_goTypes = []any{
+	(OptLevel)(0),                 // 0: evm.v1.OptLevel
+	(SpecIdPolicy)(0),             // 1: evm.v1.SpecIdPolicy
+	(CompileStatus)(0),            // 2: evm.v1.CompileStatus
+	(*CompilerConfig)(nil),        // 3: evm.v1.CompilerConfig
+	(*ContractCompileState)(nil),  // 4: evm.v1.ContractCompileState
+	(*ContractCompileStates)(nil), // 5: evm.v1.ContractCompileStates
+	(*CompilerStats)(nil),         // 6: evm.v1.CompilerStats
+}
+var file_compiler_proto_depIdxs = []int32{
+	0, // 0: evm.v1.CompilerConfig.opt_level:type_name -> evm.v1.OptLevel
+	1, // 1: evm.v1.CompilerConfig.spec_id_policy:type_name -> evm.v1.SpecIdPolicy
+	2, // 2: evm.v1.ContractCompileState.status:type_name -> evm.v1.CompileStatus
+	4, // 3: evm.v1.ContractCompileStates.contracts:type_name -> evm.v1.ContractCompileState
+	4, // [4:4] is the sub-list for method output_type
+	4, // [4:4] is the sub-list for method input_type
+	4, // [4:4] is the sub-list for extension type_name
+	4, // [4:4] is the sub-list for extension extendee
+	0, // [0:4] is the sub-list for field type_name
+}
+
+func init() { file_compiler_proto_init() }
+func file_compiler_proto_init() {
+	if File_compiler_proto != nil {
+		return
+	}
+	file_compiler_proto_msgTypes[0].OneofWrappers = []any{}
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: file_compiler_proto_rawDesc,
+			NumEnums:      3,
+			NumMessages:   4,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_compiler_proto_goTypes,
+		DependencyIndexes: file_compiler_proto_depIdxs,
+		EnumInfos:         file_compiler_proto_enumTypes,
+		MessageInfos:      file_compiler_proto_msgTypes,
+	}.Build()
+	File_compiler_proto = out.File
+	file_compiler_proto_rawDesc = nil
+	file_compiler_proto_goTypes = nil
+	file_compiler_proto_depIdxs = nil
+}
@@ -0,0 +1,417 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.35.2
+// 	protoc        v6.31.1
+// source: precompile.proto
+
+package types
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Passed to the callback of a custom precompile
+type PrecompileCall struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Address  []byte `protobuf:"bytes,1,opt,name=address,proto3" json:"address,omitempty"` // 20 bytes, the address of the precompile
+	Caller   []byte `protobuf:"bytes,2,opt,name=caller,proto3" json:"caller,omitempty"`   // 20 bytes
+	Value    []byte `protobuf:"bytes,3,opt,name=value,proto3" json:"value,omitempty"`     // big-endian, already transferred to the precompile
+	Input    []byte `protobuf:"bytes,4,opt,name=input,proto3" json:"input,omitempty"`
+	GasLimit uint64 `protobuf:"varint,5,opt,name=gas_limit,json=gasLimit,proto3" json:"gas_limit,omitempty"`
+	IsStatic bool   `protobuf:"varint,6,opt,name=is_static,json=isStatic,proto3" json:"is_static,omitempty"` // state changes are rejected
+}
+
+func (x *PrecompileCall) Reset() {
+	*x = PrecompileCall{}
+	mi := &file_precompile_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *PrecompileCall) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PrecompileCall) ProtoMessage() {}
+
+func (x *PrecompileCall) ProtoReflect() protoreflect.Message {
+	mi := &file_precompile_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PrecompileCall.ProtoReflect.Descriptor instead.
+func (*PrecompileCall) Descriptor() ([]byte, []int) {
+	return file_precompile_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *PrecompileCall) GetAddress() []byte {
+	if x != nil {
+		return x.Address
+	}
+	return nil
+}
+
+func (x *PrecompileCall) GetCaller() []byte {
+	if x != nil {
+		return x.Caller
+	}
+	return nil
+}
+
+func (x *PrecompileCall) GetValue() []byte {
+	if x != nil {
+		return x.Value
+	}
+	return nil
+}
+
+func (x *PrecompileCall) GetInput() []byte {
+	if x != nil {
+		return x.Input
+	}
+	return nil
+}
+
+func (x *PrecompileCall) GetGasLimit() uint64 {
+	if x != nil {
+		return x.GasLimit
+	}
+	return 0
+}
+
+func (x *PrecompileCall) GetIsStatic() bool {
+	if x != nil {
+		return x.IsStatic
+	}
+	return false
+}
+
+// Returned by the callback of a custom precompile. The callback only sees the state committed to
+// the state db, not the changes made by the transaction before the call.
+type PrecompileOutput struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Output  []byte `protobuf:"bytes,1,opt,name=output,proto3" json:"output,omitempty"`                   // returned, or revert data when reverted
+	GasUsed uint64 `protobuf:"varint,2,opt,name=gas_used,json=gasUsed,proto3" json:"gas_used,omitempty"` // above the gas limit, the call runs out of gas
+	// The call reverts with `output` as revert data and returns its unused gas, its state changes
+	// are dropped
+	Reverted bool `protobuf:"varint,3,opt,name=reverted,proto3" json:"reverted,omitempty"`
+	// State changes of the call, journaled with the changes of the transaction
+	Transfers []*Transfer     `protobuf:"bytes,4,rep,name=transfers,proto3" json:"transfers,omitempty"`
+	Storage   []*StorageWrite `protobuf:"bytes,5,rep,name=storage,proto3" json:"storage,omitempty"`
+	// The call halts with a precompile error and consumes all of its gas, like a standard
+	// precompile failing, its state changes are dropped. Takes precedence over `reverted`.
+	Failed bool `protobuf:"varint,6,opt,name=failed,proto3" json:"failed,omitempty"`
+}
+
+func (x *PrecompileOutput) Reset() {
+	*x = PrecompileOutput{}
+	mi := &file_precompile_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *PrecompileOutput) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PrecompileOutput) ProtoMessage() {}
+
+func (x *PrecompileOutput) ProtoReflect() protoreflect.Message {
+	mi := &file_precompile_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PrecompileOutput.ProtoReflect.Descriptor instead.
+func (*PrecompileOutput) Descriptor() ([]byte, []int) {
+	return file_precompile_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *PrecompileOutput) GetOutput() []byte {
+	if x != nil {
+		return x.Output
+	}
+	return nil
+}
+
+func (x *PrecompileOutput) GetGasUsed() uint64 {
+	if x != nil {
+		return x.GasUsed
+	}
+	return 0
+}
+
+func (x *PrecompileOutput) GetReverted() bool {
+	if x != nil {
+		return x.Reverted
+	}
+	return false
+}
+
+func (x *PrecompileOutput) GetTransfers() []*Transfer {
+	if x != nil {
+		return x.Transfers
+	}
+	return nil
+}
+
+func (x *PrecompileOutput) GetStorage() []*StorageWrite {
+	if x != nil {
+		return x.Storage
+	}
+	return nil
+}
+
+func (x *PrecompileOutput) GetFailed() bool {
+	if x != nil {
+		return x.Failed
+	}
+	return false
+}
+
+type Transfer struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	From  []byte `protobuf:"bytes,1,opt,name=from,proto3" json:"from,omitempty"`   // 20 bytes
+	To    []byte `protobuf:"bytes,2,opt,name=to,proto3" json:"to,omitempty"`       // 20 bytes
+	Value []byte `protobuf:"bytes,3,opt,name=value,proto3" json:"value,omitempty"` // big-endian
+}
+
+func (x *Transfer) Reset() {
+	*x = Transfer{}
+	mi := &file_precompile_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *Transfer) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Transfer) ProtoMessage() {}
+
+func (x *Transfer) ProtoReflect() protoreflect.Message {
+	mi := &file_precompile_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Transfer.ProtoReflect.Descriptor instead.
+func (*Transfer) Descriptor() ([]byte, []int) {
+	return file_precompile_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *Transfer) GetFrom() []byte {
+	if x != nil {
+		return x.From
+	}
+	return nil
+}
+
+func (x *Transfer) GetTo() []byte {
+	if x != nil {
+		return x.To
+	}
+	return nil
+}
+
+func (x *Transfer) GetValue() []byte {
+	if x != nil {
+		return x.Value
+	}
+	return nil
+}
+
+type StorageWrite struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Address []byte `protobuf:"bytes,1,opt,name=address,proto3" json:"address,omitempty"` // 20 bytes
+	Key     []byte `protobuf:"bytes,2,opt,name=key,proto3" json:"key,omitempty"`         // big-endian
+	Value   []byte `protobuf:"bytes,3,opt,name=value,proto3" json:"value,omitempty"`     // big-endian
+}
+
+func (x *StorageWrite) Reset() {
+	*x = StorageWrite{}
+	mi := &file_precompile_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *StorageWrite) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StorageWrite) ProtoMessage() {}
+
+func (x *StorageWrite) ProtoReflect() protoreflect.Message {
+	mi := &file_precompile_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StorageWrite.ProtoReflect.Descriptor instead.
+func (*StorageWrite) Descriptor() ([]byte, []int) {
+	return file_precompile_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *StorageWrite) GetAddress() []byte {
+	if x != nil {
+		return x.Address
+	}
+	return nil
+}
+
+func (x *StorageWrite) GetKey() []byte {
+	if x != nil {
+		return x.Key
+	}
+	return nil
+}
+
+func (x *StorageWrite) GetValue() []byte {
+	if x != nil {
+		return x.Value
+	}
+	return nil
+}
+
+var File_precompile_proto protoreflect.FileDescriptor
+
+var file_precompile_proto_rawDesc = []byte{
+	0x0a, 0x10, 0x70, 0x72, 0x65, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x12, 0x06, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x22, 0xa8, 0x01, 0x0a, 0x0e, 0x50,
+	0x72, 0x65, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x43, 0x61, 0x6c, 0x6c, 0x12, 0x18, 0x0a,
+	0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07,
+	0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x61, 0x6c, 0x6c, 0x65,
+	0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x12,
+	0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05,
+	0x76, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x18, 0x04,
+	0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x67,
+	0x61, 0x73, 0x5f, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08,
+	0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x69, 0x73, 0x5f, 0x73,
+	0x74, 0x61, 0x74, 0x69, 0x63, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x08, 0x69, 0x73, 0x53,
+	0x74, 0x61, 0x74, 0x69, 0x63, 0x22, 0xd9, 0x01, 0x0a, 0x10, 0x50, 0x72, 0x65, 0x63, 0x6f, 0x6d,
+	0x70, 0x69, 0x6c, 0x65, 0x4f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x75,
+	0x74, 0x70, 0x75, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x6f, 0x75, 0x74, 0x70,
+	0x75, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x67, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x1a, 0x0a,
+	0x08, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52,
+	0x08, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x65, 0x64, 0x12, 0x2e, 0x0a, 0x09, 0x74, 0x72, 0x61,
+	0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x65,
+	0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x09,
+	0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x2e, 0x0a, 0x07, 0x73, 0x74, 0x6f,
+	0x72, 0x61, 0x67, 0x65, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x65, 0x76, 0x6d,
+	0x2e, 0x76, 0x31, 0x2e, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65,
+	0x52, 0x07, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x66, 0x61, 0x69,
+	0x6c, 0x65, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x66, 0x61, 0x69, 0x6c, 0x65,
+	0x64, 0x22, 0x44, 0x0a, 0x08, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x12, 0x0a,
+	0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f,
+	0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x02, 0x74,
+	0x6f, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c,
+	0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x50, 0x0a, 0x0c, 0x53, 0x74, 0x6f, 0x72, 0x61,
+	0x67, 0x65, 0x57, 0x72, 0x69, 0x74, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65,
+	0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+	0x73, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03,
+	0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x42, 0x0a, 0x5a, 0x08, 0x2e, 0x2e, 0x2f,
+	0x74, 0x79, 0x70, 0x65, 0x73, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+}
+
+var (
+	file_precompile_proto_rawDescOnce sync.Once
+	file_precompile_proto_rawDescData = file_precompile_proto_rawDesc
+)
+
+func file_precompile_proto_rawDescGZIP() []byte {
+	file_precompile_proto_rawDescOnce.Do(func() {
+		file_precompile_proto_rawDescData = protoimpl.X.CompressGZIP(file_precompile_proto_rawDescData)
+	})
+	return file_precompile_proto_rawDescData
+}
+
+var file_precompile_proto_msgTypes = make([]protoimpl.MessageInfo, 4)
+var file_precompile_proto_goTypes = []any{
+	(*PrecompileCall)(nil),   // 0: evm.v1.PrecompileCall
+	(*PrecompileOutput)(nil), // 1: evm.v1.PrecompileOutput
+	(*Transfer)(nil),         // 2: evm.v1.Transfer
+	(*StorageWrite)(nil),     // 3: evm.v1.StorageWrite
+}
+var file_precompile_proto_depIdxs = []int32{
+	2, // 0: evm.v1.PrecompileOutput.transfers:type_name -> evm.v1.Transfer
+	3, // 1: evm.v1.PrecompileOutput.storage:type_name -> evm.v1.StorageWrite
+	2, // [2:2] is the sub-list for method output_type
+	2, // [2:2] is the sub-list for method input_type
+	2, // [2:2] is the sub-list for extension type_name
+	2, // [2:2] is the sub-list for extension extendee
+	0, // [0:2] is the sub-list for field type_name
+}
+
+func init() { file_precompile_proto_init() }
+func file_precompile_proto_init() {
+	if File_precompile_proto != nil {
+		return
+	}
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: file_precompile_proto_rawDesc,
+			NumEnums:      0,
+			NumMessages:   4,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_precompile_proto_goTypes,
+		DependencyIndexes: file_precompile_proto_depIdxs,
+		MessageInfos:      file_precompile_proto_msgTypes,
+	}.Build()
+	File_precompile_proto = out.File
+	file_precompile_proto_rawDesc = nil
+	file_precompile_proto_goTypes = nil
+	file_precompile_proto_depIdxs = nil
+}
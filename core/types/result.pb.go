@@ -1,7 +1,7 @@
 // Code generated by protoc-gen-go. DO NOT EDIT.
 // versions:
 // 	protoc-gen-go v1.35.2
-// 	protoc        v5.29.1
+// 	protoc        v6.31.1
 // source: result.proto
 
 package types
@@ -202,6 +202,292 @@ func (SuccessReasonEnum) EnumDescriptor() ([]byte, []int) {
 	return file_result_proto_rawDescGZIP(), []int{1}
 }
 
+type ErrorKind int32
+
+const (
+	ErrorKind_ERROR_KIND_UNSPECIFIED         ErrorKind = 0
+	ErrorKind_ERROR_KIND_INVALID_TRANSACTION ErrorKind = 1 // see InvalidTransactionReason
+	ErrorKind_ERROR_KIND_INVALID_HEADER      ErrorKind = 2 // see InvalidHeaderReason
+	ErrorKind_ERROR_KIND_DATABASE            ErrorKind = 3 // see BackendErrorKind
+	ErrorKind_ERROR_KIND_CUSTOM              ErrorKind = 4
+	ErrorKind_ERROR_KIND_PRECOMPILE          ErrorKind = 5
+)
+
+// Enum value maps for ErrorKind.
+var (
+	ErrorKind_name = map[int32]string{
+		0: "ERROR_KIND_UNSPECIFIED",
+		1: "ERROR_KIND_INVALID_TRANSACTION",
+		2: "ERROR_KIND_INVALID_HEADER",
+		3: "ERROR_KIND_DATABASE",
+		4: "ERROR_KIND_CUSTOM",
+		5: "ERROR_KIND_PRECOMPILE",
+	}
+	ErrorKind_value = map[string]int32{
+		"ERROR_KIND_UNSPECIFIED":         0,
+		"ERROR_KIND_INVALID_TRANSACTION": 1,
+		"ERROR_KIND_INVALID_HEADER":      2,
+		"ERROR_KIND_DATABASE":            3,
+		"ERROR_KIND_CUSTOM":              4,
+		"ERROR_KIND_PRECOMPILE":          5,
+	}
+)
+
+func (x ErrorKind) Enum() *ErrorKind {
+	p := new(ErrorKind)
+	*p = x
+	return p
+}
+
+func (x ErrorKind) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ErrorKind) Descriptor() protoreflect.EnumDescriptor {
+	return file_result_proto_enumTypes[2].Descriptor()
+}
+
+func (ErrorKind) Type() protoreflect.EnumType {
+	return &file_result_proto_enumTypes[2]
+}
+
+func (x ErrorKind) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ErrorKind.Descriptor instead.
+func (ErrorKind) EnumDescriptor() ([]byte, []int) {
+	return file_result_proto_rawDescGZIP(), []int{2}
+}
+
+type InvalidTransactionReason int32
+
+const (
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_UNSPECIFIED                         InvalidTransactionReason = 0
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_PRIORITY_FEE_GREATER_THAN_MAX_FEE   InvalidTransactionReason = 1
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_GAS_PRICE_LESS_THAN_BASEFEE         InvalidTransactionReason = 2
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_CALLER_GAS_LIMIT_MORE_THAN_BLOCK    InvalidTransactionReason = 3
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_CALL_GAS_COST_MORE_THAN_GAS_LIMIT   InvalidTransactionReason = 4
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_REJECT_CALLER_WITH_CODE             InvalidTransactionReason = 5
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_LACK_OF_FUND_FOR_MAX_FEE            InvalidTransactionReason = 6 // fee, balance
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_OVERFLOW_PAYMENT_IN_TRANSACTION     InvalidTransactionReason = 7
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_NONCE_OVERFLOW_IN_TRANSACTION       InvalidTransactionReason = 8
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_NONCE_TOO_HIGH                      InvalidTransactionReason = 9  // tx_nonce, state_nonce
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_NONCE_TOO_LOW                       InvalidTransactionReason = 10 // tx_nonce, state_nonce
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_CREATE_INIT_CODE_SIZE_LIMIT         InvalidTransactionReason = 11
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_INVALID_CHAIN_ID                    InvalidTransactionReason = 12
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_ACCESS_LIST_NOT_SUPPORTED           InvalidTransactionReason = 13
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_MAX_FEE_PER_BLOB_GAS_NOT_SUPPORTED  InvalidTransactionReason = 14
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_BLOB_VERSIONED_HASHES_NOT_SUPPORTED InvalidTransactionReason = 15
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_BLOB_GAS_PRICE_GREATER_THAN_MAX     InvalidTransactionReason = 16
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_EMPTY_BLOBS                         InvalidTransactionReason = 17
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_BLOB_CREATE_TRANSACTION             InvalidTransactionReason = 18
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_TOO_MANY_BLOBS                      InvalidTransactionReason = 19 // max_blobs, have_blobs
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_BLOB_VERSION_NOT_SUPPORTED          InvalidTransactionReason = 20
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_EOF_CREATE_SHOULD_HAVE_TO_ADDRESS   InvalidTransactionReason = 21
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_AUTHORIZATION_LIST_NOT_SUPPORTED    InvalidTransactionReason = 22
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_AUTHORIZATION_LIST_INVALID_FIELDS   InvalidTransactionReason = 23
+	InvalidTransactionReason_INVALID_TRANSACTION_REASON_EMPTY_AUTHORIZATION_LIST            InvalidTransactionReason = 24
+)
+
+// Enum value maps for InvalidTransactionReason.
+var (
+	InvalidTransactionReason_name = map[int32]string{
+		0:  "INVALID_TRANSACTION_REASON_UNSPECIFIED",
+		1:  "INVALID_TRANSACTION_REASON_PRIORITY_FEE_GREATER_THAN_MAX_FEE",
+		2:  "INVALID_TRANSACTION_REASON_GAS_PRICE_LESS_THAN_BASEFEE",
+		3:  "INVALID_TRANSACTION_REASON_CALLER_GAS_LIMIT_MORE_THAN_BLOCK",
+		4:  "INVALID_TRANSACTION_REASON_CALL_GAS_COST_MORE_THAN_GAS_LIMIT",
+		5:  "INVALID_TRANSACTION_REASON_REJECT_CALLER_WITH_CODE",
+		6:  "INVALID_TRANSACTION_REASON_LACK_OF_FUND_FOR_MAX_FEE",
+		7:  "INVALID_TRANSACTION_REASON_OVERFLOW_PAYMENT_IN_TRANSACTION",
+		8:  "INVALID_TRANSACTION_REASON_NONCE_OVERFLOW_IN_TRANSACTION",
+		9:  "INVALID_TRANSACTION_REASON_NONCE_TOO_HIGH",
+		10: "INVALID_TRANSACTION_REASON_NONCE_TOO_LOW",
+		11: "INVALID_TRANSACTION_REASON_CREATE_INIT_CODE_SIZE_LIMIT",
+		12: "INVALID_TRANSACTION_REASON_INVALID_CHAIN_ID",
+		13: "INVALID_TRANSACTION_REASON_ACCESS_LIST_NOT_SUPPORTED",
+		14: "INVALID_TRANSACTION_REASON_MAX_FEE_PER_BLOB_GAS_NOT_SUPPORTED",
+		15: "INVALID_TRANSACTION_REASON_BLOB_VERSIONED_HASHES_NOT_SUPPORTED",
+		16: "INVALID_TRANSACTION_REASON_BLOB_GAS_PRICE_GREATER_THAN_MAX",
+		17: "INVALID_TRANSACTION_REASON_EMPTY_BLOBS",
+		18: "INVALID_TRANSACTION_REASON_BLOB_CREATE_TRANSACTION",
+		19: "INVALID_TRANSACTION_REASON_TOO_MANY_BLOBS",
+		20: "INVALID_TRANSACTION_REASON_BLOB_VERSION_NOT_SUPPORTED",
+		21: "INVALID_TRANSACTION_REASON_EOF_CREATE_SHOULD_HAVE_TO_ADDRESS",
+		22: "INVALID_TRANSACTION_REASON_AUTHORIZATION_LIST_NOT_SUPPORTED",
+		23: "INVALID_TRANSACTION_REASON_AUTHORIZATION_LIST_INVALID_FIELDS",
+		24: "INVALID_TRANSACTION_REASON_EMPTY_AUTHORIZATION_LIST",
+	}
+	InvalidTransactionReason_value = map[string]int32{
+		"INVALID_TRANSACTION_REASON_UNSPECIFIED":                         0,
+		"INVALID_TRANSACTION_REASON_PRIORITY_FEE_GREATER_THAN_MAX_FEE":   1,
+		"INVALID_TRANSACTION_REASON_GAS_PRICE_LESS_THAN_BASEFEE":         2,
+		"INVALID_TRANSACTION_REASON_CALLER_GAS_LIMIT_MORE_THAN_BLOCK":    3,
+		"INVALID_TRANSACTION_REASON_CALL_GAS_COST_MORE_THAN_GAS_LIMIT":   4,
+		"INVALID_TRANSACTION_REASON_REJECT_CALLER_WITH_CODE":             5,
+		"INVALID_TRANSACTION_REASON_LACK_OF_FUND_FOR_MAX_FEE":            6,
+		"INVALID_TRANSACTION_REASON_OVERFLOW_PAYMENT_IN_TRANSACTION":     7,
+		"INVALID_TRANSACTION_REASON_NONCE_OVERFLOW_IN_TRANSACTION":       8,
+		"INVALID_TRANSACTION_REASON_NONCE_TOO_HIGH":                      9,
+		"INVALID_TRANSACTION_REASON_NONCE_TOO_LOW":                       10,
+		"INVALID_TRANSACTION_REASON_CREATE_INIT_CODE_SIZE_LIMIT":         11,
+		"INVALID_TRANSACTION_REASON_INVALID_CHAIN_ID":                    12,
+		"INVALID_TRANSACTION_REASON_ACCESS_LIST_NOT_SUPPORTED":           13,
+		"INVALID_TRANSACTION_REASON_MAX_FEE_PER_BLOB_GAS_NOT_SUPPORTED":  14,
+		"INVALID_TRANSACTION_REASON_BLOB_VERSIONED_HASHES_NOT_SUPPORTED": 15,
+		"INVALID_TRANSACTION_REASON_BLOB_GAS_PRICE_GREATER_THAN_MAX":     16,
+		"INVALID_TRANSACTION_REASON_EMPTY_BLOBS":                         17,
+		"INVALID_TRANSACTION_REASON_BLOB_CREATE_TRANSACTION":             18,
+		"INVALID_TRANSACTION_REASON_TOO_MANY_BLOBS":                      19,
+		"INVALID_TRANSACTION_REASON_BLOB_VERSION_NOT_SUPPORTED":          20,
+		"INVALID_TRANSACTION_REASON_EOF_CREATE_SHOULD_HAVE_TO_ADDRESS":   21,
+		"INVALID_TRANSACTION_REASON_AUTHORIZATION_LIST_NOT_SUPPORTED":    22,
+		"INVALID_TRANSACTION_REASON_AUTHORIZATION_LIST_INVALID_FIELDS":   23,
+		"INVALID_TRANSACTION_REASON_EMPTY_AUTHORIZATION_LIST":            24,
+	}
+)
+
+func (x InvalidTransactionReason) Enum() *InvalidTransactionReason {
+	p := new(InvalidTransactionReason)
+	*p = x
+	return p
+}
+
+func (x InvalidTransactionReason) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (InvalidTransactionReason) Descriptor() protoreflect.EnumDescriptor {
+	return file_result_proto_enumTypes[3].Descriptor()
+}
+
+func (InvalidTransactionReason) Type() protoreflect.EnumType {
+	return &file_result_proto_enumTypes[3]
+}
+
+func (x InvalidTransactionReason) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use InvalidTransactionReason.Descriptor instead.
+func (InvalidTransactionReason) EnumDescriptor() ([]byte, []int) {
+	return file_result_proto_rawDescGZIP(), []int{3}
+}
+
+type InvalidHeaderReason int32
+
+const (
+	InvalidHeaderReason_INVALID_HEADER_REASON_UNSPECIFIED             InvalidHeaderReason = 0
+	InvalidHeaderReason_INVALID_HEADER_REASON_PREVRANDAO_NOT_SET      InvalidHeaderReason = 1
+	InvalidHeaderReason_INVALID_HEADER_REASON_EXCESS_BLOB_GAS_NOT_SET InvalidHeaderReason = 2
+)
+
+// Enum value maps for InvalidHeaderReason.
+var (
+	InvalidHeaderReason_name = map[int32]string{
+		0: "INVALID_HEADER_REASON_UNSPECIFIED",
+		1: "INVALID_HEADER_REASON_PREVRANDAO_NOT_SET",
+		2: "INVALID_HEADER_REASON_EXCESS_BLOB_GAS_NOT_SET",
+	}
+	InvalidHeaderReason_value = map[string]int32{
+		"INVALID_HEADER_REASON_UNSPECIFIED":             0,
+		"INVALID_HEADER_REASON_PREVRANDAO_NOT_SET":      1,
+		"INVALID_HEADER_REASON_EXCESS_BLOB_GAS_NOT_SET": 2,
+	}
+)
+
+func (x InvalidHeaderReason) Enum() *InvalidHeaderReason {
+	p := new(InvalidHeaderReason)
+	*p = x
+	return p
+}
+
+func (x InvalidHeaderReason) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (InvalidHeaderReason) Descriptor() protoreflect.EnumDescriptor {
+	return file_result_proto_enumTypes[4].Descriptor()
+}
+
+func (InvalidHeaderReason) Type() protoreflect.EnumType {
+	return &file_result_proto_enumTypes[4]
+}
+
+func (x InvalidHeaderReason) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use InvalidHeaderReason.Descriptor instead.
+func (InvalidHeaderReason) EnumDescriptor() ([]byte, []int) {
+	return file_result_proto_rawDescGZIP(), []int{4}
+}
+
+type BackendErrorKind int32
+
+const (
+	BackendErrorKind_BACKEND_ERROR_KIND_UNSPECIFIED      BackendErrorKind = 0
+	BackendErrorKind_BACKEND_ERROR_KIND_FOREIGN_PANIC    BackendErrorKind = 1
+	BackendErrorKind_BACKEND_ERROR_KIND_BAD_ARGUMENT     BackendErrorKind = 2
+	BackendErrorKind_BACKEND_ERROR_KIND_INVALID_UTF8     BackendErrorKind = 3
+	BackendErrorKind_BACKEND_ERROR_KIND_UNIMPLEMENTED    BackendErrorKind = 4
+	BackendErrorKind_BACKEND_ERROR_KIND_UNKNOWN          BackendErrorKind = 5
+	BackendErrorKind_BACKEND_ERROR_KIND_USER             BackendErrorKind = 6
+	BackendErrorKind_BACKEND_ERROR_KIND_UNREACHABLE_CALL BackendErrorKind = 7
+)
+
+// Enum value maps for BackendErrorKind.
+var (
+	BackendErrorKind_name = map[int32]string{
+		0: "BACKEND_ERROR_KIND_UNSPECIFIED",
+		1: "BACKEND_ERROR_KIND_FOREIGN_PANIC",
+		2: "BACKEND_ERROR_KIND_BAD_ARGUMENT",
+		3: "BACKEND_ERROR_KIND_INVALID_UTF8",
+		4: "BACKEND_ERROR_KIND_UNIMPLEMENTED",
+		5: "BACKEND_ERROR_KIND_UNKNOWN",
+		6: "BACKEND_ERROR_KIND_USER",
+		7: "BACKEND_ERROR_KIND_UNREACHABLE_CALL",
+	}
+	BackendErrorKind_value = map[string]int32{
+		"BACKEND_ERROR_KIND_UNSPECIFIED":      0,
+		"BACKEND_ERROR_KIND_FOREIGN_PANIC":    1,
+		"BACKEND_ERROR_KIND_BAD_ARGUMENT":     2,
+		"BACKEND_ERROR_KIND_INVALID_UTF8":     3,
+		"BACKEND_ERROR_KIND_UNIMPLEMENTED":    4,
+		"BACKEND_ERROR_KIND_UNKNOWN":          5,
+		"BACKEND_ERROR_KIND_USER":             6,
+		"BACKEND_ERROR_KIND_UNREACHABLE_CALL": 7,
+	}
+)
+
+func (x BackendErrorKind) Enum() *BackendErrorKind {
+	p := new(BackendErrorKind)
+	*p = x
+	return p
+}
+
+func (x BackendErrorKind) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (BackendErrorKind) Descriptor() protoreflect.EnumDescriptor {
+	return file_result_proto_enumTypes[5].Descriptor()
+}
+
+func (BackendErrorKind) Type() protoreflect.EnumType {
+	return &file_result_proto_enumTypes[5]
+}
+
+func (x BackendErrorKind) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use BackendErrorKind.Descriptor instead.
+func (BackendErrorKind) EnumDescriptor() ([]byte, []int) {
+	return file_result_proto_rawDescGZIP(), []int{5}
+}
+
 type Topic struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
@@ -713,12 +999,23 @@ func (x *Halt) GetGasUsed() uint64 {
 	return 0
 }
 
+// Transaction which could not be executed. Only the fields of the reason are set.
 type Error struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Message string `protobuf:"bytes,1,opt,name=message,proto3" json:"message,omitempty"`
+	Message     string                   `protobuf:"bytes,1,opt,name=message,proto3" json:"message,omitempty"` // human readable, not meant to be matched
+	Kind        ErrorKind                `protobuf:"varint,2,opt,name=kind,proto3,enum=evm.v1.ErrorKind" json:"kind,omitempty"`
+	Transaction InvalidTransactionReason `protobuf:"varint,3,opt,name=transaction,proto3,enum=evm.v1.InvalidTransactionReason" json:"transaction,omitempty"`
+	Header      InvalidHeaderReason      `protobuf:"varint,4,opt,name=header,proto3,enum=evm.v1.InvalidHeaderReason" json:"header,omitempty"`
+	Database    BackendErrorKind         `protobuf:"varint,5,opt,name=database,proto3,enum=evm.v1.BackendErrorKind" json:"database,omitempty"`
+	Fee         []byte                   `protobuf:"bytes,6,opt,name=fee,proto3" json:"fee,omitempty"`         // 32 bytes
+	Balance     []byte                   `protobuf:"bytes,7,opt,name=balance,proto3" json:"balance,omitempty"` // 32 bytes
+	TxNonce     uint64                   `protobuf:"varint,8,opt,name=tx_nonce,json=txNonce,proto3" json:"tx_nonce,omitempty"`
+	StateNonce  uint64                   `protobuf:"varint,9,opt,name=state_nonce,json=stateNonce,proto3" json:"state_nonce,omitempty"`
+	MaxBlobs    uint64                   `protobuf:"varint,10,opt,name=max_blobs,json=maxBlobs,proto3" json:"max_blobs,omitempty"`
+	HaveBlobs   uint64                   `protobuf:"varint,11,opt,name=have_blobs,json=haveBlobs,proto3" json:"have_blobs,omitempty"`
 }
 
 func (x *Error) Reset() {
@@ -758,6 +1055,194 @@ func (x *Error) GetMessage() string {
 	return ""
 }
 
+func (x *Error) GetKind() ErrorKind {
+	if x != nil {
+		return x.Kind
+	}
+	return ErrorKind_ERROR_KIND_UNSPECIFIED
+}
+
+func (x *Error) GetTransaction() InvalidTransactionReason {
+	if x != nil {
+		return x.Transaction
+	}
+	return InvalidTransactionReason_INVALID_TRANSACTION_REASON_UNSPECIFIED
+}
+
+func (x *Error) GetHeader() InvalidHeaderReason {
+	if x != nil {
+		return x.Header
+	}
+	return InvalidHeaderReason_INVALID_HEADER_REASON_UNSPECIFIED
+}
+
+func (x *Error) GetDatabase() BackendErrorKind {
+	if x != nil {
+		return x.Database
+	}
+	return BackendErrorKind_BACKEND_ERROR_KIND_UNSPECIFIED
+}
+
+func (x *Error) GetFee() []byte {
+	if x != nil {
+		return x.Fee
+	}
+	return nil
+}
+
+func (x *Error) GetBalance() []byte {
+	if x != nil {
+		return x.Balance
+	}
+	return nil
+}
+
+func (x *Error) GetTxNonce() uint64 {
+	if x != nil {
+		return x.TxNonce
+	}
+	return 0
+}
+
+func (x *Error) GetStateNonce() uint64 {
+	if x != nil {
+		return x.StateNonce
+	}
+	return 0
+}
+
+func (x *Error) GetMaxBlobs() uint64 {
+	if x != nil {
+		return x.MaxBlobs
+	}
+	return 0
+}
+
+func (x *Error) GetHaveBlobs() uint64 {
+	if x != nil {
+		return x.HaveBlobs
+	}
+	return 0
+}
+
+// Receipt of an executed transaction. The logs are the ones of the Success result.
+type Receipt struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	TxType            uint32 `protobuf:"varint,1,opt,name=tx_type,json=txType,proto3" json:"tx_type,omitempty"`                                    // EIP-2718 type
+	Status            bool   `protobuf:"varint,2,opt,name=status,proto3" json:"status,omitempty"`                                                  // true when the transaction succeeded
+	CumulativeGasUsed uint64 `protobuf:"varint,3,opt,name=cumulative_gas_used,json=cumulativeGasUsed,proto3" json:"cumulative_gas_used,omitempty"` // gas used by the block up to and including this transaction
+	GasUsed           uint64 `protobuf:"varint,4,opt,name=gas_used,json=gasUsed,proto3" json:"gas_used,omitempty"`
+	LogsBloom         []byte `protobuf:"bytes,5,opt,name=logs_bloom,json=logsBloom,proto3" json:"logs_bloom,omitempty"`                           // 256 bytes
+	EffectiveGasPrice []byte `protobuf:"bytes,6,opt,name=effective_gas_price,json=effectiveGasPrice,proto3" json:"effective_gas_price,omitempty"` // 32 bytes
+	BlobGasUsed       uint64 `protobuf:"varint,7,opt,name=blob_gas_used,json=blobGasUsed,proto3" json:"blob_gas_used,omitempty"`
+	BlobGasPrice      []byte `protobuf:"bytes,8,opt,name=blob_gas_price,json=blobGasPrice,proto3" json:"blob_gas_price,omitempty"`        // 32 bytes, empty for transactions without blobs
+	ContractAddress   []byte `protobuf:"bytes,9,opt,name=contract_address,json=contractAddress,proto3" json:"contract_address,omitempty"` // 20 bytes, empty unless the transaction created a contract
+	Encoded           []byte `protobuf:"bytes,10,opt,name=encoded,proto3" json:"encoded,omitempty"`                                       // EIP-2718 encoding of the receipt, the leaf of the receipts root
+}
+
+func (x *Receipt) Reset() {
+	*x = Receipt{}
+	mi := &file_result_proto_msgTypes[10]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *Receipt) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Receipt) ProtoMessage() {}
+
+func (x *Receipt) ProtoReflect() protoreflect.Message {
+	mi := &file_result_proto_msgTypes[10]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Receipt.ProtoReflect.Descriptor instead.
+func (*Receipt) Descriptor() ([]byte, []int) {
+	return file_result_proto_rawDescGZIP(), []int{10}
+}
+
+func (x *Receipt) GetTxType() uint32 {
+	if x != nil {
+		return x.TxType
+	}
+	return 0
+}
+
+func (x *Receipt) GetStatus() bool {
+	if x != nil {
+		return x.Status
+	}
+	return false
+}
+
+func (x *Receipt) GetCumulativeGasUsed() uint64 {
+	if x != nil {
+		return x.CumulativeGasUsed
+	}
+	return 0
+}
+
+func (x *Receipt) GetGasUsed() uint64 {
+	if x != nil {
+		return x.GasUsed
+	}
+	return 0
+}
+
+func (x *Receipt) GetLogsBloom() []byte {
+	if x != nil {
+		return x.LogsBloom
+	}
+	return nil
+}
+
+func (x *Receipt) GetEffectiveGasPrice() []byte {
+	if x != nil {
+		return x.EffectiveGasPrice
+	}
+	return nil
+}
+
+func (x *Receipt) GetBlobGasUsed() uint64 {
+	if x != nil {
+		return x.BlobGasUsed
+	}
+	return 0
+}
+
+func (x *Receipt) GetBlobGasPrice() []byte {
+	if x != nil {
+		return x.BlobGasPrice
+	}
+	return nil
+}
+
+func (x *Receipt) GetContractAddress() []byte {
+	if x != nil {
+		return x.ContractAddress
+	}
+	return nil
+}
+
+func (x *Receipt) GetEncoded() []byte {
+	if x != nil {
+		return x.Encoded
+	}
+	return nil
+}
+
 type EvmResult struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
@@ -769,12 +1254,15 @@ type EvmResult struct {
 	//	*EvmResult_Revert
 	//	*EvmResult_Halt
 	//	*EvmResult_Error
-	Result isEvmResult_Result `protobuf_oneof:"result"`
+	Result    isEvmResult_Result `protobuf_oneof:"result"`
+	Receipt   *Receipt           `protobuf:"bytes,5,opt,name=receipt,proto3" json:"receipt,omitempty"`     // set when the transaction was executed
+	Commit    *CommitResult      `protobuf:"bytes,6,opt,name=commit,proto3" json:"commit,omitempty"`       // set when the state changes were committed
+	Changeset *StateChangeset    `protobuf:"bytes,7,opt,name=changeset,proto3" json:"changeset,omitempty"` // set when the state changes were returned instead of committed
 }
 
 func (x *EvmResult) Reset() {
 	*x = EvmResult{}
-	mi := &file_result_proto_msgTypes[10]
+	mi := &file_result_proto_msgTypes[11]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -786,7 +1274,7 @@ func (x *EvmResult) String() string {
 func (*EvmResult) ProtoMessage() {}
 
 func (x *EvmResult) ProtoReflect() protoreflect.Message {
-	mi := &file_result_proto_msgTypes[10]
+	mi := &file_result_proto_msgTypes[11]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -799,7 +1287,7 @@ func (x *EvmResult) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use EvmResult.ProtoReflect.Descriptor instead.
 func (*EvmResult) Descriptor() ([]byte, []int) {
-	return file_result_proto_rawDescGZIP(), []int{10}
+	return file_result_proto_rawDescGZIP(), []int{11}
 }
 
 func (m *EvmResult) GetResult() isEvmResult_Result {
@@ -837,6 +1325,27 @@ func (x *EvmResult) GetError() *Error {
 	return nil
 }
 
+func (x *EvmResult) GetReceipt() *Receipt {
+	if x != nil {
+		return x.Receipt
+	}
+	return nil
+}
+
+func (x *EvmResult) GetCommit() *CommitResult {
+	if x != nil {
+		return x.Commit
+	}
+	return nil
+}
+
+func (x *EvmResult) GetChangeset() *StateChangeset {
+	if x != nil {
+		return x.Changeset
+	}
+	return nil
+}
+
 type isEvmResult_Result interface {
 	isEvmResult_Result()
 }
@@ -865,11 +1374,265 @@ func (*EvmResult_Halt) isEvmResult_Result() {}
 
 func (*EvmResult_Error) isEvmResult_Result() {}
 
+type AccessListResult struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	AccessList []*AccessListItem `protobuf:"bytes,1,rep,name=access_list,json=accessList,proto3" json:"access_list,omitempty"`
+	GasUsed    uint64            `protobuf:"varint,2,opt,name=gas_used,json=gasUsed,proto3" json:"gas_used,omitempty"` // gas used by the transaction with the generated access list
+	Result     *EvmResult        `protobuf:"bytes,3,opt,name=result,proto3" json:"result,omitempty"`
+}
+
+func (x *AccessListResult) Reset() {
+	*x = AccessListResult{}
+	mi := &file_result_proto_msgTypes[12]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *AccessListResult) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AccessListResult) ProtoMessage() {}
+
+func (x *AccessListResult) ProtoReflect() protoreflect.Message {
+	mi := &file_result_proto_msgTypes[12]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AccessListResult.ProtoReflect.Descriptor instead.
+func (*AccessListResult) Descriptor() ([]byte, []int) {
+	return file_result_proto_rawDescGZIP(), []int{12}
+}
+
+func (x *AccessListResult) GetAccessList() []*AccessListItem {
+	if x != nil {
+		return x.AccessList
+	}
+	return nil
+}
+
+func (x *AccessListResult) GetGasUsed() uint64 {
+	if x != nil {
+		return x.GasUsed
+	}
+	return 0
+}
+
+func (x *AccessListResult) GetResult() *EvmResult {
+	if x != nil {
+		return x.Result
+	}
+	return nil
+}
+
+type EstimateGasResult struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Gas     uint64     `protobuf:"varint,1,opt,name=gas,proto3" json:"gas,omitempty"`        // zero when the transaction fails with the highest allowed gas limit
+	Failure *EvmResult `protobuf:"bytes,2,opt,name=failure,proto3" json:"failure,omitempty"` // revert or halt with the highest allowed gas limit
+}
+
+func (x *EstimateGasResult) Reset() {
+	*x = EstimateGasResult{}
+	mi := &file_result_proto_msgTypes[13]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *EstimateGasResult) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*EstimateGasResult) ProtoMessage() {}
+
+func (x *EstimateGasResult) ProtoReflect() protoreflect.Message {
+	mi := &file_result_proto_msgTypes[13]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use EstimateGasResult.ProtoReflect.Descriptor instead.
+func (*EstimateGasResult) Descriptor() ([]byte, []int) {
+	return file_result_proto_rawDescGZIP(), []int{13}
+}
+
+func (x *EstimateGasResult) GetGas() uint64 {
+	if x != nil {
+		return x.Gas
+	}
+	return 0
+}
+
+func (x *EstimateGasResult) GetFailure() *EvmResult {
+	if x != nil {
+		return x.Failure
+	}
+	return nil
+}
+
+type TransactionResult struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Result            *EvmResult `protobuf:"bytes,1,opt,name=result,proto3" json:"result,omitempty"`
+	CumulativeGasUsed uint64     `protobuf:"varint,2,opt,name=cumulative_gas_used,json=cumulativeGasUsed,proto3" json:"cumulative_gas_used,omitempty"` // gas used by the block up to and including this transaction
+}
+
+func (x *TransactionResult) Reset() {
+	*x = TransactionResult{}
+	mi := &file_result_proto_msgTypes[14]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TransactionResult) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TransactionResult) ProtoMessage() {}
+
+func (x *TransactionResult) ProtoReflect() protoreflect.Message {
+	mi := &file_result_proto_msgTypes[14]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TransactionResult.ProtoReflect.Descriptor instead.
+func (*TransactionResult) Descriptor() ([]byte, []int) {
+	return file_result_proto_rawDescGZIP(), []int{14}
+}
+
+func (x *TransactionResult) GetResult() *EvmResult {
+	if x != nil {
+		return x.Result
+	}
+	return nil
+}
+
+func (x *TransactionResult) GetCumulativeGasUsed() uint64 {
+	if x != nil {
+		return x.CumulativeGasUsed
+	}
+	return 0
+}
+
+type BlockResult struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Results       []*TransactionResult `protobuf:"bytes,1,rep,name=results,proto3" json:"results,omitempty"` // in execution order
+	GasUsed       uint64               `protobuf:"varint,2,opt,name=gas_used,json=gasUsed,proto3" json:"gas_used,omitempty"`
+	Logs          []*Log               `protobuf:"bytes,3,rep,name=logs,proto3" json:"logs,omitempty"`                            // logs of all transactions, in execution order
+	TotalFees     []byte               `protobuf:"bytes,4,opt,name=total_fees,json=totalFees,proto3" json:"total_fees,omitempty"` // 32 bytes, priority fees paid to the coinbase
+	BlobGasUsed   uint64               `protobuf:"varint,5,opt,name=blob_gas_used,json=blobGasUsed,proto3" json:"blob_gas_used,omitempty"`
+	ExcessBlobGas uint64               `protobuf:"varint,6,opt,name=excess_blob_gas,json=excessBlobGas,proto3" json:"excess_blob_gas,omitempty"` // of the next block, with the blob target of the chain config
+}
+
+func (x *BlockResult) Reset() {
+	*x = BlockResult{}
+	mi := &file_result_proto_msgTypes[15]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *BlockResult) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BlockResult) ProtoMessage() {}
+
+func (x *BlockResult) ProtoReflect() protoreflect.Message {
+	mi := &file_result_proto_msgTypes[15]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BlockResult.ProtoReflect.Descriptor instead.
+func (*BlockResult) Descriptor() ([]byte, []int) {
+	return file_result_proto_rawDescGZIP(), []int{15}
+}
+
+func (x *BlockResult) GetResults() []*TransactionResult {
+	if x != nil {
+		return x.Results
+	}
+	return nil
+}
+
+func (x *BlockResult) GetGasUsed() uint64 {
+	if x != nil {
+		return x.GasUsed
+	}
+	return 0
+}
+
+func (x *BlockResult) GetLogs() []*Log {
+	if x != nil {
+		return x.Logs
+	}
+	return nil
+}
+
+func (x *BlockResult) GetTotalFees() []byte {
+	if x != nil {
+		return x.TotalFees
+	}
+	return nil
+}
+
+func (x *BlockResult) GetBlobGasUsed() uint64 {
+	if x != nil {
+		return x.BlobGasUsed
+	}
+	return 0
+}
+
+func (x *BlockResult) GetExcessBlobGas() uint64 {
+	if x != nil {
+		return x.ExcessBlobGas
+	}
+	return 0
+}
+
 var File_result_proto protoreflect.FileDescriptor
 
 var file_result_proto_rawDesc = []byte{
 	0x0a, 0x0c, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x06,
-	0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x22, 0x1d, 0x0a, 0x05, 0x54, 0x6f, 0x70, 0x69, 0x63, 0x12,
+	0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x1a, 0x0b, 0x73, 0x74, 0x61, 0x74, 0x65, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x1a, 0x11, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x1d, 0x0a, 0x05, 0x54, 0x6f, 0x70, 0x69, 0x63, 0x12,
 	0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05,
 	0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x44, 0x0a, 0x07, 0x4c, 0x6f, 0x67, 0x44, 0x61, 0x74, 0x61,
 	0x12, 0x25, 0x0a, 0x06, 0x74, 0x6f, 0x70, 0x69, 0x63, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
@@ -914,113 +1677,338 @@ var file_result_proto_rawDesc = []byte{
 	0x61, 0x6c, 0x74, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x45, 0x6e, 0x75, 0x6d, 0x52, 0x06, 0x72,
 	0x65, 0x61, 0x73, 0x6f, 0x6e, 0x12, 0x19, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73, 0x65,
 	0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x67, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64,
-	0x22, 0x21, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73,
-	0x73, 0x61, 0x67, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73,
-	0x61, 0x67, 0x65, 0x22, 0xb7, 0x01, 0x0a, 0x09, 0x45, 0x76, 0x6d, 0x52, 0x65, 0x73, 0x75, 0x6c,
-	0x74, 0x12, 0x2b, 0x0a, 0x07, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x75, 0x63, 0x63,
-	0x65, 0x73, 0x73, 0x48, 0x00, 0x52, 0x07, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x12, 0x28,
-	0x0a, 0x06, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e,
-	0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x76, 0x65, 0x72, 0x74, 0x48, 0x00,
-	0x52, 0x06, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x12, 0x22, 0x0a, 0x04, 0x68, 0x61, 0x6c, 0x74,
-	0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e,
-	0x48, 0x61, 0x6c, 0x74, 0x48, 0x00, 0x52, 0x04, 0x68, 0x61, 0x6c, 0x74, 0x12, 0x25, 0x0a, 0x05,
-	0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x65, 0x76,
-	0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72,
-	0x72, 0x6f, 0x72, 0x42, 0x08, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x2a, 0xee, 0x09,
-	0x0a, 0x0e, 0x48, 0x61, 0x6c, 0x74, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x45, 0x6e, 0x75, 0x6d,
-	0x12, 0x2c, 0x0a, 0x28, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f,
-	0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44,
-	0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x25,
-	0x0a, 0x21, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e,
-	0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x42, 0x41,
-	0x53, 0x49, 0x43, 0x10, 0x01, 0x12, 0x2c, 0x0a, 0x28, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45,
-	0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46,
-	0x5f, 0x47, 0x41, 0x53, 0x5f, 0x4d, 0x45, 0x4d, 0x4f, 0x52, 0x59, 0x5f, 0x4c, 0x49, 0x4d, 0x49,
-	0x54, 0x10, 0x02, 0x12, 0x26, 0x0a, 0x22, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53,
-	0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x47,
-	0x41, 0x53, 0x5f, 0x4d, 0x45, 0x4d, 0x4f, 0x52, 0x59, 0x10, 0x03, 0x12, 0x2a, 0x0a, 0x26, 0x48,
-	0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f,
-	0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x50, 0x52, 0x45, 0x43, 0x4f,
-	0x4d, 0x50, 0x49, 0x4c, 0x45, 0x10, 0x04, 0x12, 0x2f, 0x0a, 0x2b, 0x48, 0x41, 0x4c, 0x54, 0x5f,
+	0x22, 0x9b, 0x03, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65,
+	0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73,
+	0x73, 0x61, 0x67, 0x65, 0x12, 0x25, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x0e, 0x32, 0x11, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x72, 0x72, 0x6f,
+	0x72, 0x4b, 0x69, 0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x42, 0x0a, 0x0b, 0x74,
+	0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e,
+	0x32, 0x20, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69,
+	0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x61, 0x73,
+	0x6f, 0x6e, 0x52, 0x0b, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12,
+	0x33, 0x0a, 0x06, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0e, 0x32,
+	0x1b, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
+	0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x52, 0x06, 0x68, 0x65,
+	0x61, 0x64, 0x65, 0x72, 0x12, 0x34, 0x0a, 0x08, 0x64, 0x61, 0x74, 0x61, 0x62, 0x61, 0x73, 0x65,
+	0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x18, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e,
+	0x42, 0x61, 0x63, 0x6b, 0x65, 0x6e, 0x64, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64,
+	0x52, 0x08, 0x64, 0x61, 0x74, 0x61, 0x62, 0x61, 0x73, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x66, 0x65,
+	0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x66, 0x65, 0x65, 0x12, 0x18, 0x0a, 0x07,
+	0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x62,
+	0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x78, 0x5f, 0x6e, 0x6f, 0x6e,
+	0x63, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x74, 0x78, 0x4e, 0x6f, 0x6e, 0x63,
+	0x65, 0x12, 0x1f, 0x0a, 0x0b, 0x73, 0x74, 0x61, 0x74, 0x65, 0x5f, 0x6e, 0x6f, 0x6e, 0x63, 0x65,
+	0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x73, 0x74, 0x61, 0x74, 0x65, 0x4e, 0x6f, 0x6e,
+	0x63, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x61, 0x78, 0x5f, 0x62, 0x6c, 0x6f, 0x62, 0x73, 0x18,
+	0x0a, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x6d, 0x61, 0x78, 0x42, 0x6c, 0x6f, 0x62, 0x73, 0x12,
+	0x1d, 0x0a, 0x0a, 0x68, 0x61, 0x76, 0x65, 0x5f, 0x62, 0x6c, 0x6f, 0x62, 0x73, 0x18, 0x0b, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x09, 0x68, 0x61, 0x76, 0x65, 0x42, 0x6c, 0x6f, 0x62, 0x73, 0x22, 0xe3,
+	0x02, 0x0a, 0x07, 0x52, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78,
+	0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x06, 0x74, 0x78, 0x54,
+	0x79, 0x70, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x08, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x2e, 0x0a, 0x13, 0x63,
+	0x75, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73,
+	0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x11, 0x63, 0x75, 0x6d, 0x75, 0x6c, 0x61,
+	0x74, 0x69, 0x76, 0x65, 0x47, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x19, 0x0a, 0x08, 0x67,
+	0x61, 0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x67,
+	0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x6c, 0x6f, 0x67, 0x73, 0x5f, 0x62,
+	0x6c, 0x6f, 0x6f, 0x6d, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x6c, 0x6f, 0x67, 0x73,
+	0x42, 0x6c, 0x6f, 0x6f, 0x6d, 0x12, 0x2e, 0x0a, 0x13, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x69,
+	0x76, 0x65, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x06, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x11, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x47, 0x61, 0x73,
+	0x50, 0x72, 0x69, 0x63, 0x65, 0x12, 0x22, 0x0a, 0x0d, 0x62, 0x6c, 0x6f, 0x62, 0x5f, 0x67, 0x61,
+	0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c,
+	0x6f, 0x62, 0x47, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x24, 0x0a, 0x0e, 0x62, 0x6c, 0x6f,
+	0x62, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28,
+	0x0c, 0x52, 0x0c, 0x62, 0x6c, 0x6f, 0x62, 0x47, 0x61, 0x73, 0x50, 0x72, 0x69, 0x63, 0x65, 0x12,
+	0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72,
+	0x65, 0x73, 0x73, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+	0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x65, 0x6e,
+	0x63, 0x6f, 0x64, 0x65, 0x64, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x65, 0x6e, 0x63,
+	0x6f, 0x64, 0x65, 0x64, 0x22, 0xc6, 0x02, 0x0a, 0x09, 0x45, 0x76, 0x6d, 0x52, 0x65, 0x73, 0x75,
+	0x6c, 0x74, 0x12, 0x2b, 0x0a, 0x07, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x75, 0x63,
+	0x63, 0x65, 0x73, 0x73, 0x48, 0x00, 0x52, 0x07, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x12,
+	0x28, 0x0a, 0x06, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x0e, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x76, 0x65, 0x72, 0x74, 0x48,
+	0x00, 0x52, 0x06, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x12, 0x22, 0x0a, 0x04, 0x68, 0x61, 0x6c,
+	0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31,
+	0x2e, 0x48, 0x61, 0x6c, 0x74, 0x48, 0x00, 0x52, 0x04, 0x68, 0x61, 0x6c, 0x74, 0x12, 0x25, 0x0a,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x65,
+	0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x12, 0x29, 0x0a, 0x07, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x18,
+	0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x52,
+	0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x52, 0x07, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x12,
+	0x2c, 0x0a, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x14, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52,
+	0x65, 0x73, 0x75, 0x6c, 0x74, 0x52, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x12, 0x34, 0x0a,
+	0x09, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x65, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x16, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x43,
+	0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x65, 0x74, 0x52, 0x09, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65,
+	0x73, 0x65, 0x74, 0x42, 0x08, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x22, 0x91, 0x01,
+	0x0a, 0x10, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x75,
+	0x6c, 0x74, 0x12, 0x37, 0x0a, 0x0b, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x5f, 0x6c, 0x69, 0x73,
+	0x74, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31,
+	0x2e, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x52,
+	0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x67,
+	0x61, 0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x67,
+	0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x29, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e,
+	0x45, 0x76, 0x6d, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x52, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c,
+	0x74, 0x22, 0x52, 0x0a, 0x11, 0x45, 0x73, 0x74, 0x69, 0x6d, 0x61, 0x74, 0x65, 0x47, 0x61, 0x73,
+	0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x67, 0x61, 0x73, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x03, 0x67, 0x61, 0x73, 0x12, 0x2b, 0x0a, 0x07, 0x66, 0x61, 0x69, 0x6c,
+	0x75, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x65, 0x76, 0x6d, 0x2e,
+	0x76, 0x31, 0x2e, 0x45, 0x76, 0x6d, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x52, 0x07, 0x66, 0x61,
+	0x69, 0x6c, 0x75, 0x72, 0x65, 0x22, 0x6e, 0x0a, 0x11, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x29, 0x0a, 0x06, 0x72, 0x65,
+	0x73, 0x75, 0x6c, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x65, 0x76, 0x6d,
+	0x2e, 0x76, 0x31, 0x2e, 0x45, 0x76, 0x6d, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x52, 0x06, 0x72,
+	0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x2e, 0x0a, 0x13, 0x63, 0x75, 0x6d, 0x75, 0x6c, 0x61, 0x74,
+	0x69, 0x76, 0x65, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x04, 0x52, 0x11, 0x63, 0x75, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x47, 0x61,
+	0x73, 0x55, 0x73, 0x65, 0x64, 0x22, 0xe9, 0x01, 0x0a, 0x0b, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x52,
+	0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x33, 0x0a, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73,
+	0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e,
+	0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x75, 0x6c,
+	0x74, 0x52, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x12, 0x19, 0x0a, 0x08, 0x67, 0x61,
+	0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x67, 0x61,
+	0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x1f, 0x0a, 0x04, 0x6c, 0x6f, 0x67, 0x73, 0x18, 0x03, 0x20,
+	0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x65, 0x76, 0x6d, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x67,
+	0x52, 0x04, 0x6c, 0x6f, 0x67, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x5f,
+	0x66, 0x65, 0x65, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x74, 0x6f, 0x74, 0x61,
+	0x6c, 0x46, 0x65, 0x65, 0x73, 0x12, 0x22, 0x0a, 0x0d, 0x62, 0x6c, 0x6f, 0x62, 0x5f, 0x67, 0x61,
+	0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c,
+	0x6f, 0x62, 0x47, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x26, 0x0a, 0x0f, 0x65, 0x78, 0x63,
+	0x65, 0x73, 0x73, 0x5f, 0x62, 0x6c, 0x6f, 0x62, 0x5f, 0x67, 0x61, 0x73, 0x18, 0x06, 0x20, 0x01,
+	0x28, 0x04, 0x52, 0x0d, 0x65, 0x78, 0x63, 0x65, 0x73, 0x73, 0x42, 0x6c, 0x6f, 0x62, 0x47, 0x61,
+	0x73, 0x2a, 0xee, 0x09, 0x0a, 0x0e, 0x48, 0x61, 0x6c, 0x74, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e,
+	0x45, 0x6e, 0x75, 0x6d, 0x12, 0x2c, 0x0a, 0x28, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41,
+	0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
+	0x46, 0x49, 0x45, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44,
+	0x10, 0x00, 0x12, 0x25, 0x0a, 0x21, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f,
+	0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x47, 0x41,
+	0x53, 0x5f, 0x42, 0x41, 0x53, 0x49, 0x43, 0x10, 0x01, 0x12, 0x2c, 0x0a, 0x28, 0x48, 0x41, 0x4c,
+	0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55,
+	0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x4d, 0x45, 0x4d, 0x4f, 0x52, 0x59, 0x5f,
+	0x4c, 0x49, 0x4d, 0x49, 0x54, 0x10, 0x02, 0x12, 0x26, 0x0a, 0x22, 0x48, 0x41, 0x4c, 0x54, 0x5f,
 	0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54, 0x5f,
-	0x4f, 0x46, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x4f,
-	0x50, 0x45, 0x52, 0x41, 0x4e, 0x44, 0x10, 0x05, 0x12, 0x31, 0x0a, 0x2d, 0x48, 0x41, 0x4c, 0x54,
-	0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54,
-	0x5f, 0x4f, 0x46, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x52, 0x45, 0x45, 0x4e, 0x54, 0x52, 0x41, 0x4e,
-	0x43, 0x59, 0x5f, 0x53, 0x45, 0x4e, 0x54, 0x52, 0x59, 0x10, 0x06, 0x12, 0x25, 0x0a, 0x21, 0x48,
+	0x4f, 0x46, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x4d, 0x45, 0x4d, 0x4f, 0x52, 0x59, 0x10, 0x03, 0x12,
+	0x2a, 0x0a, 0x26, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45,
+	0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x50,
+	0x52, 0x45, 0x43, 0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x45, 0x10, 0x04, 0x12, 0x2f, 0x0a, 0x2b, 0x48,
 	0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f,
-	0x4f, 0x50, 0x43, 0x4f, 0x44, 0x45, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44,
-	0x10, 0x07, 0x12, 0x26, 0x0a, 0x22, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f,
-	0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x46,
-	0x45, 0x5f, 0x4f, 0x50, 0x43, 0x4f, 0x44, 0x45, 0x10, 0x08, 0x12, 0x21, 0x0a, 0x1d, 0x48, 0x41,
-	0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x49,
-	0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x4a, 0x55, 0x4d, 0x50, 0x10, 0x09, 0x12, 0x22, 0x0a,
-	0x1e, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55,
-	0x4d, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x41, 0x43, 0x54, 0x49, 0x56, 0x41, 0x54, 0x45, 0x44, 0x10,
-	0x0a, 0x12, 0x24, 0x0a, 0x20, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
-	0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x53, 0x54, 0x41, 0x43, 0x4b, 0x5f, 0x55, 0x4e, 0x44, 0x45,
-	0x52, 0x46, 0x4c, 0x4f, 0x57, 0x10, 0x0b, 0x12, 0x23, 0x0a, 0x1f, 0x48, 0x41, 0x4c, 0x54, 0x5f,
-	0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x53, 0x54, 0x41, 0x43,
-	0x4b, 0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x10, 0x0c, 0x12, 0x22, 0x0a, 0x1e,
+	0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c,
+	0x49, 0x44, 0x5f, 0x4f, 0x50, 0x45, 0x52, 0x41, 0x4e, 0x44, 0x10, 0x05, 0x12, 0x31, 0x0a, 0x2d,
 	0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d,
-	0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x4f, 0x46, 0x46, 0x53, 0x45, 0x54, 0x10, 0x0d,
-	0x12, 0x25, 0x0a, 0x21, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f,
-	0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x5f, 0x43, 0x4f, 0x4c, 0x4c,
-	0x49, 0x53, 0x49, 0x4f, 0x4e, 0x10, 0x0e, 0x12, 0x25, 0x0a, 0x21, 0x48, 0x41, 0x4c, 0x54, 0x5f,
-	0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x50, 0x52, 0x45, 0x43,
-	0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10, 0x0f, 0x12, 0x23,
-	0x0a, 0x1f, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e,
-	0x55, 0x4d, 0x5f, 0x4e, 0x4f, 0x4e, 0x43, 0x45, 0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46, 0x4c, 0x4f,
-	0x57, 0x10, 0x10, 0x12, 0x2f, 0x0a, 0x2b, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53,
-	0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x5f, 0x43,
-	0x4f, 0x4e, 0x54, 0x52, 0x41, 0x43, 0x54, 0x5f, 0x53, 0x49, 0x5a, 0x45, 0x5f, 0x4c, 0x49, 0x4d,
-	0x49, 0x54, 0x10, 0x11, 0x12, 0x35, 0x0a, 0x31, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41,
+	0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x52, 0x45, 0x45, 0x4e,
+	0x54, 0x52, 0x41, 0x4e, 0x43, 0x59, 0x5f, 0x53, 0x45, 0x4e, 0x54, 0x52, 0x59, 0x10, 0x06, 0x12,
+	0x25, 0x0a, 0x21, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45,
+	0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x50, 0x43, 0x4f, 0x44, 0x45, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46,
+	0x4f, 0x55, 0x4e, 0x44, 0x10, 0x07, 0x12, 0x26, 0x0a, 0x22, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52,
+	0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c,
+	0x49, 0x44, 0x5f, 0x46, 0x45, 0x5f, 0x4f, 0x50, 0x43, 0x4f, 0x44, 0x45, 0x10, 0x08, 0x12, 0x21,
+	0x0a, 0x1d, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e,
+	0x55, 0x4d, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x4a, 0x55, 0x4d, 0x50, 0x10,
+	0x09, 0x12, 0x22, 0x0a, 0x1e, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
+	0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x41, 0x43, 0x54, 0x49, 0x56, 0x41,
+	0x54, 0x45, 0x44, 0x10, 0x0a, 0x12, 0x24, 0x0a, 0x20, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45,
+	0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x53, 0x54, 0x41, 0x43, 0x4b, 0x5f,
+	0x55, 0x4e, 0x44, 0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x10, 0x0b, 0x12, 0x23, 0x0a, 0x1f, 0x48,
+	0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f,
+	0x53, 0x54, 0x41, 0x43, 0x4b, 0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x10, 0x0c,
+	0x12, 0x22, 0x0a, 0x1e, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f,
+	0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x4f, 0x46, 0x46, 0x53,
+	0x45, 0x54, 0x10, 0x0d, 0x12, 0x25, 0x0a, 0x21, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41,
 	0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x5f,
-	0x43, 0x4f, 0x4e, 0x54, 0x52, 0x41, 0x43, 0x54, 0x5f, 0x53, 0x54, 0x41, 0x52, 0x54, 0x49, 0x4e,
-	0x47, 0x5f, 0x57, 0x49, 0x54, 0x48, 0x5f, 0x45, 0x46, 0x10, 0x12, 0x12, 0x30, 0x0a, 0x2c, 0x48,
+	0x43, 0x4f, 0x4c, 0x4c, 0x49, 0x53, 0x49, 0x4f, 0x4e, 0x10, 0x0e, 0x12, 0x25, 0x0a, 0x21, 0x48,
 	0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f,
-	0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x5f, 0x49, 0x4e, 0x49, 0x54, 0x5f, 0x43, 0x4f, 0x44, 0x45,
-	0x5f, 0x53, 0x49, 0x5a, 0x45, 0x5f, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x10, 0x13, 0x12, 0x25, 0x0a,
-	0x21, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55,
-	0x4d, 0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x5f, 0x50, 0x41, 0x59, 0x4d, 0x45,
-	0x4e, 0x54, 0x10, 0x14, 0x12, 0x34, 0x0a, 0x30, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41,
-	0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x45, 0x5f, 0x43,
-	0x48, 0x41, 0x4e, 0x47, 0x45, 0x5f, 0x44, 0x55, 0x52, 0x49, 0x4e, 0x47, 0x5f, 0x53, 0x54, 0x41,
-	0x54, 0x49, 0x43, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x10, 0x15, 0x12, 0x33, 0x0a, 0x2f, 0x48, 0x41,
-	0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x43,
-	0x41, 0x4c, 0x4c, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x41, 0x4c, 0x4c, 0x4f, 0x57, 0x45, 0x44, 0x5f,
-	0x49, 0x4e, 0x53, 0x49, 0x44, 0x45, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x49, 0x43, 0x10, 0x16, 0x12,
-	0x21, 0x0a, 0x1d, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45,
-	0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x46, 0x55, 0x4e, 0x44, 0x53,
-	0x10, 0x17, 0x12, 0x22, 0x0a, 0x1e, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f,
-	0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x5f, 0x54, 0x4f, 0x4f, 0x5f,
-	0x44, 0x45, 0x45, 0x50, 0x10, 0x18, 0x12, 0x30, 0x0a, 0x2c, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52,
-	0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x45, 0x4f, 0x46, 0x5f, 0x46,
-	0x55, 0x4e, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x53, 0x54, 0x41, 0x43, 0x4b, 0x5f, 0x4f, 0x56,
-	0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x10, 0x19, 0x12, 0x2b, 0x0a, 0x27, 0x48, 0x41, 0x4c, 0x54,
-	0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x49, 0x4e, 0x56,
-	0x41, 0x4c, 0x49, 0x44, 0x5f, 0x45, 0x58, 0x54, 0x43, 0x41, 0x4c, 0x4c, 0x5f, 0x54, 0x41, 0x52,
-	0x47, 0x45, 0x54, 0x10, 0x1a, 0x12, 0x2a, 0x0a, 0x26, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45,
+	0x50, 0x52, 0x45, 0x43, 0x4f, 0x4d, 0x50, 0x49, 0x4c, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52,
+	0x10, 0x0f, 0x12, 0x23, 0x0a, 0x1f, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f,
+	0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4e, 0x4f, 0x4e, 0x43, 0x45, 0x5f, 0x4f, 0x56, 0x45,
+	0x52, 0x46, 0x4c, 0x4f, 0x57, 0x10, 0x10, 0x12, 0x2f, 0x0a, 0x2b, 0x48, 0x41, 0x4c, 0x54, 0x5f,
+	0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x43, 0x52, 0x45, 0x41,
+	0x54, 0x45, 0x5f, 0x43, 0x4f, 0x4e, 0x54, 0x52, 0x41, 0x43, 0x54, 0x5f, 0x53, 0x49, 0x5a, 0x45,
+	0x5f, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x10, 0x11, 0x12, 0x35, 0x0a, 0x31, 0x48, 0x41, 0x4c, 0x54,
+	0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x43, 0x52, 0x45,
+	0x41, 0x54, 0x45, 0x5f, 0x43, 0x4f, 0x4e, 0x54, 0x52, 0x41, 0x43, 0x54, 0x5f, 0x53, 0x54, 0x41,
+	0x52, 0x54, 0x49, 0x4e, 0x47, 0x5f, 0x57, 0x49, 0x54, 0x48, 0x5f, 0x45, 0x46, 0x10, 0x12, 0x12,
+	0x30, 0x0a, 0x2c, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45,
+	0x4e, 0x55, 0x4d, 0x5f, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x5f, 0x49, 0x4e, 0x49, 0x54, 0x5f,
+	0x43, 0x4f, 0x44, 0x45, 0x5f, 0x53, 0x49, 0x5a, 0x45, 0x5f, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x10,
+	0x13, 0x12, 0x25, 0x0a, 0x21, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
+	0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x5f, 0x50,
+	0x41, 0x59, 0x4d, 0x45, 0x4e, 0x54, 0x10, 0x14, 0x12, 0x34, 0x0a, 0x30, 0x48, 0x41, 0x4c, 0x54,
+	0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x53, 0x54, 0x41,
+	0x54, 0x45, 0x5f, 0x43, 0x48, 0x41, 0x4e, 0x47, 0x45, 0x5f, 0x44, 0x55, 0x52, 0x49, 0x4e, 0x47,
+	0x5f, 0x53, 0x54, 0x41, 0x54, 0x49, 0x43, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x10, 0x15, 0x12, 0x33,
+	0x0a, 0x2f, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e,
+	0x55, 0x4d, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x41, 0x4c, 0x4c, 0x4f,
+	0x57, 0x45, 0x44, 0x5f, 0x49, 0x4e, 0x53, 0x49, 0x44, 0x45, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x49,
+	0x43, 0x10, 0x16, 0x12, 0x21, 0x0a, 0x1d, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53,
+	0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x4f, 0x55, 0x54, 0x5f, 0x4f, 0x46, 0x5f, 0x46,
+	0x55, 0x4e, 0x44, 0x53, 0x10, 0x17, 0x12, 0x22, 0x0a, 0x1e, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52,
+	0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x5f,
+	0x54, 0x4f, 0x4f, 0x5f, 0x44, 0x45, 0x45, 0x50, 0x10, 0x18, 0x12, 0x30, 0x0a, 0x2c, 0x48, 0x41,
+	0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x45,
+	0x4f, 0x46, 0x5f, 0x46, 0x55, 0x4e, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x53, 0x54, 0x41, 0x43,
+	0x4b, 0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x10, 0x19, 0x12, 0x2b, 0x0a, 0x27,
+	0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d,
+	0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x45, 0x58, 0x54, 0x43, 0x41, 0x4c, 0x4c,
+	0x5f, 0x54, 0x41, 0x52, 0x47, 0x45, 0x54, 0x10, 0x1a, 0x12, 0x2a, 0x0a, 0x26, 0x48, 0x41, 0x4c,
+	0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x45, 0x4f,
+	0x46, 0x5f, 0x41, 0x55, 0x58, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46,
+	0x4c, 0x4f, 0x57, 0x10, 0x1b, 0x12, 0x2b, 0x0a, 0x27, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45,
 	0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x45, 0x4f, 0x46, 0x5f, 0x41, 0x55,
-	0x58, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x10,
-	0x1b, 0x12, 0x2b, 0x0a, 0x27, 0x48, 0x41, 0x4c, 0x54, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
-	0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x45, 0x4f, 0x46, 0x5f, 0x41, 0x55, 0x58, 0x5f, 0x44, 0x41,
-	0x54, 0x41, 0x5f, 0x54, 0x4f, 0x4f, 0x5f, 0x53, 0x4d, 0x41, 0x4c, 0x4c, 0x10, 0x1c, 0x2a, 0xca,
-	0x01, 0x0a, 0x11, 0x53, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e,
-	0x45, 0x6e, 0x75, 0x6d, 0x12, 0x23, 0x0a, 0x1f, 0x53, 0x55, 0x43, 0x43, 0x45, 0x53, 0x53, 0x5f,
-	0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x55, 0x4e, 0x53, 0x50,
-	0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x1c, 0x0a, 0x18, 0x53, 0x55, 0x43,
-	0x43, 0x45, 0x53, 0x53, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d,
-	0x5f, 0x53, 0x54, 0x4f, 0x50, 0x10, 0x01, 0x12, 0x1e, 0x0a, 0x1a, 0x53, 0x55, 0x43, 0x43, 0x45,
-	0x53, 0x53, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x52,
-	0x45, 0x54, 0x55, 0x52, 0x4e, 0x10, 0x02, 0x12, 0x25, 0x0a, 0x21, 0x53, 0x55, 0x43, 0x43, 0x45,
-	0x53, 0x53, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x53,
-	0x45, 0x4c, 0x46, 0x5f, 0x44, 0x45, 0x53, 0x54, 0x52, 0x55, 0x43, 0x54, 0x10, 0x03, 0x12, 0x2b,
-	0x0a, 0x27, 0x53, 0x55, 0x43, 0x43, 0x45, 0x53, 0x53, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
-	0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x45, 0x4f, 0x46, 0x5f, 0x52, 0x45, 0x54, 0x55, 0x52, 0x4e,
-	0x5f, 0x43, 0x4f, 0x4e, 0x54, 0x52, 0x41, 0x43, 0x54, 0x10, 0x04, 0x42, 0x0a, 0x5a, 0x08, 0x2e,
-	0x2e, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+	0x58, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x5f, 0x54, 0x4f, 0x4f, 0x5f, 0x53, 0x4d, 0x41, 0x4c, 0x4c,
+	0x10, 0x1c, 0x2a, 0xca, 0x01, 0x0a, 0x11, 0x53, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x52, 0x65,
+	0x61, 0x73, 0x6f, 0x6e, 0x45, 0x6e, 0x75, 0x6d, 0x12, 0x23, 0x0a, 0x1f, 0x53, 0x55, 0x43, 0x43,
+	0x45, 0x53, 0x53, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f,
+	0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x1c, 0x0a,
+	0x18, 0x53, 0x55, 0x43, 0x43, 0x45, 0x53, 0x53, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f,
+	0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x53, 0x54, 0x4f, 0x50, 0x10, 0x01, 0x12, 0x1e, 0x0a, 0x1a, 0x53,
+	0x55, 0x43, 0x43, 0x45, 0x53, 0x53, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e,
+	0x55, 0x4d, 0x5f, 0x52, 0x45, 0x54, 0x55, 0x52, 0x4e, 0x10, 0x02, 0x12, 0x25, 0x0a, 0x21, 0x53,
+	0x55, 0x43, 0x43, 0x45, 0x53, 0x53, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e,
+	0x55, 0x4d, 0x5f, 0x53, 0x45, 0x4c, 0x46, 0x5f, 0x44, 0x45, 0x53, 0x54, 0x52, 0x55, 0x43, 0x54,
+	0x10, 0x03, 0x12, 0x2b, 0x0a, 0x27, 0x53, 0x55, 0x43, 0x43, 0x45, 0x53, 0x53, 0x5f, 0x52, 0x45,
+	0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4e, 0x55, 0x4d, 0x5f, 0x45, 0x4f, 0x46, 0x5f, 0x52, 0x45,
+	0x54, 0x55, 0x52, 0x4e, 0x5f, 0x43, 0x4f, 0x4e, 0x54, 0x52, 0x41, 0x43, 0x54, 0x10, 0x04, 0x2a,
+	0xb5, 0x01, 0x0a, 0x09, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x1a, 0x0a,
+	0x16, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50,
+	0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x22, 0x0a, 0x1e, 0x45, 0x52, 0x52,
+	0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f,
+	0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x10, 0x01, 0x12, 0x1d, 0x0a,
+	0x19, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41,
+	0x4c, 0x49, 0x44, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x10, 0x02, 0x12, 0x17, 0x0a, 0x13,
+	0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x42,
+	0x41, 0x53, 0x45, 0x10, 0x03, 0x12, 0x15, 0x0a, 0x11, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b,
+	0x49, 0x4e, 0x44, 0x5f, 0x43, 0x55, 0x53, 0x54, 0x4f, 0x4d, 0x10, 0x04, 0x12, 0x19, 0x0a, 0x15,
+	0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x50, 0x52, 0x45, 0x43, 0x4f,
+	0x4d, 0x50, 0x49, 0x4c, 0x45, 0x10, 0x05, 0x2a, 0xcd, 0x0b, 0x0a, 0x18, 0x49, 0x6e, 0x76, 0x61,
+	0x6c, 0x69, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65,
+	0x61, 0x73, 0x6f, 0x6e, 0x12, 0x2a, 0x0a, 0x26, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f,
+	0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53,
+	0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00,
+	0x12, 0x40, 0x0a, 0x3c, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e,
+	0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x50,
+	0x52, 0x49, 0x4f, 0x52, 0x49, 0x54, 0x59, 0x5f, 0x46, 0x45, 0x45, 0x5f, 0x47, 0x52, 0x45, 0x41,
+	0x54, 0x45, 0x52, 0x5f, 0x54, 0x48, 0x41, 0x4e, 0x5f, 0x4d, 0x41, 0x58, 0x5f, 0x46, 0x45, 0x45,
+	0x10, 0x01, 0x12, 0x3a, 0x0a, 0x36, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52,
+	0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
+	0x5f, 0x47, 0x41, 0x53, 0x5f, 0x50, 0x52, 0x49, 0x43, 0x45, 0x5f, 0x4c, 0x45, 0x53, 0x53, 0x5f,
+	0x54, 0x48, 0x41, 0x4e, 0x5f, 0x42, 0x41, 0x53, 0x45, 0x46, 0x45, 0x45, 0x10, 0x02, 0x12, 0x3f,
+	0x0a, 0x3b, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41,
+	0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x43, 0x41, 0x4c,
+	0x4c, 0x45, 0x52, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x5f, 0x4d, 0x4f,
+	0x52, 0x45, 0x5f, 0x54, 0x48, 0x41, 0x4e, 0x5f, 0x42, 0x4c, 0x4f, 0x43, 0x4b, 0x10, 0x03, 0x12,
+	0x40, 0x0a, 0x3c, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53,
+	0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x43, 0x41,
+	0x4c, 0x4c, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x43, 0x4f, 0x53, 0x54, 0x5f, 0x4d, 0x4f, 0x52, 0x45,
+	0x5f, 0x54, 0x48, 0x41, 0x4e, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x10,
+	0x04, 0x12, 0x36, 0x0a, 0x32, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41,
+	0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f,
+	0x52, 0x45, 0x4a, 0x45, 0x43, 0x54, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x45, 0x52, 0x5f, 0x57, 0x49,
+	0x54, 0x48, 0x5f, 0x43, 0x4f, 0x44, 0x45, 0x10, 0x05, 0x12, 0x37, 0x0a, 0x33, 0x49, 0x4e, 0x56,
+	0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e,
+	0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x4c, 0x41, 0x43, 0x4b, 0x5f, 0x4f, 0x46, 0x5f,
+	0x46, 0x55, 0x4e, 0x44, 0x5f, 0x46, 0x4f, 0x52, 0x5f, 0x4d, 0x41, 0x58, 0x5f, 0x46, 0x45, 0x45,
+	0x10, 0x06, 0x12, 0x3e, 0x0a, 0x3a, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52,
+	0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
+	0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x5f, 0x50, 0x41, 0x59, 0x4d, 0x45, 0x4e,
+	0x54, 0x5f, 0x49, 0x4e, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e,
+	0x10, 0x07, 0x12, 0x3c, 0x0a, 0x38, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52,
+	0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e,
+	0x5f, 0x4e, 0x4f, 0x4e, 0x43, 0x45, 0x5f, 0x4f, 0x56, 0x45, 0x52, 0x46, 0x4c, 0x4f, 0x57, 0x5f,
+	0x49, 0x4e, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x10, 0x08,
+	0x12, 0x2d, 0x0a, 0x29, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e,
+	0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x4e,
+	0x4f, 0x4e, 0x43, 0x45, 0x5f, 0x54, 0x4f, 0x4f, 0x5f, 0x48, 0x49, 0x47, 0x48, 0x10, 0x09, 0x12,
+	0x2c, 0x0a, 0x28, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53,
+	0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x4e, 0x4f,
+	0x4e, 0x43, 0x45, 0x5f, 0x54, 0x4f, 0x4f, 0x5f, 0x4c, 0x4f, 0x57, 0x10, 0x0a, 0x12, 0x3a, 0x0a,
+	0x36, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43,
+	0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x43, 0x52, 0x45, 0x41,
+	0x54, 0x45, 0x5f, 0x49, 0x4e, 0x49, 0x54, 0x5f, 0x43, 0x4f, 0x44, 0x45, 0x5f, 0x53, 0x49, 0x5a,
+	0x45, 0x5f, 0x4c, 0x49, 0x4d, 0x49, 0x54, 0x10, 0x0b, 0x12, 0x2f, 0x0a, 0x2b, 0x49, 0x4e, 0x56,
+	0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e,
+	0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f,
+	0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x49, 0x44, 0x10, 0x0c, 0x12, 0x38, 0x0a, 0x34, 0x49, 0x4e,
+	0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f,
+	0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x41, 0x43, 0x43, 0x45, 0x53, 0x53, 0x5f,
+	0x4c, 0x49, 0x53, 0x54, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x53, 0x55, 0x50, 0x50, 0x4f, 0x52, 0x54,
+	0x45, 0x44, 0x10, 0x0d, 0x12, 0x41, 0x0a, 0x3d, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f,
+	0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53,
+	0x4f, 0x4e, 0x5f, 0x4d, 0x41, 0x58, 0x5f, 0x46, 0x45, 0x45, 0x5f, 0x50, 0x45, 0x52, 0x5f, 0x42,
+	0x4c, 0x4f, 0x42, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x53, 0x55, 0x50, 0x50,
+	0x4f, 0x52, 0x54, 0x45, 0x44, 0x10, 0x0e, 0x12, 0x42, 0x0a, 0x3e, 0x49, 0x4e, 0x56, 0x41, 0x4c,
+	0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52,
+	0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x42, 0x4c, 0x4f, 0x42, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49,
+	0x4f, 0x4e, 0x45, 0x44, 0x5f, 0x48, 0x41, 0x53, 0x48, 0x45, 0x53, 0x5f, 0x4e, 0x4f, 0x54, 0x5f,
+	0x53, 0x55, 0x50, 0x50, 0x4f, 0x52, 0x54, 0x45, 0x44, 0x10, 0x0f, 0x12, 0x3e, 0x0a, 0x3a, 0x49,
+	0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49,
+	0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x42, 0x4c, 0x4f, 0x42, 0x5f, 0x47,
+	0x41, 0x53, 0x5f, 0x50, 0x52, 0x49, 0x43, 0x45, 0x5f, 0x47, 0x52, 0x45, 0x41, 0x54, 0x45, 0x52,
+	0x5f, 0x54, 0x48, 0x41, 0x4e, 0x5f, 0x4d, 0x41, 0x58, 0x10, 0x10, 0x12, 0x2a, 0x0a, 0x26, 0x49,
+	0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49,
+	0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4d, 0x50, 0x54, 0x59, 0x5f,
+	0x42, 0x4c, 0x4f, 0x42, 0x53, 0x10, 0x11, 0x12, 0x36, 0x0a, 0x32, 0x49, 0x4e, 0x56, 0x41, 0x4c,
+	0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52,
+	0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x42, 0x4c, 0x4f, 0x42, 0x5f, 0x43, 0x52, 0x45, 0x41, 0x54,
+	0x45, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x10, 0x12, 0x12,
+	0x2d, 0x0a, 0x29, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53,
+	0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x54, 0x4f,
+	0x4f, 0x5f, 0x4d, 0x41, 0x4e, 0x59, 0x5f, 0x42, 0x4c, 0x4f, 0x42, 0x53, 0x10, 0x13, 0x12, 0x39,
+	0x0a, 0x35, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41,
+	0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x42, 0x4c, 0x4f,
+	0x42, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x53, 0x55,
+	0x50, 0x50, 0x4f, 0x52, 0x54, 0x45, 0x44, 0x10, 0x14, 0x12, 0x40, 0x0a, 0x3c, 0x49, 0x4e, 0x56,
+	0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e,
+	0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4f, 0x46, 0x5f, 0x43, 0x52, 0x45, 0x41,
+	0x54, 0x45, 0x5f, 0x53, 0x48, 0x4f, 0x55, 0x4c, 0x44, 0x5f, 0x48, 0x41, 0x56, 0x45, 0x5f, 0x54,
+	0x4f, 0x5f, 0x41, 0x44, 0x44, 0x52, 0x45, 0x53, 0x53, 0x10, 0x15, 0x12, 0x3f, 0x0a, 0x3b, 0x49,
+	0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54, 0x49,
+	0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x41, 0x55, 0x54, 0x48, 0x4f, 0x52,
+	0x49, 0x5a, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x4c, 0x49, 0x53, 0x54, 0x5f, 0x4e, 0x4f, 0x54,
+	0x5f, 0x53, 0x55, 0x50, 0x50, 0x4f, 0x52, 0x54, 0x45, 0x44, 0x10, 0x16, 0x12, 0x40, 0x0a, 0x3c,
+	0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54,
+	0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x41, 0x55, 0x54, 0x48, 0x4f,
+	0x52, 0x49, 0x5a, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x4c, 0x49, 0x53, 0x54, 0x5f, 0x49, 0x4e,
+	0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x53, 0x10, 0x17, 0x12, 0x37,
+	0x0a, 0x33, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41,
+	0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x4d, 0x50,
+	0x54, 0x59, 0x5f, 0x41, 0x55, 0x54, 0x48, 0x4f, 0x52, 0x49, 0x5a, 0x41, 0x54, 0x49, 0x4f, 0x4e,
+	0x5f, 0x4c, 0x49, 0x53, 0x54, 0x10, 0x18, 0x2a, 0x9d, 0x01, 0x0a, 0x13, 0x49, 0x6e, 0x76, 0x61,
+	0x6c, 0x69, 0x64, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x12,
+	0x25, 0x0a, 0x21, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45,
+	0x52, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
+	0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x2c, 0x0a, 0x28, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49,
+	0x44, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f,
+	0x50, 0x52, 0x45, 0x56, 0x52, 0x41, 0x4e, 0x44, 0x41, 0x4f, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x53,
+	0x45, 0x54, 0x10, 0x01, 0x12, 0x31, 0x0a, 0x2d, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f,
+	0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x45, 0x58,
+	0x43, 0x45, 0x53, 0x53, 0x5f, 0x42, 0x4c, 0x4f, 0x42, 0x5f, 0x47, 0x41, 0x53, 0x5f, 0x4e, 0x4f,
+	0x54, 0x5f, 0x53, 0x45, 0x54, 0x10, 0x02, 0x2a, 0xb2, 0x02, 0x0a, 0x10, 0x42, 0x61, 0x63, 0x6b,
+	0x65, 0x6e, 0x64, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x22, 0x0a, 0x1e,
+	0x42, 0x41, 0x43, 0x4b, 0x45, 0x4e, 0x44, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49,
+	0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00,
+	0x12, 0x24, 0x0a, 0x20, 0x42, 0x41, 0x43, 0x4b, 0x45, 0x4e, 0x44, 0x5f, 0x45, 0x52, 0x52, 0x4f,
+	0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x46, 0x4f, 0x52, 0x45, 0x49, 0x47, 0x4e, 0x5f, 0x50,
+	0x41, 0x4e, 0x49, 0x43, 0x10, 0x01, 0x12, 0x23, 0x0a, 0x1f, 0x42, 0x41, 0x43, 0x4b, 0x45, 0x4e,
+	0x44, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x42, 0x41, 0x44,
+	0x5f, 0x41, 0x52, 0x47, 0x55, 0x4d, 0x45, 0x4e, 0x54, 0x10, 0x02, 0x12, 0x23, 0x0a, 0x1f, 0x42,
+	0x41, 0x43, 0x4b, 0x45, 0x4e, 0x44, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e,
+	0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x55, 0x54, 0x46, 0x38, 0x10, 0x03,
+	0x12, 0x24, 0x0a, 0x20, 0x42, 0x41, 0x43, 0x4b, 0x45, 0x4e, 0x44, 0x5f, 0x45, 0x52, 0x52, 0x4f,
+	0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x49, 0x4d, 0x50, 0x4c, 0x45, 0x4d, 0x45,
+	0x4e, 0x54, 0x45, 0x44, 0x10, 0x04, 0x12, 0x1e, 0x0a, 0x1a, 0x42, 0x41, 0x43, 0x4b, 0x45, 0x4e,
+	0x44, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x4b,
+	0x4e, 0x4f, 0x57, 0x4e, 0x10, 0x05, 0x12, 0x1b, 0x0a, 0x17, 0x42, 0x41, 0x43, 0x4b, 0x45, 0x4e,
+	0x44, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x53, 0x45,
+	0x52, 0x10, 0x06, 0x12, 0x27, 0x0a, 0x23, 0x42, 0x41, 0x43, 0x4b, 0x45, 0x4e, 0x44, 0x5f, 0x45,
+	0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x52, 0x45, 0x41, 0x43,
+	0x48, 0x41, 0x42, 0x4c, 0x45, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x10, 0x07, 0x42, 0x0a, 0x5a, 0x08,
+	0x2e, 0x2e, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 }
 
 var (
@@ -1035,41 +2023,66 @@ func file_result_proto_rawDescGZIP() []byte {
 	return file_result_proto_rawDescData
 }
 
-var file_result_proto_enumTypes = make([]protoimpl.EnumInfo, 2)
-var file_result_proto_msgTypes = make([]protoimpl.MessageInfo, 11)
+var file_result_proto_enumTypes = make([]protoimpl.EnumInfo, 6)
+var file_result_proto_msgTypes = make([]protoimpl.MessageInfo, 16)
 var file_result_proto_goTypes = []any{
-	(HaltReasonEnum)(0),    // 0: evm.v1.HaltReasonEnum
-	(SuccessReasonEnum)(0), // 1: evm.v1.SuccessReasonEnum
-	(*Topic)(nil),          // 2: evm.v1.Topic
-	(*LogData)(nil),        // 3: evm.v1.LogData
-	(*Log)(nil),            // 4: evm.v1.Log
-	(*Call)(nil),           // 5: evm.v1.Call
-	(*Create)(nil),         // 6: evm.v1.Create
-	(*Output)(nil),         // 7: evm.v1.Output
-	(*Success)(nil),        // 8: evm.v1.Success
-	(*Revert)(nil),         // 9: evm.v1.Revert
-	(*Halt)(nil),           // 10: evm.v1.Halt
-	(*Error)(nil),          // 11: evm.v1.Error
-	(*EvmResult)(nil),      // 12: evm.v1.EvmResult
+	(HaltReasonEnum)(0),           // 0: evm.v1.HaltReasonEnum
+	(SuccessReasonEnum)(0),        // 1: evm.v1.SuccessReasonEnum
+	(ErrorKind)(0),                // 2: evm.v1.ErrorKind
+	(InvalidTransactionReason)(0), // 3: evm.v1.InvalidTransactionReason
+	(InvalidHeaderReason)(0),      // 4: evm.v1.InvalidHeaderReason
+	(BackendErrorKind)(0),         // 5: evm.v1.BackendErrorKind
+	(*Topic)(nil),                 // 6: evm.v1.Topic
+	(*LogData)(nil),               // 7: evm.v1.LogData
+	(*Log)(nil),                   // 8: evm.v1.Log
+	(*Call)(nil),                  // 9: evm.v1.Call
+	(*Create)(nil),                // 10: evm.v1.Create
+	(*Output)(nil),                // 11: evm.v1.Output
+	(*Success)(nil),               // 12: evm.v1.Success
+	(*Revert)(nil),                // 13: evm.v1.Revert
+	(*Halt)(nil),                  // 14: evm.v1.Halt
+	(*Error)(nil),                 // 15: evm.v1.Error
+	(*Receipt)(nil),               // 16: evm.v1.Receipt
+	(*EvmResult)(nil),             // 17: evm.v1.EvmResult
+	(*AccessListResult)(nil),      // 18: evm.v1.AccessListResult
+	(*EstimateGasResult)(nil),     // 19: evm.v1.EstimateGasResult
+	(*TransactionResult)(nil),     // 20: evm.v1.TransactionResult
+	(*BlockResult)(nil),           // 21: evm.v1.BlockResult
+	(*CommitResult)(nil),          // 22: evm.v1.CommitResult
+	(*StateChangeset)(nil),        // 23: evm.v1.StateChangeset
+	(*AccessListItem)(nil),        // 24: evm.v1.AccessListItem
 }
 var file_result_proto_depIdxs = []int32{
-	2,  // 0: evm.v1.LogData.topics:type_name -> evm.v1.Topic
-	3,  // 1: evm.v1.Log.data:type_name -> evm.v1.LogData
-	5,  // 2: evm.v1.Output.call:type_name -> evm.v1.Call
-	6,  // 3: evm.v1.Output.create:type_name -> evm.v1.Create
+	6,  // 0: evm.v1.LogData.topics:type_name -> evm.v1.Topic
+	7,  // 1: evm.v1.Log.data:type_name -> evm.v1.LogData
+	9,  // 2: evm.v1.Output.call:type_name -> evm.v1.Call
+	10, // 3: evm.v1.Output.create:type_name -> evm.v1.Create
 	1,  // 4: evm.v1.Success.reason:type_name -> evm.v1.SuccessReasonEnum
-	4,  // 5: evm.v1.Success.logs:type_name -> evm.v1.Log
-	7,  // 6: evm.v1.Success.output:type_name -> evm.v1.Output
+	8,  // 5: evm.v1.Success.logs:type_name -> evm.v1.Log
+	11, // 6: evm.v1.Success.output:type_name -> evm.v1.Output
 	0,  // 7: evm.v1.Halt.reason:type_name -> evm.v1.HaltReasonEnum
-	8,  // 8: evm.v1.EvmResult.success:type_name -> evm.v1.Success
-	9,  // 9: evm.v1.EvmResult.revert:type_name -> evm.v1.Revert
-	10, // 10: evm.v1.EvmResult.halt:type_name -> evm.v1.Halt
-	11, // 11: evm.v1.EvmResult.error:type_name -> evm.v1.Error
-	12, // [12:12] is the sub-list for method output_type
-	12, // [12:12] is the sub-list for method input_type
-	12, // [12:12] is the sub-list for extension type_name
-	12, // [12:12] is the sub-list for extension extendee
-	0,  // [0:12] is the sub-list for field type_name
+	2,  // 8: evm.v1.Error.kind:type_name -> evm.v1.ErrorKind
+	3,  // 9: evm.v1.Error.transaction:type_name -> evm.v1.InvalidTransactionReason
+	4,  // 10: evm.v1.Error.header:type_name -> evm.v1.InvalidHeaderReason
+	5,  // 11: evm.v1.Error.database:type_name -> evm.v1.BackendErrorKind
+	12, // 12: evm.v1.EvmResult.success:type_name -> evm.v1.Success
+	13, // 13: evm.v1.EvmResult.revert:type_name -> evm.v1.Revert
+	14, // 14: evm.v1.EvmResult.halt:type_name -> evm.v1.Halt
+	15, // 15: evm.v1.EvmResult.error:type_name -> evm.v1.Error
+	16, // 16: evm.v1.EvmResult.receipt:type_name -> evm.v1.Receipt
+	22, // 17: evm.v1.EvmResult.commit:type_name -> evm.v1.CommitResult
+	23, // 18: evm.v1.EvmResult.changeset:type_name -> evm.v1.StateChangeset
+	24, // 19: evm.v1.AccessListResult.access_list:type_name -> evm.v1.AccessListItem
+	17, // 20: evm.v1.AccessListResult.result:type_name -> evm.v1.EvmResult
+	17, // 21: evm.v1.EstimateGasResult.failure:type_name -> evm.v1.EvmResult
+	17, // 22: evm.v1.TransactionResult.result:type_name -> evm.v1.EvmResult
+	20, // 23: evm.v1.BlockResult.results:type_name -> evm.v1.TransactionResult
+	8,  // 24: evm.v1.BlockResult.logs:type_name -> evm.v1.Log
+	25, // [25:25] is the sub-list for method output_type
+	25, // [25:25] is the sub-list for method input_type
+	25, // [25:25] is the sub-list for extension type_name
+	25, // [25:25] is the sub-list for extension extendee
+	0,  // [0:25] is the sub-list for field type_name
 }
 
 func init() { file_result_proto_init() }
@@ -1077,11 +2090,13 @@ func file_result_proto_init() {
 	if File_result_proto != nil {
 		return
 	}
+	file_state_proto_init()
+	file_transaction_proto_init()
 	file_result_proto_msgTypes[5].OneofWrappers = []any{
 		(*Output_Call)(nil),
 		(*Output_Create)(nil),
 	}
-	file_result_proto_msgTypes[10].OneofWrappers = []any{
+	file_result_proto_msgTypes[11].OneofWrappers = []any{
 		(*EvmResult_Success)(nil),
 		(*EvmResult_Revert)(nil),
 		(*EvmResult_Halt)(nil),
@@ -1092,8 +2107,8 @@ func file_result_proto_init() {
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_result_proto_rawDesc,
-			NumEnums:      2,
-			NumMessages:   11,
+			NumEnums:      6,
+			NumMessages:   16,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
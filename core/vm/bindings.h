@@ -103,9 +103,11 @@ typedef struct {
   size_t cap;
 } UnmanagedVector;
 
-typedef struct {
-
-} evm_t;
+/**
+ * Opaque handle of a VM created through the FFI. Handles are never reused, so the handle of a
+ * freed VM stays invalid. Zero is never a handle.
+ */
+typedef uint64_t vm_t;
 
 typedef struct {
   uint8_t _private[0];
@@ -131,21 +133,20 @@ typedef struct {
    *
    * # Parameters
    * - `db_t`: A mutable pointer to the database.
-   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the codes will be
-   *   stored.
-   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the storages will be
-   *   stored.
-   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the accounts will be
-   *   stored.
-   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the deleted accounts
-   *   will be stored.
+   * - `U8SliceView`: The encoded `Storages` which were updated.
+   * - `U8SliceView`: The encoded `Accounts` which were updated.
+   * - `U8SliceView`: The encoded `Deleted` accounts, whose account and storage are wiped before
+   *   the updates are applied. Created accounts are listed as well, so that the storage they
+   *   had before is wiped.
+   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the encoded
+   *   `CommitResult`, e.g. the new state root, will be stored. It may be left empty.
    * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
    *   be stored.
    *
    * # Returns
    * - `i32`: Status code indicating success or failure.
    */
-  int32_t (*commit)(db_t*, U8SliceView, U8SliceView, U8SliceView, U8SliceView, UnmanagedVector*);
+  int32_t (*commit)(db_t*, U8SliceView, U8SliceView, U8SliceView, UnmanagedVector*, UnmanagedVector*);
   /**
    * Retrieves the account for a given address.
    *
@@ -207,6 +208,51 @@ typedef struct {
    * - `i32`: Status code indicating success or failure.
    */
   int32_t (*get_block_hash)(db_t*, uint64_t, UnmanagedVector*, UnmanagedVector*);
+  /**
+   * Optionally retrieves the accounts of many addresses in one call.
+   *
+   * # Parameters
+   * - `db_t`: A mutable pointer to the database.
+   * - `U8SliceView`: The `Addresses` proto of the accounts being retrieved.
+   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the `AccountList`
+   *   proto, in the order of the addresses, will be stored.
+   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
+   *   be stored.
+   *
+   * # Returns
+   * - `i32`: Status code indicating success or failure.
+   */
+  int32_t (*get_accounts_batch)(db_t*, U8SliceView, UnmanagedVector*, UnmanagedVector*);
+  /**
+   * Optionally retrieves many storage slots in one call.
+   *
+   * # Parameters
+   * - `db_t`: A mutable pointer to the database.
+   * - `U8SliceView`: The `StorageKeys` proto of the slots being retrieved.
+   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the `StorageValues`
+   *   proto, in the order of the keys, will be stored.
+   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
+   *   be stored.
+   *
+   * # Returns
+   * - `i32`: Status code indicating success or failure.
+   */
+  int32_t (*get_storage_batch)(db_t*, U8SliceView, UnmanagedVector*, UnmanagedVector*);
+  /**
+   * Optionally retrieves many codes by their hashes in one call.
+   *
+   * # Parameters
+   * - `db_t`: A mutable pointer to the database.
+   * - `U8SliceView`: The `CodeHashes` proto of the codes being retrieved.
+   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the `Codes` proto, in
+   *   the order of the hashes, will be stored.
+   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
+   *   be stored.
+   *
+   * # Returns
+   * - `i32`: Status code indicating success or failure.
+   */
+  int32_t (*get_codes_batch)(db_t*, U8SliceView, UnmanagedVector*, UnmanagedVector*);
 } Db_vtable;
 
 typedef struct {
@@ -232,30 +278,452 @@ typedef struct {
   size_t len;
 } ByteSliceView;
 
+/**
+ * Opaque handle of a VM pool created through the FFI. Like VM handles, pool handles are never
+ * reused and zero is never a handle.
+ */
+typedef uint64_t pool_t;
+
+typedef struct {
+  uint8_t _private[0];
+} precompile_t;
+
+typedef struct {
+  /**
+   * Runs the custom precompile of a call.
+   *
+   * # Parameters
+   * - `precompile_t`: A mutable pointer to the precompiles.
+   * - `U8SliceView`: The encoded `PrecompileCall`, with the address of the precompile.
+   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the encoded
+   *   `PrecompileOutput` will be stored.
+   * - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
+   *   be stored. An error aborts the transaction, while a call which fails or reverts returns
+   *   an output saying so, see `CustomPrecompile::call`.
+   *
+   * # Returns
+   * - `i32`: Status code indicating success or failure.
+   */
+  int32_t (*call)(precompile_t*, U8SliceView, UnmanagedVector*, UnmanagedVector*);
+} Precompile_vtable;
+
+/**
+ * Precompiles implemented in Go.
+ */
+typedef struct {
+  precompile_t *state;
+  Precompile_vtable vtable;
+} GoPrecompiles;
+
+/**
+ * Opaque handle of a session created through the FFI. Like VM handles, session handles are never
+ * reused and zero is never a handle.
+ */
+typedef uint64_t session_t;
+
+/**
+ * Persists the changes committed to a state db implemented in Rust since the previous block, as
+ * block `number` with `hash`.
+ */
+void commit_native_block(Db db, uint64_t number, ByteSliceView hash, UnmanagedVector *errmsg);
+
+/**
+ * Returns the `ContractCompileStates` of every contract seen by a VM created with
+ * `new_vm_with_compiler` for its spec, with the calls counted against the threshold.
+ */
+UnmanagedVector compile_call_counts(vm_t vm, UnmanagedVector *errmsg);
+
+/**
+ * Returns the `ContractCompileState` of the contract with `code_hash` for the spec of a VM
+ * created with `new_vm_with_compiler`.
+ */
+UnmanagedVector compile_status(vm_t vm, ByteSliceView code_hash, UnmanagedVector *errmsg);
+
+/**
+ * Returns the `CompilerStats` of a VM created with `new_vm_with_compiler`.
+ */
+UnmanagedVector compiler_stats(vm_t vm, UnmanagedVector *errmsg);
+
+/**
+ * Configures the cache of state db reads of the VM from a `CacheConfig`, resetting its counters.
+ * A nil config disables the cache.
+ */
+void configure_state_cache(vm_t vm, ByteSliceView config, UnmanagedVector *errmsg);
+
+/**
+ * Generates an EIP-2930 access list for the transaction, equivalent to geth's
+ * `eth_createAccessList`, and returns an `AccessListResult`. State changes are not committed.
+ */
+UnmanagedVector create_access_list(vm_t vm,
+                                   Db db,
+                                   ByteSliceView block,
+                                   ByteSliceView tx,
+                                   UnmanagedVector *errmsg);
+
 void destroy_unmanaged_vector(UnmanagedVector v);
 
-UnmanagedVector execute_tx(evm_t *vm_ptr,
-                           bool aot,
+/**
+ * Estimates the gas limit of the transaction, equivalent to geth's `eth_estimateGas`, and returns
+ * an `EstimateGasResult`. `gas_cap` bounds the search when it is not zero.
+ */
+UnmanagedVector estimate_gas(vm_t vm,
+                             Db db,
+                             ByteSliceView block,
+                             ByteSliceView tx,
+                             uint64_t gas_cap,
+                             UnmanagedVector *errmsg);
+
+/**
+ * Drops the compiled code of the contract with `code_hash` from memory and from the store. The
+ * contract is interpreted and counted from zero again.
+ */
+void evict_compiled(vm_t vm, ByteSliceView code_hash, UnmanagedVector *errmsg);
+
+/**
+ * Executes and commits the transactions of a block in order, binding the state db and the block
+ * env once, and returns a `BlockResult`.
+ *
+ * An invalid transaction makes the block invalid: the block fails with an error, unless
+ * `skip_invalid` is set, in which case invalid transactions are reported as `Error` results in
+ * place and leave the state untouched. A failed block or commit returns an error, the
+ * transactions committed before it are not reverted.
+ */
+UnmanagedVector execute_block(vm_t vm,
+                              Db db,
+                              ByteSliceView block,
+                              ByteSliceView txs,
+                              bool skip_invalid,
+                              UnmanagedVector *errmsg);
+
+/**
+ * Executes and commits the transaction, returning an `EvmResult` with its `Receipt`, the
+ * cumulative gas used being the gas of the transaction alone, and the `CommitResult` of the state
+ * db. A transaction which cannot be executed or committed, e.g. with a bad nonce or if the state
+ * db fails, is returned as a typed `Error` result instead of through `errmsg`.
+ */
+UnmanagedVector execute_tx(vm_t vm,
                            Db db,
                            ByteSliceView block,
                            ByteSliceView tx,
                            UnmanagedVector *errmsg);
 
-void free_vm(evm_t *vm, bool aot);
+/**
+ * Executes and commits the transaction like `execute_tx`, returning a `PrestateTrace` of the
+ * touched accounts.
+ */
+UnmanagedVector execute_tx_with_prestate(vm_t vm,
+                                         Db db,
+                                         ByteSliceView block,
+                                         ByteSliceView tx,
+                                         ByteSliceView config,
+                                         UnmanagedVector *errmsg);
+
+/**
+ * Executes the transaction with the validation of `execute_tx`, without committing it. The
+ * returned `EvmResult` carries the `StateChangeset` which `execute_tx` would have committed, for
+ * the caller to apply or discard.
+ */
+UnmanagedVector execute_tx_without_commit(vm_t vm,
+                                          Db db,
+                                          ByteSliceView block,
+                                          ByteSliceView tx,
+                                          UnmanagedVector *errmsg);
+
+/**
+ * Queues the contract with `code_hash`, read from `db`, for compilation ahead of the others,
+ * whatever its call count.
+ */
+void force_compile(vm_t vm, Db db, ByteSliceView code_hash, UnmanagedVector *errmsg);
+
+/**
+ * Releases a state db implemented in Rust, e.g. created by `new_memory_db`.
+ */
+void free_native_db(Db db);
+
+/**
+ * Frees the session without committing its changes.
+ */
+void free_session(session_t session, UnmanagedVector *errmsg);
+
+/**
+ * Frees the VM of the handle, which is invalid afterwards. Calls running on the VM complete
+ * first.
+ */
+void free_vm(vm_t vm, UnmanagedVector *errmsg);
+
+/**
+ * Frees the pool of the handle, which is invalid afterwards. Calls running on the pool complete
+ * first.
+ */
+void free_vm_pool(pool_t pool, UnmanagedVector *errmsg);
+
+/**
+ * Drops the state reads cached by the VM, e.g. after the state db was changed outside of the VM
+ * without a new version.
+ */
+void invalidate_state_cache(vm_t vm, UnmanagedVector *errmsg);
+
+/**
+ * Like `load_precompile_plugin`, for every VM of the pool.
+ */
+void load_pool_precompile_plugin(pool_t pool, ByteSliceView path, UnmanagedVector *errmsg);
+
+/**
+ * Loads the precompiles written in Rust of the plugin at `path`, a shared library exporting the
+ * versioned C ABI of `PrecompilePlugin`, and calls them for their addresses like
+ * `register_precompiles`. Plugins are meant to be loaded once, right after the VM is created.
+ */
+void load_precompile_plugin(vm_t vm, ByteSliceView path, UnmanagedVector *errmsg);
+
+/**
+ * Creates a state db kept in memory, seeded with the `Genesis` alloc. The returned db is passed
+ * to the entry points like a Go state db, and must be released with `free_native_db`.
+ */
+Db new_memory_db(ByteSliceView genesis, UnmanagedVector *errmsg);
+
+/**
+ * Starts a session executing transactions on top of each other in the block, on a new VM like
+ * `vm`. Their changes are kept in Rust, the state db is left untouched until `session_commit`, so
+ * `db` must stay valid until the session is committed or freed. Returns 0 on failure.
+ *
+ * The session keeps the `state` pointer of `db` across calls, while cgo only keeps a Go pointer
+ * valid for the call it is passed to: Go must pin its state db with a `runtime.Pinner` from this
+ * call until the session is committed or freed.
+ */
+session_t new_session(vm_t vm, Db db, ByteSliceView block, UnmanagedVector *errmsg);
 
 UnmanagedVector new_unmanaged_vector(bool nil, const uint8_t *ptr, size_t length);
 
-evm_t *new_vm(uint8_t default_spec_id);
+/**
+ * Creates an interpreting VM and returns its handle, or zero on failure.
+ */
+vm_t new_vm(uint8_t default_spec_id);
+
+/**
+ * Creates a pool of `size` interpreting VMs on the chain of the `ChainConfig`, which serves
+ * `pool_execute_tx` and `pool_simulate_tx` from many threads at once. A nil config keeps the
+ * defaults, for chain id 1. Returns zero if `size` is zero or the config is invalid.
+ */
+pool_t new_vm_pool(uint8_t default_spec_id,
+                   size_t size,
+                   ByteSliceView chain,
+                   UnmanagedVector *errmsg);
+
+/**
+ * Creates a pool of `size` VMs sharing one compile worker, configured like the worker of
+ * `new_vm_with_compiler`, on the chain of the `ChainConfig` like `new_vm_pool`: a contract is
+ * compiled once for the pool, and runs compiled on all of its VMs. Returns zero if `size` is
+ * zero, a config is invalid or the store cannot be opened.
+ */
+pool_t new_vm_pool_with_compiler(uint8_t default_spec_id,
+                                 size_t size,
+                                 ByteSliceView config,
+                                 ByteSliceView chain,
+                                 UnmanagedVector *errmsg);
+
+/**
+ * Creates an interpreting VM on the chain of the `ChainConfig`: its chain id, the limits of the
+ * contracts and the checks of the transactions. A nil config keeps the defaults, for chain id 1.
+ * Returns zero if the config is invalid.
+ */
+vm_t new_vm_with_chain_config(uint8_t default_spec_id,
+                              ByteSliceView config,
+                              UnmanagedVector *errmsg);
+
+/**
+ * Creates a VM which compiles the contracts it calls often to native code, as set by the
+ * `CompilerConfig`, a nil config compiling every contract with the default options. Compiled
+ * contracts are kept in the store dir and loaded again by the VMs created over the same store.
+ * The VM runs on the chain of the `ChainConfig` like `new_vm_with_chain_config`. Returns zero if
+ * a config is invalid or the store cannot be opened.
+ */
+vm_t new_vm_with_compiler(uint8_t default_spec_id,
+                          ByteSliceView config,
+                          ByteSliceView chain,
+                          UnmanagedVector *errmsg);
+
+/**
+ * Creates a VM over a state db implemented in Rust, e.g. opened by `open_sled_db`, instead of the
+ * Go state db, on the chain of the `ChainConfig` like `new_vm_with_chain_config`. The db passed to
+ * the entry points is ignored. The VM does not own the db, which must be released with
+ * `free_native_db` once the VM is freed. Returns zero if the config is invalid.
+ */
+vm_t new_vm_with_db(uint8_t default_spec_id, Db db, ByteSliceView chain, UnmanagedVector *errmsg);
+
+/**
+ * Creates an interpreting VM like `new_vm_with_chain_config`, calling the precompiles of the
+ * plugin at `path` like `load_precompile_plugin`. Returns zero if the config is invalid or the
+ * plugin cannot be loaded.
+ */
+vm_t new_vm_with_precompile_plugin(uint8_t default_spec_id,
+                                   ByteSliceView path,
+                                   ByteSliceView chain,
+                                   UnmanagedVector *errmsg);
+
+/**
+ * Opens the state db persisted with sled at `path`, creating it if needed. The returned db is
+ * passed to the entry points or to `new_vm_with_db`, and must be released with `free_native_db`.
+ *
+ * Committed changes are only persisted by `commit_native_block`, atomically for the whole block.
+ */
+Db open_sled_db(ByteSliceView path, UnmanagedVector *errmsg);
+
+/**
+ * Executes and commits the transaction like `execute_tx`, on a VM lent by the pool. Safe to call
+ * from many threads at once; a call waits for a VM when they are all in use. Concurrent calls
+ * must not commit to the same state db.
+ */
+UnmanagedVector pool_execute_tx(pool_t pool,
+                                Db db,
+                                ByteSliceView block,
+                                ByteSliceView tx,
+                                UnmanagedVector *errmsg);
+
+/**
+ * Simulates the transaction like `simulate_tx`, on a VM lent by the pool. Safe to call from many
+ * threads at once; a call waits for a VM when they are all in use.
+ */
+UnmanagedVector pool_simulate_tx(pool_t pool,
+                                 Db db,
+                                 ByteSliceView block,
+                                 ByteSliceView tx,
+                                 UnmanagedVector *errmsg);
+
+/**
+ * Reads the state the transaction is known to touch into the state cache before it runs: the
+ * sender, the recipient, the coinbase, the access list and the extra `StorageKeys` in `keys`,
+ * where a key without a slot prefetches the account only. The batch callbacks of the db are used
+ * when it implements them.
+ *
+ * The prefetched values are read by the next transaction the VM executes, whatever the config of
+ * the cache, so the state db must not change in between.
+ */
+void prefetch_tx(vm_t vm,
+                 Db db,
+                 ByteSliceView block,
+                 ByteSliceView tx,
+                 ByteSliceView keys,
+                 UnmanagedVector *errmsg);
+
+/**
+ * Like `register_precompiles`, for every VM of the pool.
+ */
+void register_pool_precompiles(pool_t pool,
+                               GoPrecompiles precompiles,
+                               ByteSliceView addresses,
+                               UnmanagedVector *errmsg);
+
+/**
+ * Forwards the calls to the `Addresses` to the Go precompiles, from the next transaction on. The
+ * addresses are warm like the standard precompiles, and replace any precompile registered for
+ * them before. `precompiles` must stay valid while the VM is used.
+ *
+ * The Go precompiles see the Go state db as committed, without the changes the running
+ * transaction made before the call: they must not read state the transaction may have changed.
+ */
+void register_precompiles(vm_t vm,
+                          GoPrecompiles precompiles,
+                          ByteSliceView addresses,
+                          UnmanagedVector *errmsg);
 
-evm_t *new_vm_with_compiler(uint8_t default_spec_id,
-                            uint64_t thershold,
-                            size_t max_concurrent_size);
+/**
+ * Returns a checkpoint of the session, to revert the transactions executed after it with
+ * `session_revert_to`. Checkpoint ids are never reused, zero is returned on failure only.
+ */
+uint64_t session_checkpoint(session_t session, UnmanagedVector *errmsg);
 
-UnmanagedVector simulate_tx(evm_t *vm_ptr,
-                            bool aot,
+/**
+ * Commits the changes of the session to the state db with one call of its `commit` callback, and
+ * returns its `CommitResult`. The session is freed, committed or not.
+ */
+UnmanagedVector session_commit(session_t session, UnmanagedVector *errmsg);
+
+/**
+ * Executes the transaction in the session, on the changes of the transactions executed before
+ * it, and returns an `EvmResult` whose receipt counts the gas of the session.
+ */
+UnmanagedVector session_execute(session_t session, ByteSliceView tx, UnmanagedVector *errmsg);
+
+/**
+ * Reverts the transactions executed in the session since the checkpoint. The checkpoints taken
+ * after it become invalid.
+ */
+void session_revert_to(session_t session, uint64_t checkpoint, UnmanagedVector *errmsg);
+
+/**
+ * Replaces the chain config of the VM with the `ChainConfig`, from the next transaction on. A nil
+ * config restores the defaults.
+ */
+void set_chain_config(vm_t vm, ByteSliceView config, UnmanagedVector *errmsg);
+
+/**
+ * Enables the parallel execution of `execute_block` on `workers` threads, or disables it when
+ * `workers` is zero. The results are identical to the sequential execution.
+ *
+ * Transactions executed in parallel are interpreted, without the compiled code of the AOT
+ * compiler. Blocks are executed sequentially while the VM has custom precompiles, or a chain
+ * config disabling the nonce check or setting blob parameters.
+ */
+void set_parallel_execution(vm_t vm, size_t workers, UnmanagedVector *errmsg);
+
+/**
+ * Like `set_chain_config`, for every VM of the pool, from the next call on. Calls running on the
+ * pool at the moment may run with parts of the previous config.
+ */
+void set_pool_chain_config(pool_t pool, ByteSliceView config, UnmanagedVector *errmsg);
+
+/**
+ * Sets the version of the state db the next calls of the VM read from, an epoch Go bumps whenever
+ * the state db changes outside of the VM. A block scoped cache is kept across calls only while the
+ * version and the block stay the same; without a version it is kept for the transactions of one
+ * call.
+ */
+void set_state_version(vm_t vm, uint64_t version, UnmanagedVector *errmsg);
+
+/**
+ * Executes the transaction without committing it, returning an `EvmResult` like `execute_tx`.
+ */
+UnmanagedVector simulate_tx(vm_t vm,
                             Db db,
                             ByteSliceView block,
                             ByteSliceView tx,
                             UnmanagedVector *errmsg);
 
+/**
+ * Simulates the transaction like `simulate_tx`, returning a `PrestateTrace` of the touched
+ * accounts.
+ */
+UnmanagedVector simulate_tx_with_prestate(vm_t vm,
+                                          Db db,
+                                          ByteSliceView block,
+                                          ByteSliceView tx,
+                                          ByteSliceView config,
+                                          UnmanagedVector *errmsg);
+
+/**
+ * Returns the `CacheStats` of the state db reads of the VM.
+ */
+UnmanagedVector state_cache_stats(vm_t vm, UnmanagedVector *errmsg);
+
+/**
+ * Executes the transaction with the call tracer attached and returns a `CallTrace`. State
+ * changes are not committed.
+ */
+UnmanagedVector trace_call_tx(vm_t vm,
+                              Db db,
+                              ByteSliceView block,
+                              ByteSliceView tx,
+                              UnmanagedVector *errmsg);
+
+/**
+ * Executes the transaction with the EIP-3155 struct logger attached and returns a
+ * `StructLogTrace`. State changes are not committed.
+ */
+UnmanagedVector trace_tx(vm_t vm,
+                         Db db,
+                         ByteSliceView block,
+                         ByteSliceView tx,
+                         ByteSliceView config,
+                         UnmanagedVector *errmsg);
+
 #endif /* __LIBREVMAPI__ */
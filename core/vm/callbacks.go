@@ -7,14 +7,14 @@ package vm
 #include "bindings.h"
 
 // typedefs for _cgo functions (db)
-typedef GoError (*commit_fn)(db_t *ptr, U8SliceView codes, U8SliceView storages, U8SliceView accounts, U8SliceView deletedAccounts, UnmanagedVector *errOut);
+typedef GoError (*commit_fn)(db_t *ptr, U8SliceView storages, U8SliceView accounts, U8SliceView deletedAccounts, UnmanagedVector *result, UnmanagedVector *errOut);
 typedef GoError (*get_account_fn)(db_t *ptr, U8SliceView address, UnmanagedVector *result, UnmanagedVector *errOut);
 typedef GoError (*get_code_by_hash_fn)(db_t *ptr, U8SliceView codeHash, UnmanagedVector *result, UnmanagedVector *errOut);
 typedef GoError (*get_storage_fn)(db_t *ptr, U8SliceView address, U8SliceView key, UnmanagedVector *result, UnmanagedVector *errOut);
 typedef GoError (*get_block_hash_fn)(db_t *ptr, uint64_t blockNumber, UnmanagedVector *result, UnmanagedVector *errOut);
 
 // forward declarations (db)
-GoError cCommit_cgo(db_t *ptr, U8SliceView codes, U8SliceView storages, U8SliceView accounts, U8SliceView deletedAccounts, UnmanagedVector *errOut);
+GoError cCommit_cgo(db_t *ptr, U8SliceView storages, U8SliceView accounts, U8SliceView deletedAccounts, UnmanagedVector *result, UnmanagedVector *errOut);
 GoError cGetAccount_cgo(db_t *ptr, U8SliceView address, UnmanagedVector *result, UnmanagedVector *errOut);
 GoError cGetCodeByHash_cgo(db_t *ptr, U8SliceView codeHash, UnmanagedVector *result, UnmanagedVector *errOut);
 GoError cGetStorage_cgo(db_t *ptr, U8SliceView address, U8SliceView key, UnmanagedVector *result, UnmanagedVector *errOut);
@@ -28,6 +28,8 @@ import (
 	"unsafe"
 
 	state "github.com/0xEyrie/revmffi/core/state"
+	"github.com/0xEyrie/revmffi/core/types"
+	"google.golang.org/protobuf/proto"
 )
 
 // Note: we have to include all exports in the same file (at least since they both import bindings.h),
@@ -82,14 +84,14 @@ func buildDB(state *DBState) C.Db {
 }
 
 //export cCommit
-func cCommit(ptr *C.db_t, storages C.U8SliceView, accounts C.U8SliceView, deletedAccounts C.U8SliceView, errOut *C.UnmanagedVector) (ret C.GoError) {
+func cCommit(ptr *C.db_t, storages C.U8SliceView, accounts C.U8SliceView, deletedAccounts C.U8SliceView, result *C.UnmanagedVector, errOut *C.UnmanagedVector) (ret C.GoError) {
 	defer recoverPanic(&ret)
 
-	if ptr == nil || errOut == nil {
+	if ptr == nil || result == nil || errOut == nil {
 		// we received an invalid pointer
 		return C.GoError_BadArgument
 	}
-	if !(*errOut).is_none {
+	if !(*result).is_none || !(*errOut).is_none {
 		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
 	}
 
@@ -98,7 +100,18 @@ func cCommit(ptr *C.db_t, storages C.U8SliceView, accounts C.U8SliceView, delete
 	v1 := copyU8Slice(accounts)
 	v2 := copyU8Slice(deletedAccounts)
 
-	statedb.UpdateAndCommit(v0, v1, v2)
+	root, err := statedb.UpdateAndCommit(v0, v1, v2)
+	if err != nil {
+		// a failed commit fails the transaction in Rust with this message
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.GoError_User
+	}
+	v, err := proto.Marshal(&types.CommitResult{StateRoot: root.Bytes()})
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.GoError_CannotSerialize
+	}
+	*result = newUnmanagedVector(v)
 
 	return C.GoError_None
 }
@@ -5,15 +5,15 @@ package vm
 #include <stdio.h>
 
 // imports (db)
-GoError cCommit(db_t *ptr, U8SliceView codes, U8SliceView storages, U8SliceView accounts, U8SliceView deletedAccounts, UnmanagedVector *errOut);
+GoError cCommit(db_t *ptr, U8SliceView storages, U8SliceView accounts, U8SliceView deletedAccounts, UnmanagedVector *result, UnmanagedVector *errOut);
 GoError cGetAccount(db_t *ptr, U8SliceView address, UnmanagedVector *result, UnmanagedVector *errOut);
 GoError cGetCodeByHash(db_t *ptr, U8SliceView codeHash, UnmanagedVector *result, UnmanagedVector *errOut);
 GoError cGetStorage(db_t *ptr, U8SliceView address, U8SliceView key, UnmanagedVector *result, UnmanagedVector *errOut);
 GoError cGetBlockHash(db_t *ptr, uint64_t blockNumber, UnmanagedVector *result, UnmanagedVector *errOut);
 
 // Gateway functions (db)
-GoError cCommit_cgo(db_t *ptr, U8SliceView codes, U8SliceView storages, U8SliceView accounts, U8SliceView deletedAccounts, UnmanagedVector *errOut) {
-	return cCommit(ptr, codes, storages, accounts, deletedAccounts, errOut);
+GoError cCommit_cgo(db_t *ptr, U8SliceView storages, U8SliceView accounts, U8SliceView deletedAccounts, UnmanagedVector *result, UnmanagedVector *errOut) {
+	return cCommit(ptr, storages, accounts, deletedAccounts, result, errOut);
 }
 GoError cGetAccount_cgo(db_t *ptr, U8SliceView address, UnmanagedVector *result, UnmanagedVector *errOut) {
 	return cGetAccount(ptr, address, result, errOut);
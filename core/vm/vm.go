@@ -15,32 +15,64 @@ import (
 
 // EVM represents an Ethereum Virtual Machine instance
 type EVM struct {
-	evm_ptr     *C.evm_t
-	StateDB     state.ExtendedStateDB
-	hasCompiler bool
+	// handle of the VM in librevm, zero is never a handle
+	vm      C.vm_t
+	StateDB state.ExtendedStateDB
 }
 
-// DestroyVM releases the VM instance
-func DestroyVM(vm EVM) {
-	C.free_vm(vm.evm_ptr, C.bool(vm.hasCompiler))
+// DestroyVM releases the VM instance, the handle is invalid afterwards
+func DestroyVM(vm EVM) error {
+	errmsg := uninitializedUnmanagedVector()
+	_, err := C.free_vm(vm.vm, &errmsg)
+	if err != nil && err.(syscall.Errno) != C.Success {
+		return errorWithMessage(err, errmsg)
+	}
+	return nil
 }
 
 // NewEVM initializes a new VM instance
 func NewEVM(statedb state.ExtendedStateDB, spec SpecId) EVM {
 	return EVM{
-		evm_ptr:     C.new_vm(cu8(spec)),
-		StateDB:     statedb,
-		hasCompiler: false,
+		vm:      C.new_vm(cu8(spec)),
+		StateDB: statedb,
 	}
 }
 
-// NewEVMWithCompiler initializes a new VM instance with AOT compiler
-func NewEVMWithCompiler(statedb state.ExtendedStateDB, thershold uint64, maxConcurrentSize uint, spec SpecId) EVM {
+// NewEVMWithChainConfig initializes a new VM instance following the encoded `ChainConfig` proto.
+// A nil config keeps the defaults.
+func NewEVMWithChainConfig(statedb state.ExtendedStateDB, chain []byte, spec SpecId) (EVM, error) {
+	chainView := makeView(chain)
+	defer runtime.KeepAlive(chainView)
+
+	errmsg := uninitializedUnmanagedVector()
+	vm, err := C.new_vm_with_chain_config(cu8(spec), chainView, &errmsg)
+	if err != nil && err.(syscall.Errno) != C.Success {
+		return EVM{}, errorWithMessage(err, errmsg)
+	}
 	return EVM{
-		evm_ptr:     C.new_vm_with_compiler(cu8(spec), cu64(thershold), cusize(maxConcurrentSize)),
-		StateDB:     statedb,
-		hasCompiler: true,
+		vm:      vm,
+		StateDB: statedb,
+	}, nil
+}
+
+// NewEVMWithCompiler initializes a new VM instance with AOT compiler, configured by the encoded
+// `CompilerConfig` proto and following the encoded `ChainConfig` proto. Nil configs keep the
+// defaults.
+func NewEVMWithCompiler(statedb state.ExtendedStateDB, config []byte, chain []byte, spec SpecId) (EVM, error) {
+	configView := makeView(config)
+	defer runtime.KeepAlive(configView)
+	chainView := makeView(chain)
+	defer runtime.KeepAlive(chainView)
+
+	errmsg := uninitializedUnmanagedVector()
+	vm, err := C.new_vm_with_compiler(cu8(spec), configView, chainView, &errmsg)
+	if err != nil && err.(syscall.Errno) != C.Success {
+		return EVM{}, errorWithMessage(err, errmsg)
 	}
+	return EVM{
+		vm:      vm,
+		StateDB: statedb,
+	}, nil
 }
 
 // `Execute` executes a transaction on the VM
@@ -58,7 +90,7 @@ func (evm *EVM) Execute(
 	defer runtime.KeepAlive(txByteSliceView)
 
 	errmsg := uninitializedUnmanagedVector()
-	res, err := C.execute_tx(evm.evm_ptr, C.bool(evm.hasCompiler), db, blockBytesSliceView, txByteSliceView, &errmsg)
+	res, err := C.execute_tx(evm.vm, db, blockBytesSliceView, txByteSliceView, &errmsg)
 	if err != nil && err.(syscall.Errno) != C.Success {
 		// ignore the opereation times out error
 		errno, ok := err.(syscall.Errno)
@@ -86,7 +118,7 @@ func (evm *EVM) simulate(
 	defer runtime.KeepAlive(txByteSliceView)
 
 	errmsg := uninitializedUnmanagedVector()
-	res, err := C.simulate_tx(evm.evm_ptr, C.bool(evm.hasCompiler), db, blockBytesSliceView, txByteSliceView, &errmsg)
+	res, err := C.simulate_tx(evm.vm, db, blockBytesSliceView, txByteSliceView, &errmsg)
 	if err != nil && err.(syscall.Errno) != C.Success {
 		// ignore the operation timed out error
 		errno, ok := err.(syscall.Errno)
@@ -1,8 +1,14 @@
-use std::env;
+use std::{env, fs, path::Path};
 
 fn main() {
     let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
 
+    // The AOT store evicts the artifacts of other compiler versions, which include the revmc
+    // resolved by the lock file
+    let lock = Path::new(&crate_dir).join("../Cargo.lock");
+    let lock = fs::read_to_string(&lock).expect("Failed to read Cargo.lock");
+    println!("cargo:rustc-env=REVMC_VERSION={}", revmc_version(&lock));
+
     cbindgen::generate(crate_dir).expect("Unable to generate bindings").write_to_file("bindings.h");
 
     prost_build::compile_protos(
@@ -11,8 +17,36 @@ fn main() {
             "../proto/evm/v1/block.proto",
             "../proto/evm/v1/result.proto",
             "../proto/evm/v1/state.proto",
+            "../proto/evm/v1/trace.proto",
+            "../proto/evm/v1/compiler.proto",
+            "../proto/evm/v1/precompile.proto",
+            "../proto/evm/v1/chain.proto",
         ],
         &["../proto/evm/v1/"],
     )
     .expect("Failed to compile protos");
+
+    // Listing a path disables the default of rerunning on any change of the package
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-changed=../proto");
+    println!("cargo:rerun-if-changed=../Cargo.lock");
+}
+
+/// Commit of a git revmc, or its version when it comes from a registry.
+fn revmc_version(lock: &str) -> String {
+    let package = lock
+        .split("[[package]]")
+        .find(|package| package.lines().any(|line| line.trim() == r#"name = "revmc""#))
+        .expect("revmc is missing from Cargo.lock");
+    let value = |key: &str| {
+        package.lines().find_map(|line| {
+            let value = line.trim().strip_prefix(key)?.trim_start().strip_prefix('=')?;
+            Some(value.trim().trim_matches('"').to_owned())
+        })
+    };
+    match value("source").as_deref().and_then(|source| source.split_once('#')) {
+        Some((_, commit)) => commit[..commit.len().min(7)].to_owned(),
+        None => value("version").expect("revmc has no version in Cargo.lock"),
+    }
 }
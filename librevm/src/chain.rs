@@ -0,0 +1,248 @@
+use std::sync::{Arc, RwLock};
+
+use revm::{
+    handler::register::{EvmHandler, HandleRegisterBox},
+    primitives::{
+        fake_exponential, BlockEnv, CfgEnv, EVMError, InvalidTransaction, GAS_PER_BLOB,
+        MAX_BLOB_NUMBER_PER_BLOCK, MIN_BLOB_GASPRICE, TARGET_BLOB_NUMBER_PER_BLOCK,
+        VERSIONED_HASH_VERSION_KZG,
+    },
+    Database,
+};
+
+use crate::v1::types::ChainConfig;
+
+/// The chain config of a VM. The rules revm has no setting for are enforced by the EVM handler,
+/// which shares them with the VM: a config set after the VM is built applies from the next
+/// transaction on.
+#[derive(Clone, Default)]
+pub struct ChainRules(Arc<RwLock<ChainConfig>>);
+
+impl ChainRules {
+    pub fn new(config: ChainConfig) -> Self {
+        ChainRules(Arc::new(RwLock::new(config)))
+    }
+
+    pub fn config(&self) -> ChainConfig {
+        self.0.read().unwrap_or_else(|err| err.into_inner()).clone()
+    }
+
+    /// Replaces the config, and applies it to `cfg`, the config of the EVM of the VM.
+    pub fn set(&self, config: ChainConfig, cfg: &mut CfgEnv) {
+        apply(&config, cfg);
+        self.replace(config);
+    }
+
+    /// Replaces the config without applying it, for EVMs which apply it before their next
+    /// transaction, like the ones of a VM pool.
+    pub fn replace(&self, config: ChainConfig) {
+        *self.0.write().unwrap_or_else(|err| err.into_inner()) = config;
+    }
+
+    /// Applies the config to `cfg`, e.g. the config of an EVM built for the VM.
+    pub fn apply(&self, cfg: &mut CfgEnv) {
+        apply(&self.0.read().unwrap_or_else(|err| err.into_inner()), cfg);
+    }
+
+    /// Whether the config has rules enforced by the handler, which the EVMs of the parallel
+    /// executor run without.
+    pub fn needs_handler(&self) -> bool {
+        let config = self.0.read().unwrap_or_else(|err| err.into_inner());
+        config.disable_nonce_check
+            || config.max_blobs_per_block != 0
+            || config.blob_base_fee_update_fraction != 0
+    }
+
+    /// The excess blob gas of the block after one with `excess_blob_gas` which used
+    /// `blob_gas_used`, as set by EIP-4844 with the blob target of the config.
+    pub fn next_excess_blob_gas(&self, excess_blob_gas: u64, blob_gas_used: u64) -> u64 {
+        let target = match self.config().target_blobs_per_block {
+            0 => TARGET_BLOB_NUMBER_PER_BLOCK,
+            target => target,
+        };
+        (excess_blob_gas + blob_gas_used).saturating_sub(target * GAS_PER_BLOB)
+    }
+
+    /// Enforces the rules of the config revm has no setting for: the nonce check, the maximum
+    /// number of blobs of a block and the update fraction of the blob base fee.
+    pub fn handler_register<'a, EXT: 'a, DB: Database + 'a>(
+        &self,
+    ) -> HandleRegisterBox<'a, EXT, DB> {
+        let rules = self.clone();
+        Box::new(move |handler: &mut EvmHandler<'a, EXT, DB>| {
+            let chain = rules.clone();
+            let prev = handler.validation.env.clone();
+            handler.validation.env = Arc::new(move |env| {
+                let config = chain.config();
+                let max = config.max_blobs_per_block as usize;
+                let have = env.tx.blob_hashes.len();
+                if max != 0 && have > max {
+                    return Err(EVMError::Transaction(InvalidTransaction::TooManyBlobs {
+                        max,
+                        have,
+                    }));
+                }
+                // revm checks the blobs against the maximum of EIP-4844, which a higher maximum
+                // of the config replaces
+                let revm_max = MAX_BLOB_NUMBER_PER_BLOCK as usize;
+                let above_revm_max =
+                    max > revm_max && have > revm_max && env.tx.max_fee_per_blob_gas.is_some();
+                if config.blob_base_fee_update_fraction == 0 && !above_revm_max {
+                    return prev(env);
+                }
+                let mut env = env.clone();
+                if above_revm_max {
+                    // revm checks the version of the hashes it is left with
+                    if env.tx.blob_hashes[revm_max..]
+                        .iter()
+                        .any(|hash| hash[0] != VERSIONED_HASH_VERSION_KZG)
+                    {
+                        let err = InvalidTransaction::BlobVersionNotSupported;
+                        return Err(EVMError::Transaction(err));
+                    }
+                    env.tx.blob_hashes.truncate(revm_max);
+                }
+                // the fee cap of the blobs is checked against the blob base fee of the config
+                set_blob_gasprice(&config, &mut env.block);
+                prev(&env)
+            });
+
+            let chain = rules.clone();
+            let prev = handler.validation.tx_against_state.clone();
+            handler.validation.tx_against_state = Arc::new(move |context| {
+                if chain.config().disable_nonce_check {
+                    context.evm.inner.env.tx.nonce = None;
+                }
+                prev(context)
+            });
+
+            // the preverified transactions are not validated, but their accounts are loaded
+            // before the fees are charged
+            let chain = rules.clone();
+            let prev = handler.pre_execution.load_accounts.clone();
+            handler.pre_execution.load_accounts = Arc::new(move |context| {
+                set_blob_gasprice(&chain.config(), &mut context.evm.inner.env.block);
+                prev(context)
+            });
+        })
+    }
+}
+
+/// Sets the fields of `cfg` set by `config`. Zero values keep the defaults of revm.
+fn apply(config: &ChainConfig, cfg: &mut CfgEnv) {
+    let defaults = CfgEnv::default();
+    cfg.chain_id = match config.chain_id {
+        0 => defaults.chain_id,
+        chain_id => chain_id,
+    };
+    cfg.limit_contract_code_size =
+        (config.code_size_limit != 0).then_some(config.code_size_limit as usize);
+    cfg.memory_limit = match config.memory_limit {
+        0 => defaults.memory_limit,
+        memory_limit => memory_limit,
+    };
+    cfg.disable_balance_check = config.disable_balance_check;
+    cfg.disable_base_fee = config.disable_base_fee;
+    cfg.disable_block_gas_limit = config.disable_block_gas_limit;
+    cfg.disable_eip3607 = config.disable_eip3607;
+}
+
+/// Prices the blob gas of `block` with the update fraction of the config, if it sets one.
+fn set_blob_gasprice(config: &ChainConfig, block: &mut BlockEnv) {
+    let fraction = config.blob_base_fee_update_fraction;
+    if let Some(blob) = block.blob_excess_gas_and_price.as_mut().filter(|_| fraction != 0) {
+        blob.blob_gasprice = fake_exponential(MIN_BLOB_GASPRICE, blob.excess_blob_gas, fraction);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::{address, Address, B256, U256};
+    use revm::{
+        db::InMemoryDB,
+        primitives::{AccountInfo, SpecId, TxKind},
+        Evm,
+    };
+
+    use super::*;
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+
+    #[test]
+    fn applies_the_config_to_the_transactions() {
+        let rules = ChainRules::new(ChainConfig { chain_id: 7, ..Default::default() });
+        let mut db = InMemoryDB::default();
+        let balance = U256::from(1_000_000);
+        db.insert_account_info(SENDER, AccountInfo { balance, ..Default::default() });
+
+        let mut evm = Evm::builder()
+            .with_db(db)
+            .modify_cfg_env(|cfg| rules.apply(cfg))
+            .modify_tx_env(|tx| {
+                tx.caller = SENDER;
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 21_000;
+                tx.chain_id = Some(1);
+                tx.nonce = Some(3);
+            })
+            .append_handler_register_box(rules.handler_register())
+            .build();
+        let err = evm.transact().unwrap_err();
+        assert!(matches!(err, EVMError::Transaction(InvalidTransaction::InvalidChainId)));
+
+        evm.tx_mut().chain_id = Some(7);
+        let err = evm.transact().unwrap_err();
+        assert!(matches!(err, EVMError::Transaction(InvalidTransaction::NonceTooHigh { .. })));
+
+        let config = ChainConfig { chain_id: 7, disable_nonce_check: true, ..Default::default() };
+        rules.set(config, evm.cfg_mut());
+        assert!(evm.transact().unwrap().result.is_success());
+    }
+
+    #[test]
+    fn raises_the_maximum_of_blobs() {
+        let blobs = |count: usize| {
+            let mut hash = B256::ZERO;
+            hash[0] = VERSIONED_HASH_VERSION_KZG;
+            vec![hash; count]
+        };
+        let rules = ChainRules::default();
+        let mut db = InMemoryDB::default();
+        let balance = U256::from(10_000_000);
+        db.insert_account_info(SENDER, AccountInfo { balance, ..Default::default() });
+
+        let mut evm = Evm::builder()
+            .with_db(db)
+            .with_spec_id(SpecId::CANCUN)
+            .modify_tx_env(|tx| {
+                tx.caller = SENDER;
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 21_000;
+                tx.max_fee_per_blob_gas = Some(U256::from(1));
+                tx.blob_hashes = blobs(8);
+            })
+            .append_handler_register_box(rules.handler_register())
+            .build();
+        let err = evm.transact().unwrap_err();
+        assert!(matches!(
+            err,
+            EVMError::Transaction(InvalidTransaction::TooManyBlobs { max: 6, have: 8 })
+        ));
+
+        let config = ChainConfig { max_blobs_per_block: 9, ..Default::default() };
+        rules.set(config, evm.cfg_mut());
+        assert!(evm.transact().unwrap().result.is_success());
+
+        // the hashes above the maximum of EIP-4844 are still checked
+        evm.tx_mut().blob_hashes[7] = B256::ZERO;
+        let err = evm.transact().unwrap_err();
+        assert!(matches!(err, EVMError::Transaction(InvalidTransaction::BlobVersionNotSupported)));
+
+        evm.tx_mut().blob_hashes = blobs(10);
+        let err = evm.transact().unwrap_err();
+        assert!(matches!(
+            err,
+            EVMError::Transaction(InvalidTransaction::TooManyBlobs { max: 9, have: 10 })
+        ));
+    }
+}
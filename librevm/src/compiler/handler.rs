@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use revm::{handler::register::EvmHandler, Database};
+
+use super::{store::ArtifactKey, worker::EXTCompileWorker};
+
+/// Runs the frames of compiled contracts natively, and interprets the others while they are
+/// counted and compiled.
+pub fn register_handler<DB: Database + 'static>(
+    handler: &mut EvmHandler<'_, EXTCompileWorker, DB>,
+) {
+    let prev = handler.execution.execute_frame.clone();
+    handler.execution.execute_frame = Arc::new(move |frame, memory, tables, context| {
+        let interpreter = frame.interpreter_mut();
+        // init code has no hash and is always interpreted
+        let Some(code_hash) = interpreter.contract.hash else {
+            return prev(frame, memory, tables, context);
+        };
+
+        let key = ArtifactKey { code_hash, spec_id: context.evm.spec_id() };
+        match context.external.get_function(key, &interpreter.contract.bytecode) {
+            Some(compiled) => Ok(unsafe {
+                compiled.function.call_with_interpreter_and_memory(interpreter, memory, context)
+            }),
+            None => prev(frame, memory, tables, context),
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs, thread,
+        time::{Duration, Instant},
+    };
+
+    use alloy_primitives::{address, Address, Bytes, U256};
+    use revm::{
+        db::InMemoryDB,
+        primitives::{AccountInfo, Bytecode, SpecId, TxKind},
+        Evm,
+    };
+
+    use super::*;
+    use crate::v1::types::{CompileStatus, CompilerConfig};
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+    const CONTRACT: Address = address!("2000000000000000000000000000000000000002");
+
+    #[test]
+    fn runs_compiled_contracts() {
+        let store_dir =
+            std::env::temp_dir().join(format!("revmapi-handler-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&store_dir);
+        let config = CompilerConfig {
+            store_dir: store_dir.to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let worker = EXTCompileWorker::new(config, SpecId::CANCUN).unwrap();
+
+        // PUSH1 0x2a PUSH0 SSTORE STOP
+        let code = Bytecode::new_raw(Bytes::from_static(&[0x60, 0x2a, 0x5f, 0x55, 0x00]));
+        let key = ArtifactKey { code_hash: code.hash_slow(), spec_id: SpecId::CANCUN };
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(SENDER, AccountInfo::from_balance(U256::from(1_000_000)));
+        db.insert_account_info(CONTRACT, AccountInfo::new(U256::ZERO, 1, key.code_hash, code));
+
+        let mut evm = Evm::builder()
+            .with_db(db)
+            .with_spec_id(SpecId::CANCUN)
+            .with_external_context(worker.clone())
+            .append_handler_register(register_handler::<InMemoryDB>)
+            .modify_tx_env(|tx| {
+                tx.caller = SENDER;
+                tx.transact_to = TxKind::Call(CONTRACT);
+                tx.gas_limit = 100_000;
+                tx.nonce = None;
+            })
+            .build();
+
+        // interpreted while it is compiled
+        let interpreted = evm.transact().unwrap();
+        let started = Instant::now();
+        while worker.state(key).status() != CompileStatus::Compiled {
+            assert!(started.elapsed() < Duration::from_secs(120), "{:?}", worker.state(key));
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let compiled = evm.transact().unwrap();
+        assert!(compiled.result.is_success());
+        assert_eq!(compiled.result.gas_used(), interpreted.result.gas_used());
+        let slot = &compiled.state[&CONTRACT].storage[&U256::ZERO];
+        assert_eq!(slot.present_value, U256::from(0x2a));
+
+        drop(evm);
+        drop(worker);
+        let _ = fs::remove_dir_all(store_dir);
+    }
+}
@@ -0,0 +1,7 @@
+mod handler;
+mod store;
+mod worker;
+
+pub use handler::*;
+pub use store::*;
+pub use worker::*;
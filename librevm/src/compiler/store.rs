@@ -0,0 +1,386 @@
+use std::{
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime},
+};
+
+use alloy_primitives::{hex, keccak256, B256};
+use revm::primitives::SpecId;
+
+/// Version of the code generation. Artifacts of other versions are evicted when a store is opened,
+/// so it must change whenever the generated code does. The revmc part is set by the build script
+/// from Cargo.lock.
+pub const COMPILER_VERSION: &str =
+    concat!("revmapi-", env!("CARGO_PKG_VERSION"), "-revmc-", env!("REVMC_VERSION"));
+
+/// Other compiler versions are evicted once no store of theirs was opened for that long, so that
+/// nodes of several versions can share a root.
+const STALE_VERSION_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Partial writes, scratch dirs and locks older than that were left behind by a stopped process.
+const STALE_WRITE_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Held while the stale files of a root are evicted.
+const LOCK: &str = ".lock";
+
+/// Time a store of the version was last opened, in seconds since the epoch.
+const OPENED: &str = ".opened";
+
+/// Where the contracts are compiled before they are inserted.
+const SCRATCH: &str = ".scratch";
+
+/// Makes the names of temporary files unique within the process.
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Compiled code of a contract, which is only valid for the spec it was compiled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArtifactKey {
+    pub code_hash: B256,
+    pub spec_id: SpecId,
+}
+
+/// Shared libraries compiled from contracts, kept on disk across restarts.
+///
+/// Artifacts are laid out as `<root>/<compiler version>/<target>/<spec id>/<code hash>.so`, each
+/// with the keccak256 checksum of the library next to it, so that VMs of other versions or for
+/// other targets never see each other's code. Artifacts whose checksum does not match are evicted
+/// instead of being loaded.
+///
+/// Several processes may share a store: files are written under names unique to the writer and
+/// renamed into place, and stale files are only evicted by the process holding the lock of the
+/// root.
+#[derive(Debug, Clone)]
+pub struct ArtifactStore {
+    dir: PathBuf,
+}
+
+impl ArtifactStore {
+    /// Opens the store of `target` under `root`. Unless another process is doing it already, the
+    /// compiler versions unused for a week and the writes left behind by stopped processes are
+    /// evicted.
+    pub fn open(root: impl AsRef<Path>, target: &str) -> io::Result<Self> {
+        let root = root.as_ref();
+        let version = root.join(COMPILER_VERSION);
+        let dir = version.join(sanitize(target));
+        fs::create_dir_all(dir.join(SCRATCH))?;
+        let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+        fs::write(version.join(OPENED), now.as_secs().to_string())?;
+
+        let store = Self { dir };
+        if let Some(_lock) = Lock::acquire(root)? {
+            evict_stale_versions(root)?;
+            store.remove_stale_writes()?;
+        }
+        Ok(store)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Creates an empty dir private to the caller, where a contract is compiled before its library
+    /// is inserted. The caller removes it afterwards.
+    pub fn scratch_dir(&self) -> io::Result<PathBuf> {
+        let dir = self.dir.join(SCRATCH).join(unique_name(""));
+        fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
+    /// Where the library of `key` is kept. It may not exist yet.
+    pub fn path(&self, key: &ArtifactKey) -> PathBuf {
+        self.dir.join(format!("{:?}", key.spec_id)).join(format!("{}.so", key.code_hash))
+    }
+
+    /// The library of `key`, if it is stored and its checksum matches. A corrupted artifact is
+    /// evicted.
+    pub fn load(&self, key: &ArtifactKey) -> io::Result<Option<PathBuf>> {
+        let path = self.path(key);
+        let (library, checksum) = match (fs::read(&path), fs::read_to_string(checksum_of(&path))) {
+            (Ok(library), Ok(checksum)) => (library, checksum),
+            (Err(err), _) | (_, Err(err)) if err.kind() == io::ErrorKind::NotFound => {
+                self.evict(key)?;
+                return Ok(None);
+            }
+            (Err(err), _) | (_, Err(err)) => return Err(err),
+        };
+
+        if checksum.trim() != hex::encode(keccak256(&library)) {
+            tracing::warn!(path = %path.display(), "evicting artifact with a bad checksum");
+            self.evict(key)?;
+            return Ok(None);
+        }
+        Ok(Some(path))
+    }
+
+    /// Moves the freshly linked `library` into the store as the artifact of `key`.
+    pub fn insert(&self, key: &ArtifactKey, library: &Path) -> io::Result<PathBuf> {
+        let path = self.path(key);
+        fs::create_dir_all(path.parent().expect("artifacts are in a spec dir"))?;
+
+        // the checksum is written last, an artifact without one is incomplete
+        let checksum = hex::encode(keccak256(fs::read(library)?));
+        let partial = partial_of(&path);
+        fs::copy(library, &partial)?;
+        fs::rename(&partial, &path)?;
+        let partial = partial_of(&checksum_of(&path));
+        fs::write(&partial, checksum)?;
+        fs::rename(&partial, checksum_of(&path))?;
+        Ok(path)
+    }
+
+    /// Removes the artifact of `key`, if any.
+    pub fn evict(&self, key: &ArtifactKey) -> io::Result<()> {
+        let path = self.path(key);
+        for path in [checksum_of(&path), path] {
+            match fs::remove_file(path) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of artifacts and their total size in bytes.
+    pub fn size(&self) -> io::Result<(u64, u64)> {
+        let (mut count, mut bytes) = (0, 0);
+        for entry in self.entries()? {
+            if entry.path().extension().is_some_and(|ext| ext == "so") {
+                count += 1;
+                bytes += entry.metadata()?.len();
+            }
+        }
+        Ok((count, bytes))
+    }
+
+    /// Removes the partial writes and the scratch dirs of the processes which stopped before they
+    /// were done. Those of running processes are younger than [`STALE_WRITE_AGE`].
+    fn remove_stale_writes(&self) -> io::Result<()> {
+        for entry in self.entries()? {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "partial") && is_stale(&path)? {
+                remove_if_exists(fs::remove_file(path))?;
+            }
+        }
+        for entry in fs::read_dir(self.dir.join(SCRATCH))? {
+            let path = entry?.path();
+            if is_stale(&path)? {
+                remove_if_exists(fs::remove_dir_all(path))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The files of the spec dirs.
+    fn entries(&self) -> io::Result<Vec<fs::DirEntry>> {
+        let mut entries = Vec::new();
+        for spec_dir in fs::read_dir(&self.dir)? {
+            let spec_dir = spec_dir?;
+            // the dirs of the store itself, like the scratch dir, start with a dot
+            let hidden = spec_dir.file_name().to_string_lossy().starts_with('.');
+            if !hidden && spec_dir.file_type()?.is_dir() {
+                for entry in fs::read_dir(spec_dir.path())? {
+                    entries.push(entry?);
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+fn checksum_of(library: &Path) -> PathBuf {
+    library.with_extension("so.keccak256")
+}
+
+/// Where `path` is written before it is renamed into place, under a name unique to the writer.
+fn partial_of(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(unique_name(".partial"));
+    path.with_file_name(name)
+}
+
+/// A name unique across the processes and the threads sharing a store, ending with `suffix`.
+fn unique_name(suffix: &str) -> String {
+    let count = TEMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!(".{}.{count}{suffix}", std::process::id())
+}
+
+/// Exclusive lock of the root of a store, released when dropped.
+struct Lock(PathBuf);
+
+impl Lock {
+    /// Takes the lock, unless another process holds it. A lock left behind by a stopped process is
+    /// taken over once it is stale.
+    fn acquire(root: &Path) -> io::Result<Option<Self>> {
+        let path = root.join(LOCK);
+        for _ in 0..2 {
+            match fs::File::create_new(&path) {
+                Ok(_) => return Ok(Some(Lock(path))),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    if !is_stale(&path)? {
+                        return Ok(None);
+                    }
+                    remove_if_exists(fs::remove_file(&path))?;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Removes the dirs of the compiler versions whose stores were not opened for
+/// [`STALE_VERSION_AGE`]. Dirs without the time they were opened are aged by their modification
+/// time.
+fn evict_stale_versions(root: &Path) -> io::Result<()> {
+    let now = SystemTime::now();
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        if entry.file_name() == COMPILER_VERSION || !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let opened = fs::read_to_string(entry.path().join(OPENED))
+            .ok()
+            .and_then(|secs| secs.trim().parse().ok())
+            .map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+        let opened = match opened {
+            Some(opened) => opened,
+            None => entry.metadata()?.modified()?,
+        };
+        if now.duration_since(opened).is_ok_and(|age| age > STALE_VERSION_AGE) {
+            remove_if_exists(fs::remove_dir_all(entry.path()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether `path` was last modified more than [`STALE_WRITE_AGE`] ago. A removed path is stale.
+fn is_stale(path: &Path) -> io::Result<bool> {
+    match fs::metadata(path).and_then(|metadata| metadata.modified()) {
+        Ok(modified) => Ok(modified.elapsed().is_ok_and(|age| age > STALE_WRITE_AGE)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(true),
+        Err(err) => Err(err),
+    }
+}
+
+/// Ignores the files removed by another process in the meantime.
+fn remove_if_exists(removed: io::Result<()>) -> io::Result<()> {
+    match removed {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Keeps `name` usable as a single path component.
+fn sanitize(name: &str) -> String {
+    name.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' }).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const KEY: ArtifactKey =
+        ArtifactKey { code_hash: B256::repeat_byte(0xaa), spec_id: SpecId::CANCUN };
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("revmapi-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn insert(store: &ArtifactStore, key: &ArtifactKey, library: &[u8]) -> PathBuf {
+        let linked = store.dir().join("linked.so.partial");
+        fs::write(&linked, library).unwrap();
+        store.insert(key, &linked).unwrap()
+    }
+
+    #[test]
+    fn loads_only_valid_artifacts() {
+        let root = TempDir::new("store-load");
+        let store = ArtifactStore::open(&root.0, "x86_64-znver4").unwrap();
+        assert_eq!(store.load(&KEY).unwrap(), None);
+
+        let path = insert(&store, &KEY, b"library");
+        assert_eq!(store.load(&KEY).unwrap(), Some(path.clone()));
+        assert_eq!(store.size().unwrap(), (1, 7));
+
+        // other specs and targets have their own artifacts
+        let other_spec = ArtifactKey { spec_id: SpecId::PRAGUE, ..KEY };
+        assert_eq!(store.load(&other_spec).unwrap(), None);
+        let other_target = ArtifactStore::open(&root.0, "x86_64-skylake").unwrap();
+        assert_eq!(other_target.load(&KEY).unwrap(), None);
+
+        // corrupted artifacts are evicted
+        fs::write(&path, b"corrupted").unwrap();
+        assert_eq!(store.load(&KEY).unwrap(), None);
+        assert!(!path.exists());
+    }
+
+    fn age(path: &Path, age: Duration) {
+        let file = fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() - age).unwrap();
+    }
+
+    #[test]
+    fn evicts_stale_versions_and_partial_writes() {
+        let root = TempDir::new("store-evict");
+        let store = ArtifactStore::open(&root.0, "x86_64-znver4").unwrap();
+        insert(&store, &KEY, b"library");
+
+        let stale = root.0.join("revmapi-0.0.0-revmc-0000000");
+        fs::create_dir_all(&stale).unwrap();
+        fs::write(stale.join(OPENED), "0").unwrap();
+        let recent = root.0.join("revmapi-0.0.1-revmc-0000000");
+        fs::create_dir_all(&recent).unwrap();
+        let running = partial_of(&store.path(&KEY));
+        fs::write(&running, b"lib").unwrap();
+        let stopped = partial_of(&store.path(&KEY));
+        fs::write(&stopped, b"lib").unwrap();
+        age(&stopped, 2 * STALE_WRITE_AGE);
+
+        // nothing is evicted while another process holds the lock
+        fs::write(root.0.join(LOCK), b"").unwrap();
+        ArtifactStore::open(&root.0, "x86_64-znver4").unwrap();
+        assert!(stale.exists() && stopped.exists());
+        fs::remove_file(root.0.join(LOCK)).unwrap();
+
+        let store = ArtifactStore::open(&root.0, "x86_64-znver4").unwrap();
+        assert!(!stale.exists() && recent.exists());
+        assert!(running.exists() && !stopped.exists());
+        assert!(!root.0.join(LOCK).exists());
+        assert_eq!(store.size().unwrap(), (1, 7));
+        assert!(store.load(&KEY).unwrap().is_some());
+    }
+
+    #[test]
+    fn takes_over_stale_locks() {
+        let root = TempDir::new("store-lock");
+        fs::create_dir_all(&root.0).unwrap();
+        fs::write(root.0.join(LOCK), b"").unwrap();
+        assert!(Lock::acquire(&root.0).unwrap().is_none());
+
+        age(&root.0.join(LOCK), 2 * STALE_WRITE_AGE);
+        let lock = Lock::acquire(&root.0).unwrap();
+        assert!(lock.is_some());
+        drop(lock);
+        assert!(!root.0.join(LOCK).exists());
+    }
+}
@@ -0,0 +1,578 @@
+use std::{
+    collections::VecDeque,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, Condvar, Mutex},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use alloy_primitives::{hex, keccak256, map::HashMap, Bytes, B256};
+use libloading::Library;
+use revm::primitives::{Bytecode, SpecId};
+use revmc::{
+    llvm::{inkwell::targets::TargetMachine, with_llvm_context},
+    EvmCompiler, EvmCompilerFn, EvmLlvmBackend, Linker, OptimizationLevel, RawEvmCompilerFn,
+};
+
+use crate::v1::types::{
+    CompileStatus, CompilerConfig, CompilerStats, ContractCompileState, ContractCompileStates,
+    OptLevel, SpecIdPolicy,
+};
+
+use super::store::{ArtifactKey, ArtifactStore};
+
+/// External context of the VMs created with the AOT compiler.
+///
+/// Contracts are interpreted until they have been called as many times as the threshold of the
+/// [`CompilerConfig`], then compiled to native code in the background. Compiled contracts are kept
+/// in an [`ArtifactStore`], so they are loaded instead of compiled again after a restart.
+///
+/// Clones share the compile threads and the compiled code, so the VMs given clones of one worker
+/// compile each contract once.
+#[derive(Clone)]
+pub struct EXTCompileWorker {
+    worker: Arc<Worker>,
+    threads: Arc<Threads>,
+}
+
+/// Shuts the compile threads down once the last clone of the worker is dropped.
+struct Threads {
+    worker: Arc<Worker>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+/// A compiled contract, which must be kept alive while its function runs.
+pub struct Compiled {
+    pub function: EvmCompilerFn,
+    _library: Library,
+}
+
+struct Worker {
+    config: CompilerConfig,
+    // spec of the `SpecIdPolicy` of the config
+    spec_id: SpecId,
+    store: ArtifactStore,
+    state: Mutex<State>,
+    // signaled when a contract is queued or the worker shuts down
+    queued: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    contracts: HashMap<ArtifactKey, Contract>,
+    queue: VecDeque<(ArtifactKey, Bytes)>,
+    total_compile_time: Duration,
+    max_compile_time: Duration,
+    shutdown: bool,
+}
+
+struct Contract {
+    calls: u64,
+    status: Status,
+    compile_time: Duration,
+}
+
+enum Status {
+    Counting,
+    Queued,
+    Compiling,
+    Compiled(Arc<Compiled>),
+    Failed(String),
+}
+
+impl EXTCompileWorker {
+    /// Starts the compile threads and opens the store of the config, for a VM of `spec_id`.
+    pub fn new(config: CompilerConfig, spec_id: SpecId) -> io::Result<Self> {
+        let spec_id = match config.spec_id {
+            None => spec_id,
+            Some(id) => u8::try_from(id).ok().and_then(SpecId::try_from_u8).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, format!("Unknown spec id {id}"))
+            })?,
+        };
+        let store_dir = match config.store_dir.as_str() {
+            "" => std::env::var_os("HOME").map(PathBuf::from).unwrap_or_default().join(".aotstore"),
+            dir => PathBuf::from(dir),
+        };
+        // code generated with other options is another artifact
+        let target = format!("{}-{}", host_target(), codegen_options(&config));
+        let store = ArtifactStore::open(store_dir, &target)?;
+
+        let threads = config.max_concurrent_compiles.max(1);
+        let worker = Arc::new(Worker {
+            config,
+            spec_id,
+            store,
+            state: Mutex::default(),
+            queued: Condvar::new(),
+        });
+        let handles = (0..threads)
+            .map(|_| {
+                let worker = worker.clone();
+                thread::spawn(move || worker.run())
+            })
+            .collect();
+
+        let threads = Arc::new(Threads { worker: worker.clone(), handles });
+        Ok(Self { worker, threads })
+    }
+
+    /// The compiled code of the contract, counting the call. The contract is queued for
+    /// compilation once it reaches the threshold, or right away when it is in the store already.
+    pub fn get_function(&self, key: ArtifactKey, bytecode: &Bytecode) -> Option<Arc<Compiled>> {
+        let worker = &self.worker;
+        if !worker.compiles_for(key.spec_id) {
+            return None;
+        }
+        let mut state = worker.state.lock().unwrap();
+        let state = &mut *state;
+
+        let contract = state.contracts.entry(key).or_insert_with(|| Contract {
+            calls: 0,
+            compile_time: Duration::ZERO,
+            // a stored artifact is validated and loaded by the compile threads
+            status: if worker.store.path(&key).exists() {
+                Status::Queued
+            } else {
+                Status::Counting
+            },
+        });
+        contract.calls += 1;
+        match contract.status {
+            Status::Compiled(ref compiled) => return Some(compiled.clone()),
+            Status::Queued if contract.calls == 1 => {}
+            Status::Counting if contract.calls >= worker.config.threshold => {
+                contract.status = worker.queued_status(bytecode);
+                if !matches!(contract.status, Status::Queued) {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+
+        state.queue.push_back((key, bytecode.original_bytes()));
+        worker.queued.notify_one();
+        None
+    }
+
+    /// Queues the contract ahead of the others, whatever its call count. Contracts which are
+    /// compiled or being compiled are left alone, failed ones are compiled again.
+    pub fn force_compile(&self, key: ArtifactKey, bytecode: &Bytecode) {
+        let mut state = self.worker.state.lock().unwrap();
+        let contract = state.contracts.entry(key).or_insert_with(|| Contract {
+            calls: 0,
+            status: Status::Counting,
+            compile_time: Duration::ZERO,
+        });
+        if matches!(contract.status, Status::Counting | Status::Failed(_)) {
+            contract.status = self.worker.queued_status(bytecode);
+            if !matches!(contract.status, Status::Queued) {
+                return;
+            }
+            state.queue.push_front((key, bytecode.original_bytes()));
+            self.worker.queued.notify_one();
+        }
+    }
+
+    /// Forgets the contract and removes its artifact from the store, so that it is counted from
+    /// zero again. Frames already running its compiled code are not affected.
+    pub fn evict(&self, key: ArtifactKey) -> io::Result<()> {
+        let mut state = self.worker.state.lock().unwrap();
+        state.contracts.remove(&key);
+        state.queue.retain(|(queued, _)| *queued != key);
+        self.worker.store.evict(&key)
+    }
+
+    pub fn state(&self, key: ArtifactKey) -> ContractCompileState {
+        let state = self.worker.state.lock().unwrap();
+        match state.contracts.get(&key) {
+            Some(contract) => contract.to_proto(key.code_hash),
+            None => ContractCompileState {
+                code_hash: key.code_hash.to_vec(),
+                status: CompileStatus::NotSeen.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// The contracts seen for `spec_id`, with their call counts.
+    pub fn states(&self, spec_id: SpecId) -> ContractCompileStates {
+        let state = self.worker.state.lock().unwrap();
+        let contracts = state
+            .contracts
+            .iter()
+            .filter(|(key, _)| key.spec_id == spec_id)
+            .map(|(key, contract)| contract.to_proto(key.code_hash))
+            .collect();
+        ContractCompileStates { contracts }
+    }
+
+    pub fn stats(&self) -> io::Result<CompilerStats> {
+        let mut stats = {
+            let state = self.worker.state.lock().unwrap();
+            let mut stats = CompilerStats {
+                threshold: self.worker.config.threshold,
+                queue_length: state.queue.len() as u64,
+                total_compile_time_us: state.total_compile_time.as_micros() as u64,
+                max_compile_time_us: state.max_compile_time.as_micros() as u64,
+                ..Default::default()
+            };
+            for contract in state.contracts.values() {
+                match contract.status {
+                    Status::Compiling => stats.compiling += 1,
+                    Status::Compiled(_) => stats.compiled += 1,
+                    Status::Failed(_) => stats.failed += 1,
+                    Status::Counting | Status::Queued => {}
+                }
+            }
+            stats
+        };
+        // the store is read without holding the lock
+        (stats.store_artifacts, stats.store_bytes) = self.worker.store.size()?;
+        Ok(stats)
+    }
+}
+
+impl Drop for Threads {
+    fn drop(&mut self) {
+        self.worker.state.lock().unwrap().shutdown = true;
+        self.worker.queued.notify_all();
+        // queued contracts are left to the next run, the running compiles are waited for
+        for thread in self.handles.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Worker {
+    fn compiles_for(&self, spec_id: SpecId) -> bool {
+        compiles_for(self.config.spec_id_policy(), self.spec_id, spec_id)
+    }
+
+    /// The status of a contract which is due for compilation, which fails right away when its
+    /// code is larger than the cap.
+    fn queued_status(&self, bytecode: &Bytecode) -> Status {
+        let size = bytecode.original_byte_slice().len() as u64;
+        match self.config.max_code_size {
+            max if max != 0 && size > max => {
+                Status::Failed(format!("code size {size} exceeds the cap of {max}"))
+            }
+            _ => Status::Queued,
+        }
+    }
+
+    fn run(&self) {
+        while let Some((key, bytecode)) = self.next() {
+            let started = Instant::now();
+            let status = match self.compile(&key, &bytecode) {
+                Ok(compiled) => Status::Compiled(Arc::new(compiled)),
+                Err(err) => {
+                    tracing::warn!(code_hash = %key.code_hash, "failed to compile contract: {err}");
+                    Status::Failed(err)
+                }
+            };
+            let compile_time = started.elapsed();
+
+            let mut state = self.state.lock().unwrap();
+            state.total_compile_time += compile_time;
+            state.max_compile_time = state.max_compile_time.max(compile_time);
+            match state.contracts.get_mut(&key) {
+                Some(contract) => {
+                    contract.status = status;
+                    contract.compile_time = compile_time;
+                }
+                // evicted while it was compiled
+                None => {
+                    let _ = self.store.evict(&key);
+                }
+            }
+        }
+    }
+
+    fn next(&self) -> Option<(ArtifactKey, Bytes)> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if state.shutdown {
+                return None;
+            }
+            if let Some((key, bytecode)) = state.queue.pop_front() {
+                if let Some(contract) = state.contracts.get_mut(&key) {
+                    contract.status = Status::Compiling;
+                }
+                return Some((key, bytecode));
+            }
+            state = self.queued.wait(state).unwrap();
+        }
+    }
+
+    /// Loads the stored artifact of the contract, compiling it first if there is none.
+    fn compile(&self, key: &ArtifactKey, bytecode: &Bytes) -> Result<Compiled, String> {
+        let name = symbol_name(key);
+        let path = match self.store.load(key).map_err(|err| err.to_string())? {
+            Some(path) => path,
+            None => {
+                let scratch = self.store.scratch_dir().map_err(|err| err.to_string())?;
+                let started = Instant::now();
+                let path =
+                    translate(&self.config, key, bytecode, &name, &scratch).and_then(|library| {
+                        let timeout = Duration::from_millis(self.config.compile_timeout_ms);
+                        if !timeout.is_zero() && started.elapsed() > timeout {
+                            return Err(format!("compilation timed out after {timeout:?}"));
+                        }
+                        self.store.insert(key, &library).map_err(|err| err.to_string())
+                    });
+                let _ = fs::remove_dir_all(scratch);
+                path?
+            }
+        };
+
+        let library = unsafe { Library::new(&path) }.map_err(|err| err.to_string())?;
+        let function = unsafe { library.get::<RawEvmCompilerFn>(name.as_bytes()) }
+            .map(|function| EvmCompilerFn::new(*function))
+            .map_err(|err| err.to_string())?;
+        Ok(Compiled { function, _library: library })
+    }
+}
+
+/// Compiles the contract into a shared library in `dir`, a scratch dir of the store.
+fn translate(
+    config: &CompilerConfig,
+    key: &ArtifactKey,
+    bytecode: &Bytes,
+    name: &str,
+    dir: &Path,
+) -> Result<PathBuf, String> {
+    let object = dir.join(format!("{name}.o"));
+    let library = dir.join(format!("{name}.so"));
+
+    with_llvm_context(|cx| {
+        let opt_level = match opt_level(config) {
+            OptLevel::None => OptimizationLevel::None,
+            OptLevel::Less => OptimizationLevel::Less,
+            OptLevel::Default => OptimizationLevel::Default,
+            OptLevel::Unspecified | OptLevel::Aggressive => OptimizationLevel::Aggressive,
+        };
+        let backend = EvmLlvmBackend::new(cx, true, opt_level)?;
+        let mut compiler = EvmCompiler::new(backend);
+        compiler.gas_metering(!config.disable_gas_metering);
+        compiler.debug_assertions(config.debug_assertions);
+        // SAFETY: disabling the checks is an explicit choice of the operator, for contracts known
+        // not to overflow or underflow the stack
+        unsafe { compiler.stack_bound_checks(!config.disable_stack_bound_checks) };
+        compiler.translate(name, &bytecode[..], key.spec_id)?;
+        compiler.write_object_to_file(&object)
+    })
+    .map_err(|err| err.to_string())?;
+
+    let linked = Linker::new().link(&library, [object.as_os_str()]);
+    let _ = fs::remove_file(&object);
+    linked.map_err(|err| err.to_string())?;
+    Ok(library)
+}
+
+impl Contract {
+    fn to_proto(&self, code_hash: B256) -> ContractCompileState {
+        let (status, error) = match &self.status {
+            Status::Counting => (CompileStatus::Counting, String::new()),
+            Status::Queued => (CompileStatus::Queued, String::new()),
+            Status::Compiling => (CompileStatus::Compiling, String::new()),
+            Status::Compiled(_) => (CompileStatus::Compiled, String::new()),
+            Status::Failed(err) => (CompileStatus::Failed, err.clone()),
+        };
+        ContractCompileState {
+            code_hash: code_hash.to_vec(),
+            status: status.into(),
+            calls: self.calls,
+            compile_time_us: self.compile_time.as_micros() as u64,
+            error,
+        }
+    }
+}
+
+/// Whether the contracts run under `spec_id` are compiled with `policy`, for `target` the spec the
+/// policy refers to.
+fn compiles_for(policy: SpecIdPolicy, target: SpecId, spec_id: SpecId) -> bool {
+    match policy {
+        SpecIdPolicy::Unspecified | SpecIdPolicy::Any => true,
+        SpecIdPolicy::Exact => spec_id == target,
+        SpecIdPolicy::AtLeast => SpecId::enabled(spec_id, target),
+    }
+}
+
+/// The optimization level of the config, the unspecified one being the default.
+fn opt_level(config: &CompilerConfig) -> OptLevel {
+    match config.opt_level() {
+        OptLevel::Unspecified => OptLevel::Aggressive,
+        opt_level => opt_level,
+    }
+}
+
+/// The options which change the generated code.
+fn codegen_options(config: &CompilerConfig) -> String {
+    format!(
+        "{:?}-gas{}-stack{}-debug{}",
+        opt_level(config),
+        !config.disable_gas_metering as u8,
+        !config.disable_stack_bound_checks as u8,
+        config.debug_assertions as u8
+    )
+}
+
+fn symbol_name(key: &ArtifactKey) -> String {
+    format!("contract_{}", hex::encode(key.code_hash))
+}
+
+/// The CPU the code is generated for. Artifacts compiled for another CPU, or with other features
+/// enabled, may not run on this one.
+fn host_target() -> String {
+    let cpu = TargetMachine::get_host_cpu_name();
+    let features = TargetMachine::get_host_cpu_features();
+    format!(
+        "{}-{}-{}",
+        std::env::consts::ARCH,
+        cpu.to_string_lossy(),
+        &hex::encode(keccak256(features.to_bytes()))[..16]
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // PUSH1 0x2a PUSH0 SSTORE STOP
+    const CODE: [u8; 5] = [0x60, 0x2a, 0x5f, 0x55, 0x00];
+
+    fn config(name: &str, threshold: u64) -> CompilerConfig {
+        let dir = std::env::temp_dir().join(format!("revmapi-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        CompilerConfig {
+            threshold,
+            store_dir: dir.to_string_lossy().into_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn key(bytecode: &Bytecode) -> ArtifactKey {
+        ArtifactKey { code_hash: bytecode.hash_slow(), spec_id: SpecId::CANCUN }
+    }
+
+    /// The state of the contract once the compile threads are done with it.
+    fn compiled_state(worker: &EXTCompileWorker, key: ArtifactKey) -> ContractCompileState {
+        let started = Instant::now();
+        loop {
+            let state = worker.state(key);
+            let done = matches!(state.status(), CompileStatus::Compiled | CompileStatus::Failed);
+            if done || started.elapsed() > Duration::from_secs(120) {
+                return state;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn compiles_contracts_after_the_threshold() {
+        let config = config("worker-threshold", 2);
+        let store_dir = config.store_dir.clone();
+        let bytecode = Bytecode::new_raw(Bytes::from_static(&CODE));
+        let key = key(&bytecode);
+
+        let worker = EXTCompileWorker::new(config.clone(), SpecId::CANCUN).unwrap();
+        assert!(worker.get_function(key, &bytecode).is_none());
+        assert_eq!(worker.state(key).status(), CompileStatus::Counting);
+        assert!(worker.get_function(key, &bytecode).is_none());
+
+        let state = compiled_state(&worker, key);
+        assert_eq!(state.status(), CompileStatus::Compiled, "{}", state.error);
+        assert!(worker.get_function(key, &bytecode).is_some());
+        assert_eq!(worker.state(key).calls, 3);
+        assert_eq!(worker.stats().unwrap().store_artifacts, 1);
+        drop(worker);
+
+        // a stored artifact is loaded on the first call
+        let worker = EXTCompileWorker::new(config, SpecId::CANCUN).unwrap();
+        assert!(worker.get_function(key, &bytecode).is_none());
+        assert_eq!(compiled_state(&worker, key).status(), CompileStatus::Compiled);
+
+        worker.evict(key).unwrap();
+        assert_eq!(worker.state(key).status(), CompileStatus::NotSeen);
+        assert_eq!(worker.stats().unwrap().store_artifacts, 0);
+        drop(worker);
+        let _ = fs::remove_dir_all(store_dir);
+    }
+
+    #[test]
+    fn fails_contracts_over_the_size_cap() {
+        let config = CompilerConfig { max_code_size: 4, ..config("worker-cap", 0) };
+        let store_dir = config.store_dir.clone();
+        let bytecode = Bytecode::new_raw(Bytes::from_static(&CODE));
+        let key = key(&bytecode);
+
+        let worker = EXTCompileWorker::new(config, SpecId::CANCUN).unwrap();
+        assert!(worker.get_function(key, &bytecode).is_none());
+        let state = worker.state(key);
+        assert_eq!(state.status(), CompileStatus::Failed);
+        assert_eq!(state.error, "code size 5 exceeds the cap of 4");
+
+        // forcing it does not lift the cap
+        worker.force_compile(key, &bytecode);
+        assert_eq!(worker.state(key).status(), CompileStatus::Failed);
+        assert_eq!(worker.stats().unwrap().queue_length, 0);
+        drop(worker);
+        let _ = fs::remove_dir_all(store_dir);
+    }
+
+    #[test]
+    fn compiles_for_the_specs_of_the_policy() {
+        let (target, earlier, later) = (SpecId::CANCUN, SpecId::SHANGHAI, SpecId::PRAGUE);
+        for policy in [SpecIdPolicy::Unspecified, SpecIdPolicy::Any] {
+            assert!([earlier, target, later]
+                .iter()
+                .all(|spec| compiles_for(policy, target, *spec)));
+        }
+        let exact = SpecIdPolicy::Exact;
+        assert!(compiles_for(exact, target, target));
+        assert!(!compiles_for(exact, target, earlier) && !compiles_for(exact, target, later));
+        let at_least = SpecIdPolicy::AtLeast;
+        assert!(compiles_for(at_least, target, target) && compiles_for(at_least, target, later));
+        assert!(!compiles_for(at_least, target, earlier));
+    }
+
+    #[test]
+    fn resolves_the_spec_of_the_policy() {
+        let config = config("worker-spec", 0);
+        let store_dir = config.store_dir.clone();
+        let exact = CompilerConfig { spec_id_policy: SpecIdPolicy::Exact.into(), ..config };
+
+        // the spec of the VM unless the config sets one
+        let worker = EXTCompileWorker::new(exact.clone(), SpecId::CANCUN).unwrap();
+        assert!(worker.worker.compiles_for(SpecId::CANCUN));
+        assert!(!worker.worker.compiles_for(SpecId::FRONTIER));
+        drop(worker);
+
+        let shanghai = CompilerConfig { spec_id: Some(SpecId::SHANGHAI as u32), ..exact.clone() };
+        let worker = EXTCompileWorker::new(shanghai, SpecId::CANCUN).unwrap();
+        assert!(worker.worker.compiles_for(SpecId::SHANGHAI));
+        assert!(!worker.worker.compiles_for(SpecId::CANCUN));
+        drop(worker);
+
+        let unknown = CompilerConfig { spec_id: Some(1000), ..exact };
+        assert!(EXTCompileWorker::new(unknown, SpecId::CANCUN).is_err());
+        let _ = fs::remove_dir_all(store_dir);
+    }
+
+    #[test]
+    fn defaults_unspecified_options() {
+        let aggressive =
+            CompilerConfig { opt_level: OptLevel::Aggressive.into(), ..Default::default() };
+        assert_eq!(codegen_options(&CompilerConfig::default()), codegen_options(&aggressive));
+        assert_eq!(codegen_options(&aggressive), "Aggressive-gas1-stack1-debug0");
+
+        let config = CompilerConfig {
+            opt_level: OptLevel::None.into(),
+            disable_gas_metering: true,
+            debug_assertions: true,
+            ..Default::default()
+        };
+        assert_eq!(codegen_options(&config), "None-gas0-stack1-debug1");
+    }
+}
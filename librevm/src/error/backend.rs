@@ -19,6 +19,9 @@ pub enum BackendError {
     #[error("User error during call into backend: {msg}")]
     UserErr { msg: String },
 
+    #[error("Cannot decode the {what}: {msg}")]
+    Decode { what: &'static str, msg: String },
+
     #[error("Unreachable Call")]
     UnreachableCall {},
 }
@@ -47,6 +50,10 @@ impl BackendError {
     pub fn user_err(msg: impl Into<String>) -> Self {
         BackendError::UserErr { msg: msg.into() }
     }
+
+    pub fn decode(what: &'static str, err: impl ToString) -> Self {
+        BackendError::Decode { what, msg: err.to_string() }
+    }
 }
 
 impl From<FromUtf8Error> for BackendError {
@@ -1,7 +1,9 @@
 mod backend;
 mod go;
+mod panic;
 mod rust;
 
 pub use backend::*;
 pub use go::*;
+pub use panic::*;
 pub use rust::*;
@@ -0,0 +1,111 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+};
+
+use errno::{set_errno, Errno};
+
+use crate::memory::UnmanagedVector;
+
+use super::ErrnoValue;
+
+/// Runs the body of the FFI entry point `name`, so that a panic never unwinds into Go, which
+/// would abort the process.
+///
+/// A panic is reported like an error: errno is set to [`ErrnoValue::Panic`], the panic message is
+/// written to `errmsg`, along with the backtrace when built with the `backtraces` feature, and
+/// `T::default()` is returned.
+pub fn catch_panic<T: Default>(
+    name: &str,
+    errmsg: Option<&mut UnmanagedVector>,
+    f: impl FnOnce(Option<&mut UnmanagedVector>) -> T,
+) -> T {
+    #[cfg(feature = "backtraces")]
+    backtrace::install_hook();
+
+    // the body borrows the error message, which is written again if it panics
+    let errmsg = errmsg.map_or(std::ptr::null_mut(), |errmsg| errmsg as *mut UnmanagedVector);
+    match panic::catch_unwind(AssertUnwindSafe(|| f(unsafe { errmsg.as_mut() }))) {
+        Ok(value) => value,
+        Err(payload) => {
+            let msg = panic_message(name, payload);
+            tracing::error!("{msg}");
+            if let Some(errmsg) = unsafe { errmsg.as_mut() } {
+                let _ = std::mem::replace(errmsg, UnmanagedVector::new(Some(msg.into_bytes())))
+                    .consume();
+            }
+            set_errno(Errno(ErrnoValue::Panic as i32));
+            T::default()
+        }
+    }
+}
+
+fn panic_message(name: &str, payload: Box<dyn Any + Send>) -> String {
+    let reason = match payload.downcast::<String>() {
+        Ok(reason) => *reason,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(reason) => reason.to_string(),
+            Err(_) => "unknown reason".to_string(),
+        },
+    };
+    #[allow(unused_mut)]
+    let mut msg = format!("Panic in {name}: {reason}");
+    #[cfg(feature = "backtraces")]
+    if let Some(backtrace) = backtrace::take() {
+        msg = format!("{msg}\n{backtrace}");
+    }
+    msg
+}
+
+#[cfg(feature = "backtraces")]
+mod backtrace {
+    use std::{backtrace::Backtrace, cell::RefCell, panic, sync::Once};
+
+    thread_local! {
+        // captured where the last panic of the thread happened, before it unwinds
+        static LAST: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+    }
+
+    pub fn install_hook() {
+        static HOOK: Once = Once::new();
+        HOOK.call_once(|| {
+            let prev = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                LAST.with(|last| *last.borrow_mut() = Some(Backtrace::force_capture()));
+                prev(info)
+            }));
+        });
+    }
+
+    pub fn take() -> Option<Backtrace> {
+        LAST.with(|last| last.borrow_mut().take())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use errno::errno;
+
+    use super::*;
+
+    #[test]
+    fn reports_panics_as_errors() {
+        let mut errmsg = UnmanagedVector::default();
+        let result: Option<u8> = catch_panic("test_entry", Some(&mut errmsg), |errmsg| {
+            *errmsg.unwrap() = UnmanagedVector::new(Some(b"overwritten".to_vec()));
+            panic!("boom {}", 42)
+        });
+
+        assert_eq!(result, None);
+        assert_eq!(errno().0, ErrnoValue::Panic as i32);
+        let msg = String::from_utf8(errmsg.consume().unwrap()).unwrap();
+        assert!(msg.starts_with("Panic in test_entry: boom 42"), "{msg}");
+    }
+
+    #[test]
+    fn returns_the_value_otherwise() {
+        let mut errmsg = UnmanagedVector::default();
+        assert_eq!(catch_panic("test_entry", Some(&mut errmsg), |_| 7), 7);
+        assert_eq!(errmsg.consume(), None);
+    }
+}
@@ -0,0 +1,195 @@
+use revm::{
+    interpreter::gas::CALL_STIPEND,
+    primitives::{EVMError, ExecutionResult, InvalidTransaction, TxKind, U256},
+    Database, Evm,
+};
+
+/// Gas of a plain value transfer.
+const TX_GAS: u64 = 21_000;
+/// The search stops once the bounds are within this ratio of each other, as in geth.
+const ERROR_RATIO: f64 = 0.015;
+
+#[derive(Debug, PartialEq)]
+pub enum GasEstimate {
+    /// Lowest gas limit the transaction succeeds with.
+    Gas(u64),
+    /// The transaction reverts or halts even with the highest allowed gas limit.
+    Failure(ExecutionResult),
+}
+
+/// Binary searches the lowest gas limit the transaction in `evm` succeeds with, equivalent to
+/// geth's `eth_estimateGas`. `gas_cap` bounds the search when it is not zero.
+///
+/// Nothing is committed, so the database of `evm` should cache reads for the repeated runs.
+pub fn estimate_gas<EXT, DB: Database>(
+    evm: &mut Evm<'_, EXT, DB>,
+    gas_cap: u64,
+) -> Result<GasEstimate, EVMError<DB::Error>> {
+    let tx = evm.tx().clone();
+
+    let mut hi = if tx.gas_limit >= TX_GAS {
+        tx.gas_limit
+    } else {
+        evm.block().gas_limit.saturating_to::<u64>()
+    };
+    // the caller has to afford the gas limit
+    if !tx.gas_price.is_zero() {
+        let balance = evm
+            .db_mut()
+            .basic(tx.caller)
+            .map_err(EVMError::Database)?
+            .map(|info| info.balance)
+            .unwrap_or_default();
+        let allowance = balance.saturating_sub(tx.value) / tx.gas_price;
+        hi = hi.min(allowance.saturating_to::<u64>());
+    }
+    if gas_cap != 0 {
+        hi = hi.min(gas_cap);
+    }
+
+    // plain transfers are tried with the transfer gas first
+    if let TxKind::Call(to) = tx.transact_to {
+        if tx.data.is_empty() {
+            let code_is_empty = evm
+                .db_mut()
+                .basic(to)
+                .map_err(EVMError::Database)?
+                .map_or(true, |info| info.is_empty_code_hash());
+            if code_is_empty && !fails_with(evm, TX_GAS)? {
+                return Ok(GasEstimate::Gas(TX_GAS));
+            }
+        }
+    }
+
+    // the transaction has to succeed with the highest allowed gas limit
+    let result = run(evm, hi)?;
+    let (gas_used, gas_refunded) = match result {
+        ExecutionResult::Success { gas_used, gas_refunded, .. } => (gas_used, gas_refunded),
+        _ => return Ok(GasEstimate::Failure(result)),
+    };
+    let mut lo = gas_used.saturating_sub(1);
+
+    // most transactions need about the gas they used plus what the 63/64 rule withholds from
+    // the deepest call, so that limit is tried before searching
+    let optimistic =
+        gas_used.saturating_add(gas_refunded).saturating_add(CALL_STIPEND).saturating_mul(64) / 63;
+    if optimistic < hi {
+        if fails_with(evm, optimistic)? {
+            lo = optimistic;
+        } else {
+            hi = optimistic;
+        }
+    }
+
+    while lo + 1 < hi {
+        if (hi - lo) as f64 / (hi as f64) < ERROR_RATIO {
+            break;
+        }
+        // skew towards the lower bound, the cap is usually far above the needed gas
+        let mid = (lo + (hi - lo) / 2).min(lo.saturating_mul(2));
+        if fails_with(evm, mid)? {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Ok(GasEstimate::Gas(hi))
+}
+
+fn run<EXT, DB: Database>(
+    evm: &mut Evm<'_, EXT, DB>,
+    gas_limit: u64,
+) -> Result<ExecutionResult, EVMError<DB::Error>> {
+    evm.tx_mut().gas_limit = gas_limit;
+    evm.transact().map(|res| res.result)
+}
+
+/// Whether the transaction fails with `gas_limit`. Limits below the intrinsic gas count as
+/// failures rather than errors.
+fn fails_with<EXT, DB: Database>(
+    evm: &mut Evm<'_, EXT, DB>,
+    gas_limit: u64,
+) -> Result<bool, EVMError<DB::Error>> {
+    match run(evm, gas_limit) {
+        Ok(result) => Ok(!result.is_success()),
+        Err(EVMError::Transaction(InvalidTransaction::CallGasCostMoreThanGasLimit)) => Ok(true),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::{address, Bytes};
+    use revm::{
+        db::InMemoryDB,
+        primitives::{AccountInfo, Bytecode},
+    };
+
+    use super::*;
+
+    const CALLER: alloy_primitives::Address = address!("1000000000000000000000000000000000000001");
+    const CALLEE: alloy_primitives::Address = address!("2000000000000000000000000000000000000002");
+
+    fn evm_with_callee_code(code: &[u8]) -> Evm<'static, (), InMemoryDB> {
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            CALLER,
+            AccountInfo { balance: U256::from(10).pow(U256::from(18)), ..Default::default() },
+        );
+        if !code.is_empty() {
+            let code = Bytecode::new_raw(Bytes::copy_from_slice(code));
+            db.insert_account_info(
+                CALLEE,
+                AccountInfo { code_hash: code.hash_slow(), code: Some(code), ..Default::default() },
+            );
+        }
+        Evm::builder()
+            .with_db(db)
+            .modify_tx_env(|tx| {
+                tx.caller = CALLER;
+                tx.transact_to = TxKind::Call(CALLEE);
+                tx.gas_price = U256::from(1);
+            })
+            .build()
+    }
+
+    #[test]
+    fn estimate_gas_of_transfer() {
+        let mut evm = evm_with_callee_code(&[]);
+        assert_eq!(estimate_gas(&mut evm, 0).unwrap(), GasEstimate::Gas(TX_GAS));
+    }
+
+    #[test]
+    fn estimate_gas_within_error_ratio() {
+        // PUSH1 0x01 PUSH1 0x00 SSTORE STOP
+        let mut evm = evm_with_callee_code(&[0x60, 0x01, 0x60, 0x00, 0x55, 0x00]);
+        let GasEstimate::Gas(estimate) = estimate_gas(&mut evm, 0).unwrap() else {
+            panic!("estimation failed");
+        };
+
+        evm.tx_mut().gas_limit = estimate;
+        let used = evm.transact().unwrap().result;
+        assert!(used.is_success());
+        assert!(estimate - used.gas_used() <= (estimate as f64 * ERROR_RATIO) as u64 + 1);
+    }
+
+    #[test]
+    fn estimate_gas_reports_revert() {
+        // PUSH0 PUSH0 REVERT
+        let mut evm = evm_with_callee_code(&[0x5f, 0x5f, 0xfd]);
+        assert!(matches!(
+            estimate_gas(&mut evm, 0).unwrap(),
+            GasEstimate::Failure(ExecutionResult::Revert { .. })
+        ));
+    }
+
+    #[test]
+    fn estimate_gas_respects_cap() {
+        let mut evm = evm_with_callee_code(&[0x60, 0x01, 0x60, 0x00, 0x55, 0x00]);
+        assert!(matches!(
+            estimate_gas(&mut evm, 21_000).unwrap(),
+            GasEstimate::Failure(ExecutionResult::Halt { .. })
+        ));
+    }
+}
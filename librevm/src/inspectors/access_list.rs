@@ -0,0 +1,183 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use alloy_primitives::{Address, B256};
+use revm::{
+    interpreter::{opcode, Interpreter},
+    precompile::{PrecompileSpecId, Precompiles},
+    primitives::{
+        AccessListItem, EVMError, ExecutionResult, ResultAndState, SpecId, TxEnv, TxKind,
+    },
+    Database, EvmContext, Inspector,
+};
+
+/// Most runs of the transaction by [`create_access_list`]. The list of a transaction whose touched
+/// keys depend on the list itself, e.g. through the gas left, may never settle.
+const MAX_RUNS: usize = 16;
+
+/// Records every address and storage slot touched by the transaction, equivalent to geth's
+/// `AccessListTracer`.
+#[derive(Debug, Default)]
+pub struct AccessListInspector {
+    // addresses which are warm anyway: sender, recipient and precompiles
+    excluded: HashSet<Address>,
+    access_list: BTreeMap<Address, BTreeSet<B256>>,
+}
+
+impl AccessListInspector {
+    /// Creates an inspector that starts from the given `access_list`.
+    pub fn new(
+        access_list: &[AccessListItem],
+        excluded: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        Self {
+            excluded: excluded.into_iter().collect(),
+            access_list: access_list
+                .iter()
+                .map(|item| (item.address, item.storage_keys.iter().copied().collect()))
+                .collect(),
+        }
+    }
+
+    pub fn into_access_list(self) -> Vec<AccessListItem> {
+        self.access_list
+            .into_iter()
+            .map(|(address, keys)| AccessListItem {
+                address,
+                storage_keys: keys.into_iter().collect(),
+            })
+            .collect()
+    }
+
+    fn add_address(&mut self, address: Address) {
+        if !self.excluded.contains(&address) {
+            self.access_list.entry(address).or_default();
+        }
+    }
+}
+
+impl<DB: Database> Inspector<DB> for AccessListInspector {
+    fn step(&mut self, interp: &mut Interpreter, _context: &mut EvmContext<DB>) {
+        match interp.current_opcode() {
+            opcode::SLOAD | opcode::SSTORE => {
+                if let Ok(slot) = interp.stack.peek(0) {
+                    self.access_list
+                        .entry(interp.contract.target_address)
+                        .or_default()
+                        .insert(B256::from(slot));
+                }
+            }
+            opcode::EXTCODECOPY
+            | opcode::EXTCODEHASH
+            | opcode::EXTCODESIZE
+            | opcode::BALANCE
+            | opcode::SELFDESTRUCT => {
+                if let Ok(word) = interp.stack.peek(0) {
+                    self.add_address(Address::from_word(B256::from(word)));
+                }
+            }
+            opcode::DELEGATECALL | opcode::CALL | opcode::STATICCALL | opcode::CALLCODE => {
+                if let Ok(word) = interp.stack.peek(1) {
+                    self.add_address(Address::from_word(B256::from(word)));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Generates the access list of `tx`, equivalent to geth's `eth_createAccessList`: the transaction
+/// is run again with the list of the previous run until the list stops changing. `transact` runs
+/// the transaction with the inspector attached, without committing it.
+///
+/// The sender, the recipient and the precompiles, including the custom `precompiles`, are warm
+/// without being listed. The nonce of the creator of a contract is read from `db` when `tx` has
+/// none.
+pub fn create_access_list<DB: Database>(
+    db: &mut DB,
+    spec_id: SpecId,
+    mut tx: TxEnv,
+    precompiles: impl IntoIterator<Item = Address>,
+    mut transact: impl FnMut(
+        &TxEnv,
+        AccessListInspector,
+    ) -> Result<(ResultAndState, AccessListInspector), EVMError<DB::Error>>,
+) -> Result<(Vec<AccessListItem>, ExecutionResult), EVMError<DB::Error>> {
+    let standard = Precompiles::new(PrecompileSpecId::from_spec_id(spec_id));
+    let mut excluded: Vec<Address> = standard.addresses().copied().collect();
+    excluded.extend(precompiles);
+    excluded.push(tx.caller);
+    match tx.transact_to {
+        TxKind::Call(to) => excluded.push(to),
+        TxKind::Create => {
+            let nonce = match tx.nonce {
+                Some(nonce) => nonce,
+                None => db.basic(tx.caller).map_err(EVMError::Database)?.unwrap_or_default().nonce,
+            };
+            excluded.push(tx.caller.create(nonce));
+        }
+    }
+
+    for _ in 0..MAX_RUNS {
+        let inspector = AccessListInspector::new(&tx.access_list, excluded.iter().copied());
+        let (res, inspector) = transact(&tx, inspector)?;
+        let access_list = inspector.into_access_list();
+        if access_list == tx.access_list {
+            return Ok((access_list, res.result));
+        }
+        tx.access_list = access_list;
+    }
+    Err(EVMError::Custom(format!("The access list did not settle after {MAX_RUNS} runs")))
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::{address, bytes, U256};
+    use revm::{db::InMemoryDB, inspector_handle_register, primitives::AccountInfo, Evm};
+
+    use super::*;
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+
+    #[test]
+    fn lists_the_slots_touched_by_created_contracts() {
+        let mut db = InMemoryDB::default();
+        let balance = U256::from(1_000_000);
+        let info = AccountInfo { balance, nonce: 5, ..Default::default() };
+        db.insert_account_info(SENDER, info);
+        // SLOAD(1), then SSTORE(2, 1) in the contract being created
+        let tx = TxEnv {
+            caller: SENDER,
+            transact_to: TxKind::Create,
+            data: bytes!("60015450600160025500"),
+            gas_limit: 100_000,
+            nonce: None,
+            ..Default::default()
+        };
+
+        let mut runs = 0;
+        let (access_list, result) =
+            create_access_list(&mut db.clone(), SpecId::CANCUN, tx, [], |tx, inspector| {
+                runs += 1;
+                let mut evm = Evm::builder()
+                    .with_db(db.clone())
+                    .with_spec_id(SpecId::CANCUN)
+                    .with_external_context(inspector)
+                    .modify_tx_env(|env| *env = tx.clone())
+                    .append_handler_register(inspector_handle_register)
+                    .build();
+                let res = evm.transact()?;
+                Ok((res, evm.into_context().external))
+            })
+            .unwrap();
+
+        assert!(result.is_success());
+        // the created contract is warm, its slots are listed under it anyway
+        let created = SENDER.create(5);
+        let keys = [B256::with_last_byte(1), B256::with_last_byte(2)];
+        assert_eq!(
+            access_list,
+            vec![AccessListItem { address: created, storage_keys: keys.into() }]
+        );
+        assert_eq!(runs, 2);
+    }
+}
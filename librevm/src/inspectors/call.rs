@@ -0,0 +1,284 @@
+use alloy_primitives::{Address, Log, U256};
+use revm::{
+    interpreter::{
+        CallInputs, CallOutcome, CallScheme, CreateInputs, CreateOutcome, CreateScheme,
+        Interpreter, InterpreterResult,
+    },
+    Database, EvmContext, Inspector,
+};
+
+use super::error_message;
+use crate::v1::types::{CallFrame, CallType};
+
+/// `Error(string)` selector
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// `Panic(uint256)` selector
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Call tree tracer, equivalent to geth's `callTracer` with `withLog` enabled.
+#[derive(Debug, Default)]
+pub struct CallTracer {
+    // frames which are still executing, innermost last
+    stack: Vec<CallFrame>,
+    root: Option<CallFrame>,
+}
+
+impl CallTracer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the outermost frame, once the transaction has been executed.
+    pub fn into_root(self) -> Option<CallFrame> {
+        self.root
+    }
+
+    fn start(&mut self, frame: CallFrame) {
+        self.stack.push(frame);
+    }
+
+    fn end(&mut self, result: &InterpreterResult, to: Option<Address>) {
+        let Some(mut frame) = self.stack.pop() else {
+            return;
+        };
+
+        if let Some(to) = to {
+            frame.to = to.to_vec();
+        }
+        frame.gas_used = result.gas.spent();
+        frame.output = result.output.to_vec();
+        if !result.is_ok() {
+            if result.is_revert() {
+                frame.error = "execution reverted".to_owned();
+                frame.revert_reason = decode_revert_reason(&result.output).unwrap_or_default();
+            } else {
+                frame.error = error_message(result.result, None);
+            }
+            // logs of failed frames are discarded with the frame's state
+            clear_logs(&mut frame);
+        }
+
+        match self.stack.last_mut() {
+            Some(parent) => parent.calls.push(frame),
+            None => self.root = Some(frame),
+        }
+    }
+}
+
+impl<DB: Database> Inspector<DB> for CallTracer {
+    fn log(&mut self, _interp: &mut Interpreter, _context: &mut EvmContext<DB>, log: &Log) {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.logs.push(log.clone().into());
+        }
+    }
+
+    fn call(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        inputs: &mut CallInputs,
+    ) -> Option<CallOutcome> {
+        let call_type = match inputs.scheme {
+            CallScheme::Call | CallScheme::ExtCall => CallType::Call,
+            CallScheme::StaticCall | CallScheme::ExtStaticCall => CallType::Staticcall,
+            CallScheme::DelegateCall | CallScheme::ExtDelegateCall => CallType::Delegatecall,
+            CallScheme::CallCode => CallType::Callcode,
+        };
+        // like geth, the code of a DELEGATECALL or a CALLCODE is called by the executing contract,
+        // not by the caller propagated to its frame
+        let from = match inputs.scheme {
+            CallScheme::DelegateCall | CallScheme::ExtDelegateCall | CallScheme::CallCode => {
+                inputs.target_address
+            }
+            _ => inputs.caller,
+        };
+        self.start(CallFrame {
+            call_type: call_type.into(),
+            from: from.to_vec(),
+            to: inputs.bytecode_address.to_vec(),
+            value: inputs.transfer_value().map(|value| value.to_be_bytes_vec()).unwrap_or_default(),
+            gas: inputs.gas_limit,
+            input: inputs.input.to_vec(),
+            ..Default::default()
+        });
+        None
+    }
+
+    fn call_end(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &CallInputs,
+        outcome: CallOutcome,
+    ) -> CallOutcome {
+        self.end(&outcome.result, None);
+        outcome
+    }
+
+    fn create(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        inputs: &mut CreateInputs,
+    ) -> Option<CreateOutcome> {
+        let call_type = match inputs.scheme {
+            CreateScheme::Create => CallType::Create,
+            CreateScheme::Create2 { .. } => CallType::Create2,
+        };
+        self.start(CallFrame {
+            call_type: call_type.into(),
+            from: inputs.caller.to_vec(),
+            value: inputs.value.to_be_bytes_vec(),
+            gas: inputs.gas_limit,
+            input: inputs.init_code.to_vec(),
+            ..Default::default()
+        });
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut EvmContext<DB>,
+        _inputs: &CreateInputs,
+        outcome: CreateOutcome,
+    ) -> CreateOutcome {
+        self.end(&outcome.result, outcome.address);
+        outcome
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.calls.push(CallFrame {
+                call_type: CallType::Selfdestruct.into(),
+                from: contract.to_vec(),
+                to: target.to_vec(),
+                value: value.to_be_bytes_vec(),
+                ..Default::default()
+            });
+        }
+    }
+}
+
+fn clear_logs(frame: &mut CallFrame) {
+    frame.logs.clear();
+    frame.calls.iter_mut().for_each(clear_logs);
+}
+
+/// Decodes the solidity `Error(string)` and `Panic(uint256)` revert payloads.
+fn decode_revert_reason(output: &[u8]) -> Option<String> {
+    let (selector, data) = output.split_first_chunk::<4>()?;
+    match *selector {
+        ERROR_SELECTOR => {
+            // offset (32 bytes), length (32 bytes), utf-8 bytes
+            let len = U256::try_from_be_slice(data.get(32..64)?)?;
+            let len = usize::try_from(len).ok()?;
+            let reason = data.get(64..64usize.checked_add(len)?)?;
+            Some(String::from_utf8_lossy(reason).into_owned())
+        }
+        PANIC_SELECTOR => {
+            let code = U256::try_from_be_slice(data.get(..32)?)?;
+            Some(format!("panic: {code:#x}"))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::{address, Bytes};
+    use revm::{
+        db::InMemoryDB,
+        inspector_handle_register,
+        primitives::{AccountInfo, Bytecode, TxKind},
+        Evm,
+    };
+
+    use super::*;
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+    const CALLER: Address = address!("a000000000000000000000000000000000000000");
+    const STORE: Address = address!("b000000000000000000000000000000000000000");
+    const REVERT: Address = address!("c000000000000000000000000000000000000000");
+
+    /// `CALL` or `DELEGATECALL` of `to` without input, output or value, dropping its status.
+    fn call(opcode: u8, to: Address) -> Vec<u8> {
+        let args = if opcode == 0xf1 { 5 } else { 4 };
+        let mut code = [0x60, 0x00].repeat(args);
+        code.push(0x73);
+        code.extend_from_slice(to.as_slice());
+        code.extend_from_slice(&[0x5a, opcode, 0x50]);
+        code
+    }
+
+    fn contract(db: &mut InMemoryDB, address: Address, code: Vec<u8>) {
+        let info = AccountInfo::from_bytecode(Bytecode::new_raw(Bytes::from(code)));
+        db.insert_account_info(address, info);
+    }
+
+    #[test]
+    fn traces_nested_frames() {
+        let mut db = InMemoryDB::default();
+        let balance = U256::from(1_000_000_000);
+        db.insert_account_info(SENDER, AccountInfo { balance, ..Default::default() });
+        let mut code = call(0xf1, STORE);
+        code.extend(call(0xf4, STORE));
+        code.extend(call(0xf1, REVERT));
+        // CREATE of an empty contract, then STOP
+        code.extend_from_slice(&[0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0xf0, 0x50, 0x00]);
+        contract(&mut db, CALLER, code);
+        // SSTORE(0, 1)
+        contract(&mut db, STORE, vec![0x60, 0x01, 0x60, 0x00, 0x55, 0x00]);
+        // REVERT(0, 0)
+        contract(&mut db, REVERT, vec![0x60, 0x00, 0x60, 0x00, 0xfd]);
+
+        let mut evm = Evm::builder()
+            .with_db(db)
+            .with_external_context(CallTracer::new())
+            .modify_tx_env(|tx| {
+                tx.caller = SENDER;
+                tx.transact_to = TxKind::Call(CALLER);
+                tx.gas_limit = 1_000_000;
+            })
+            .append_handler_register(inspector_handle_register)
+            .build();
+        let res = evm.transact().unwrap();
+        assert!(res.result.is_success());
+        let nonce = res.state[&CALLER].info.nonce - 1;
+        let root = evm.into_context().external.into_root().unwrap();
+
+        assert_eq!((root.from.as_slice(), root.to.as_slice()), (&SENDER[..], &CALLER[..]));
+        assert!(root.error.is_empty());
+        let [store, delegated, reverted, created] = root.calls.as_slice() else {
+            panic!("unexpected calls {:?}", root.calls);
+        };
+        assert_eq!(store.call_type, CallType::Call as i32);
+        assert_eq!((store.from.as_slice(), store.to.as_slice()), (&CALLER[..], &STORE[..]));
+        // the code of STORE runs for CALLER, which calls it
+        assert_eq!(delegated.call_type, CallType::Delegatecall as i32);
+        assert_eq!(delegated.from.as_slice(), &CALLER[..]);
+        assert_eq!(delegated.to.as_slice(), &STORE[..]);
+        assert_eq!(reverted.to.as_slice(), &REVERT[..]);
+        assert_eq!(reverted.error, "execution reverted");
+        assert_eq!(created.call_type, CallType::Create as i32);
+        assert_eq!(created.from.as_slice(), &CALLER[..]);
+        assert_eq!(created.to.as_slice(), &CALLER.create(nonce)[..]);
+    }
+
+    #[test]
+    fn decode_revert_reason_works() {
+        // Error("boom")
+        let mut output = ERROR_SELECTOR.to_vec();
+        output.extend_from_slice(&U256::from(32).to_be_bytes::<32>());
+        output.extend_from_slice(&U256::from(4).to_be_bytes::<32>());
+        output.extend_from_slice(&[b'b', b'o', b'o', b'm']);
+        output.extend_from_slice(&[0u8; 28]);
+        assert_eq!(decode_revert_reason(&output), Some("boom".to_owned()));
+
+        // Panic(0x11)
+        let mut output = PANIC_SELECTOR.to_vec();
+        output.extend_from_slice(&U256::from(0x11).to_be_bytes::<32>());
+        assert_eq!(decode_revert_reason(&output), Some("panic: 0x11".to_owned()));
+
+        // truncated or unknown payloads
+        assert_eq!(decode_revert_reason(&ERROR_SELECTOR), None);
+        assert_eq!(decode_revert_reason(&[0xde, 0xad, 0xbe, 0xef]), None);
+        assert_eq!(decode_revert_reason(&[]), None);
+    }
+}
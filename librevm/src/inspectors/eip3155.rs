@@ -0,0 +1,260 @@
+use std::collections::{BTreeMap, HashMap};
+
+use alloy_primitives::{map::AddressHashMap, Address, B256, U256};
+use revm::{
+    interpreter::{opcode, opcode::OpCode, Interpreter},
+    Database, EvmContext, Inspector,
+};
+
+use super::error_message;
+use crate::v1::types::{StructLog, StructLoggerConfig};
+
+/// EIP-3155 step tracer, equivalent to geth's struct logger.
+///
+/// Every executed opcode is captured as a [`StructLog`]. Stack, memory, storage and return data
+/// capturing follow the flags of [`StructLoggerConfig`].
+#[derive(Debug, Default)]
+pub struct StructLogger {
+    config: StructLoggerConfig,
+    logs: Vec<StructLog>,
+    // storage slots seen so far, per contract
+    storage: AddressHashMap<BTreeMap<U256, U256>>,
+    // gas remaining before the current step
+    gas_remaining: u64,
+    // key of the SLOAD being executed in the current step
+    pending_sload: Option<U256>,
+    capturing: bool,
+}
+
+impl StructLogger {
+    pub fn new(config: StructLoggerConfig) -> Self {
+        Self { config, ..Default::default() }
+    }
+
+    pub fn into_logs(self) -> Vec<StructLog> {
+        self.logs
+    }
+
+    fn is_full(&self) -> bool {
+        self.config.limit != 0 && self.logs.len() as u64 >= self.config.limit
+    }
+
+    fn storage_of(&self, address: &Address) -> HashMap<String, Vec<u8>> {
+        self.storage
+            .get(address)
+            .map(|slots| {
+                slots
+                    .iter()
+                    .map(|(key, value)| (B256::from(*key).to_string(), value.to_be_bytes_vec()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl<DB: Database> Inspector<DB> for StructLogger {
+    fn step(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        self.gas_remaining = interp.gas.remaining();
+        self.capturing = !self.is_full();
+        if !self.capturing {
+            return;
+        }
+
+        let op = interp.current_opcode();
+        let address = interp.contract.target_address;
+        let mut storage = Default::default();
+        if !self.config.disable_storage {
+            match op {
+                opcode::SSTORE => {
+                    if let (Ok(key), Ok(value)) = (interp.stack.peek(0), interp.stack.peek(1)) {
+                        self.storage.entry(address).or_default().insert(key, value);
+                        storage = self.storage_of(&address);
+                    }
+                }
+                opcode::SLOAD => self.pending_sload = interp.stack.peek(0).ok(),
+                _ => {}
+            }
+        }
+
+        let memory = interp.shared_memory.context_memory();
+        self.logs.push(StructLog {
+            pc: interp.program_counter() as u64,
+            op: op as u32,
+            op_name: OpCode::name_by_op(op).to_string(),
+            gas: interp.gas.remaining(),
+            gas_cost: 0,
+            stack: if self.config.disable_stack {
+                Vec::new()
+            } else {
+                interp.stack.data().iter().map(|value| value.to_be_bytes_vec()).collect()
+            },
+            memory_size: memory.len() as u64,
+            memory: if self.config.enable_memory { memory.to_vec() } else { Vec::new() },
+            depth: context.journaled_state.depth(),
+            refund: interp.gas.refunded().max(0) as u64,
+            storage,
+            return_data: if self.config.enable_return_data {
+                interp.return_data_buffer.to_vec()
+            } else {
+                Vec::new()
+            },
+            error: String::new(),
+        });
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, _context: &mut EvmContext<DB>) {
+        if !self.capturing {
+            return;
+        }
+
+        if let Some(key) = self.pending_sload.take() {
+            if let Ok(value) = interp.stack.peek(0) {
+                let address = interp.contract.target_address;
+                self.storage.entry(address).or_default().insert(key, value);
+                let storage = self.storage_of(&address);
+                if let Some(log) = self.logs.last_mut() {
+                    log.storage = storage;
+                }
+            }
+        }
+
+        if let Some(log) = self.logs.last_mut() {
+            log.gas_cost = self.gas_remaining.saturating_sub(interp.gas.remaining());
+            let result = interp.instruction_result;
+            if result.is_error() || result.is_revert() {
+                let step = (log.op as u8, interp.stack.len());
+                log.error = error_message(result, Some(step));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::{address, Bytes};
+    use revm::{
+        db::InMemoryDB,
+        inspector_handle_register,
+        primitives::{AccountInfo, Bytecode, SpecId, TxKind},
+        Evm,
+    };
+
+    use super::*;
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+    const CONTRACT: Address = address!("a000000000000000000000000000000000000000");
+
+    /// SSTORE(0, 0x2a), MSTORE(0, SLOAD(0)), STOP
+    const CODE: [u8; 12] = [0x60, 0x2a, 0x60, 0x00, 0x55, 0x60, 0x00, 0x54, 0x60, 0x00, 0x52, 0x00];
+
+    fn trace(code: &[u8], config: StructLoggerConfig) -> Vec<StructLog> {
+        let mut db = InMemoryDB::default();
+        let balance = U256::from(1_000_000_000);
+        db.insert_account_info(SENDER, AccountInfo { balance, ..Default::default() });
+        let bytecode = Bytecode::new_raw(Bytes::copy_from_slice(code));
+        db.insert_account_info(CONTRACT, AccountInfo::from_bytecode(bytecode));
+
+        let mut evm = Evm::builder()
+            .with_db(db)
+            .with_spec_id(SpecId::CANCUN)
+            .with_external_context(StructLogger::new(config))
+            .modify_tx_env(|tx| {
+                tx.caller = SENDER;
+                tx.transact_to = TxKind::Call(CONTRACT);
+                tx.gas_limit = 100_000;
+            })
+            .append_handler_register(inspector_handle_register)
+            .build();
+        evm.transact().unwrap();
+        evm.into_context().external.into_logs()
+    }
+
+    fn word(value: u64) -> Vec<u8> {
+        U256::from(value).to_be_bytes_vec()
+    }
+
+    #[test]
+    fn traces_steps() {
+        let config = StructLoggerConfig {
+            enable_memory: true,
+            disable_storage: false,
+            ..Default::default()
+        };
+        let logs = trace(&CODE, config);
+
+        let steps: Vec<_> = logs
+            .iter()
+            .map(|log| {
+                let op = (log.pc, log.op, log.op_name.as_str());
+                (
+                    op,
+                    log.gas,
+                    log.gas_cost,
+                    log.stack.clone(),
+                    log.memory_size,
+                    log.depth,
+                    log.refund,
+                )
+            })
+            .collect();
+        // 21000 of the 100000 gas are intrinsic, the SSTORE of a cold slot costs 22100
+        assert_eq!(
+            steps,
+            vec![
+                ((0, 0x60, "PUSH1"), 79_000, 3, vec![], 0, 1, 0),
+                ((2, 0x60, "PUSH1"), 78_997, 3, vec![word(0x2a)], 0, 1, 0),
+                ((4, 0x55, "SSTORE"), 78_994, 22_100, vec![word(0x2a), word(0)], 0, 1, 0),
+                ((5, 0x60, "PUSH1"), 56_894, 3, vec![], 0, 1, 0),
+                ((7, 0x54, "SLOAD"), 56_891, 100, vec![word(0)], 0, 1, 0),
+                ((8, 0x60, "PUSH1"), 56_791, 3, vec![word(0x2a)], 0, 1, 0),
+                ((10, 0x52, "MSTORE"), 56_788, 6, vec![word(0x2a), word(0)], 0, 1, 0),
+                ((11, 0x00, "STOP"), 56_782, 0, vec![], 32, 1, 0),
+            ]
+        );
+
+        // the slots seen so far are reported by the SLOAD and SSTORE steps only
+        let slots = HashMap::from([(B256::ZERO.to_string(), word(0x2a))]);
+        for log in &logs {
+            let expected = if matches!(log.op_name.as_str(), "SSTORE" | "SLOAD") {
+                slots.clone()
+            } else {
+                HashMap::new()
+            };
+            assert_eq!(log.storage, expected, "storage of {}", log.op_name);
+        }
+        let mut memory = vec![0; 32];
+        memory[31] = 0x2a;
+        assert_eq!(logs.last().unwrap().memory, memory);
+        assert!(logs[..7].iter().all(|log| log.memory.is_empty()));
+    }
+
+    #[test]
+    fn excludes_memory_and_storage() {
+        let config = StructLoggerConfig {
+            enable_memory: false,
+            disable_storage: true,
+            ..Default::default()
+        };
+        let logs = trace(&CODE, config);
+
+        assert_eq!(logs.len(), 8);
+        assert!(logs.iter().all(|log| log.memory.is_empty() && log.storage.is_empty()));
+        // the memory size and the stack are still reported
+        assert_eq!(logs.last().unwrap().memory_size, 32);
+        assert_eq!(logs[2].stack, vec![word(0x2a), word(0)]);
+    }
+
+    #[test]
+    fn reports_geth_errors() {
+        let error = |code: &[u8]| trace(code, Default::default()).pop().unwrap().error;
+
+        // ADD on an empty stack
+        assert_eq!(error(&[0x01]), "stack underflow (0 <=> 2)");
+        assert_eq!(error(&[0xfe]), "invalid opcode: INVALID");
+        assert_eq!(error(&[0x0c]), "invalid opcode: opcode 0xc not defined");
+        // REVERT(0, 0)
+        assert_eq!(error(&[0x60, 0x00, 0x60, 0x00, 0xfd]), "execution reverted");
+        // JUMPDEST, JUMP(0) until the gas runs out
+        assert_eq!(error(&[0x5b, 0x60, 0x00, 0x56]), "out of gas");
+    }
+}
@@ -0,0 +1,66 @@
+mod access_list;
+mod call;
+mod eip3155;
+mod prestate;
+
+pub use access_list::*;
+pub use call::*;
+pub use eip3155::*;
+pub use prestate::*;
+
+use revm::interpreter::{opcode::OpCode, InstructionResult, STACK_LIMIT};
+
+/// The message of geth's error for a frame or a step which ended with `result`. `step` is the
+/// opcode and the stack size of the failing step, which geth's errors of steps mention.
+pub(crate) fn error_message(result: InstructionResult, step: Option<(u8, usize)>) -> String {
+    let message = match result {
+        InstructionResult::Revert => "execution reverted",
+        InstructionResult::OutOfGas
+        | InstructionResult::MemoryOOG
+        | InstructionResult::MemoryLimitOOG
+        | InstructionResult::PrecompileOOG
+        | InstructionResult::InvalidOperandOOG => "out of gas",
+        InstructionResult::CallTooDeep => "max call depth exceeded",
+        InstructionResult::OutOfFunds => "insufficient balance for transfer",
+        InstructionResult::CreateCollision => "contract address collision",
+        InstructionResult::CreateContractSizeLimit => "max code size exceeded",
+        InstructionResult::CreateInitCodeSizeLimit => "max initcode size exceeded",
+        InstructionResult::CreateContractStartingWithEF => "invalid code: must not begin with 0xef",
+        InstructionResult::InvalidJump => "invalid jump destination",
+        InstructionResult::StateChangeDuringStaticCall
+        | InstructionResult::CallNotAllowedInsideStatic => "write protection",
+        InstructionResult::OutOfOffset => "return data out of bounds",
+        InstructionResult::OverflowPayment => "gas uint64 overflow",
+        InstructionResult::NonceOverflow => "nonce uint64 overflow",
+        InstructionResult::StackUnderflow => match step {
+            Some((op, len)) => {
+                let required = OpCode::new(op).map_or(0, |op| op.inputs());
+                return format!("stack underflow ({len} <=> {required})");
+            }
+            None => "stack underflow",
+        },
+        InstructionResult::StackOverflow => match step {
+            // the limit of geth is the stack size the step may start with
+            Some((op, len)) => {
+                let limit = OpCode::new(op).map_or(STACK_LIMIT, |op| {
+                    STACK_LIMIT + op.inputs() as usize - op.outputs() as usize
+                });
+                return format!("stack limit reached {len} ({limit})");
+            }
+            None => "stack limit reached 1024",
+        },
+        InstructionResult::InvalidFEOpcode => "invalid opcode: INVALID",
+        InstructionResult::OpcodeNotFound | InstructionResult::NotActivated => match step {
+            Some((op, _)) => {
+                return match OpCode::new(op) {
+                    Some(op) => format!("invalid opcode: {}", op.as_str()),
+                    None => format!("invalid opcode: opcode {op:#x} not defined"),
+                };
+            }
+            None => "invalid opcode",
+        },
+        InstructionResult::PrecompileError => "precompile failed",
+        result => return format!("{result:?}"),
+    };
+    message.to_owned()
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use alloy_primitives::{B256, U256};
+use revm::{
+    primitives::{AccountInfo, EvmState, EvmStorageSlot, KECCAK_EMPTY},
+    Database,
+};
+
+use crate::v1::types::{AccountState, PrestateTracerConfig};
+
+/// address => account state
+pub type AccountStates = HashMap<String, AccountState>;
+
+/// Prestate tracer, equivalent to geth's `prestateTracer`.
+///
+/// Unlike the other tracers it does not hook into the interpreter. The pre values are read from
+/// the database after execution, before the state changes of the transaction are committed.
+#[derive(Debug, Default)]
+pub struct PrestateTracer {
+    config: PrestateTracerConfig,
+}
+
+impl PrestateTracer {
+    pub fn new(config: PrestateTracerConfig) -> Self {
+        Self { config }
+    }
+
+    /// Returns the `(pre, post)` states of the accounts in `state`. `post` is only populated in
+    /// diff mode. `db` must not contain the changes of `state` yet.
+    pub fn trace<DB: Database>(
+        &self,
+        db: &mut DB,
+        state: &EvmState,
+    ) -> Result<(AccountStates, AccountStates), DB::Error> {
+        let mut pre = AccountStates::new();
+        let mut post = AccountStates::new();
+
+        for (address, account) in state {
+            let pre_info = db.basic(*address)?.unwrap_or_default();
+
+            if !self.config.diff_mode {
+                // every account and slot accessed by the transaction
+                let storage = storage_of(account.storage.iter(), |slot| slot.original_value);
+                pre.insert(address.to_string(), account_state(db, &pre_info, storage)?);
+                continue;
+            }
+
+            let post_info = &account.info;
+            let deleted = account.is_selfdestructed();
+            let changed_slots: Vec<_> =
+                account.storage.iter().filter(|(_, slot)| slot.is_changed()).collect();
+            let info_changed = pre_info.balance != post_info.balance
+                || pre_info.nonce != post_info.nonce
+                || pre_info.code_hash != post_info.code_hash;
+            if !account.is_touched() || !(deleted || info_changed || !changed_slots.is_empty()) {
+                continue;
+            }
+
+            // like geth, accounts created by the transaction have no pre state unless they held a
+            // balance or a nonce before
+            if !(account.is_created() && pre_info.is_empty()) {
+                let storage = storage_of(changed_slots.iter().copied(), |slot| slot.original_value);
+                pre.insert(address.to_string(), account_state(db, &pre_info, storage)?);
+            }
+
+            // deleted accounts have no post state
+            if !deleted {
+                let mut changed = AccountState {
+                    storage: storage_of(changed_slots.iter().copied(), |slot| slot.present_value),
+                    ..Default::default()
+                };
+                if pre_info.balance != post_info.balance {
+                    changed.balance = post_info.balance.to_be_bytes_vec();
+                }
+                if pre_info.nonce != post_info.nonce {
+                    changed.nonce = Some(post_info.nonce);
+                }
+                if pre_info.code_hash != post_info.code_hash {
+                    changed.code = code_of(db, post_info)?;
+                }
+                post.insert(address.to_string(), changed);
+            }
+        }
+
+        Ok((pre, post))
+    }
+}
+
+fn account_state<DB: Database>(
+    db: &mut DB,
+    info: &AccountInfo,
+    storage: HashMap<String, Vec<u8>>,
+) -> Result<AccountState, DB::Error> {
+    Ok(AccountState {
+        balance: info.balance.to_be_bytes_vec(),
+        nonce: Some(info.nonce),
+        code: code_of(db, info)?,
+        storage,
+    })
+}
+
+fn code_of<DB: Database>(db: &mut DB, info: &AccountInfo) -> Result<Vec<u8>, DB::Error> {
+    if let Some(code) = &info.code {
+        return Ok(code.original_bytes().to_vec());
+    }
+    if info.code_hash == KECCAK_EMPTY || info.code_hash == B256::ZERO {
+        return Ok(Vec::new());
+    }
+    Ok(db.code_by_hash(info.code_hash)?.original_bytes().to_vec())
+}
+
+fn storage_of<'a>(
+    slots: impl Iterator<Item = (&'a U256, &'a EvmStorageSlot)>,
+    value: impl Fn(&EvmStorageSlot) -> U256,
+) -> HashMap<String, Vec<u8>> {
+    slots.map(|(key, slot)| (B256::from(*key).to_string(), value(slot).to_be_bytes_vec())).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::{address, bytes, Address};
+    use revm::{
+        db::InMemoryDB,
+        primitives::{ResultAndState, TxKind},
+        Evm,
+    };
+
+    use super::*;
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+    const RECEIVER: Address = address!("2000000000000000000000000000000000000002");
+
+    fn transact(
+        evm: &mut Evm<'_, (), InMemoryDB>,
+        diff_mode: bool,
+    ) -> (AccountStates, AccountStates) {
+        let ResultAndState { result, state } = evm.transact().unwrap();
+        assert!(result.is_success());
+        let tracer = PrestateTracer::new(PrestateTracerConfig { diff_mode });
+        tracer.trace(&mut evm.context.evm.db, &state).unwrap()
+    }
+
+    #[test]
+    fn traces_the_touched_accounts() {
+        let mut db = InMemoryDB::default();
+        let balance = U256::from(1_000_000);
+        db.insert_account_info(SENDER, AccountInfo { balance, ..Default::default() });
+        // an empty account which exists before the transactions
+        db.insert_account_info(RECEIVER, AccountInfo::default());
+        let mut evm = Evm::builder()
+            .with_db(db)
+            .modify_tx_env(|tx| {
+                tx.caller = SENDER;
+                tx.transact_to = TxKind::Call(RECEIVER);
+                tx.value = U256::from(7);
+                tx.gas_limit = 100_000;
+            })
+            .build();
+
+        let (pre, post) = transact(&mut evm, false);
+        assert!(post.is_empty());
+        assert_eq!(pre[&SENDER.to_string()].nonce, Some(0));
+        assert_eq!(pre[&SENDER.to_string()].balance, balance.to_be_bytes_vec());
+        assert_eq!(pre[&RECEIVER.to_string()].balance, U256::ZERO.to_be_bytes_vec());
+
+        let (pre, post) = transact(&mut evm, true);
+        assert_eq!(pre[&RECEIVER.to_string()].balance, U256::ZERO.to_be_bytes_vec());
+        assert_eq!(post[&RECEIVER.to_string()].balance, U256::from(7).to_be_bytes_vec());
+        assert_eq!(post[&SENDER.to_string()].nonce, Some(1));
+
+        // SSTORE(0, 1), deploying no code
+        let tx = evm.tx_mut();
+        tx.transact_to = TxKind::Create;
+        tx.value = U256::ZERO;
+        tx.data = bytes!("600160005500");
+        let (pre, post) = transact(&mut evm, true);
+        let created = SENDER.create(0).to_string();
+        assert!(!pre.contains_key(&created));
+        let slot = B256::ZERO.to_string();
+        assert_eq!(post[&created].storage[&slot], U256::from(1).to_be_bytes_vec());
+    }
+}
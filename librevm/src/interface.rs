@@ -1,136 +1,1375 @@
 use crate::{
-    error::set_error,
+    chain::ChainRules,
+    compiler::{ArtifactKey, EXTCompileWorker},
+    error::{catch_panic, set_error, BackendError},
+    estimate::{estimate_gas as estimate, GasEstimate},
+    inspectors::{create_access_list, CallTracer, PrestateTracer, StructLogger},
     memory::{ByteSliceView, UnmanagedVector},
-    states::{Db, StateDB},
-    types::TryIntoVec,
+    parallel::ParallelExecutor,
+    pool::{
+        get as get_pool, pool_t, register as register_pool, unregister as unregister_pool,
+        with_pooled_vm, VmPool,
+    },
+    precompiles::{load_plugin, CustomPrecompile, GoPrecompiles, PrecompileRegistry},
+    session::{
+        register as register_session, session_t, unregister as unregister_session, with_session,
+        Session,
+    },
+    states::{free_native, into_db, native_of, Db, MemoryDb, SledDb, StateDB},
+    types::{result_with_receipt, TryIntoVec, TypedTxEnv},
+    v1::types::{
+        AccessListResult, Addresses, BlockResult, CallTrace, ChainConfig, CommitResult,
+        CompilerConfig, EstimateGasResult, EvmResult, Genesis, Log, PrestateTrace,
+        PrestateTracerConfig, StorageKeys, StructLogTrace, StructLoggerConfig, TransactionResult,
+    },
+    vm::{
+        compiled_vm, get as get_vm, interpreted_vm, register, unregister, vm_t, with_compiled_vm,
+        with_vm, AnyVm, Vm,
+    },
 };
-use revm::{primitives::SpecId, Evm, EvmBuilder};
-use revmc_worker::{register_handler, EXTCompileWorker};
+use alloy_primitives::{Address, B256, U256};
+use prost::{DecodeError, Message};
+use revm::{
+    db::State,
+    inspector_handle_register,
+    primitives::{
+        BlockEnv, EVMError, ExecutionResult, InvalidTransaction, ResultAndState, SpecId, TxEnv,
+        TxKind,
+    },
+    Database, DatabaseCommit, Evm, EvmBuilder, Inspector,
+};
+use std::{num::NonZeroUsize, path::PathBuf, sync::Arc};
+
+/// Creates an interpreting VM and returns its handle, or zero on failure.
+#[no_mangle]
+pub extern "C" fn new_vm(default_spec_id: u8) -> vm_t {
+    catch_panic("new_vm", None, |_| {
+        let spec = SpecId::try_from_u8(default_spec_id).unwrap_or(SpecId::OSAKA);
+        register(AnyVm::Interpreted(interpreted_vm(
+            spec,
+            PrecompileRegistry::default(),
+            ChainRules::default(),
+        )))
+    })
+}
+
+/// Creates a VM which compiles the contracts it calls often to native code, as set by the
+/// `CompilerConfig`, a nil config compiling every contract with the default options. Compiled
+/// contracts are kept in the store dir and loaded again by the VMs created over the same store.
+/// The VM runs on the chain of the `ChainConfig` like `new_vm_with_chain_config`. Returns zero if
+/// a config is invalid or the store cannot be opened.
+#[no_mangle]
+pub extern "C" fn new_vm_with_compiler(
+    default_spec_id: u8,
+    config: ByteSliceView,
+    chain: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> vm_t {
+    catch_panic("new_vm_with_compiler", errmsg, |errmsg| {
+        let spec = SpecId::try_from_u8(default_spec_id).unwrap_or(SpecId::OSAKA);
+        let vm = chain_config(chain).and_then(|chain| {
+            let worker = compile_worker(config, spec)?;
+            let precompiles = PrecompileRegistry::default();
+            Ok(compiled_vm(spec, worker, precompiles, ChainRules::new(chain)))
+        });
+        match vm {
+            Ok(vm) => register(AnyVm::Compiled(vm)),
+            Err(err) => {
+                set_error(err, errmsg);
+                0
+            }
+        }
+    })
+}
+
+/// Creates a VM over a state db implemented in Rust, e.g. opened by `open_sled_db`, instead of the
+/// Go state db, on the chain of the `ChainConfig` like `new_vm_with_chain_config`. The db passed to
+/// the entry points is ignored. The VM does not own the db, which must be released with
+/// `free_native_db` once the VM is freed. Returns zero if the config is invalid.
+#[no_mangle]
+pub extern "C" fn new_vm_with_db(
+    default_spec_id: u8,
+    db: Db,
+    chain: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> vm_t {
+    catch_panic("new_vm_with_db", errmsg, |errmsg| {
+        let spec = SpecId::try_from_u8(default_spec_id).unwrap_or(SpecId::OSAKA);
+        match chain_config(chain) {
+            Ok(config) => {
+                let precompiles = PrecompileRegistry::default();
+                let mut vm = interpreted_vm(spec, precompiles, ChainRules::new(config));
+                vm.db = Some(db);
+                register(AnyVm::Interpreted(vm))
+            }
+            Err(err) => {
+                set_error(err, errmsg);
+                0
+            }
+        }
+    })
+}
+
+/// Creates an interpreting VM on the chain of the `ChainConfig`: its chain id, the limits of the
+/// contracts and the checks of the transactions. A nil config keeps the defaults, for chain id 1.
+/// Returns zero if the config is invalid.
+#[no_mangle]
+pub extern "C" fn new_vm_with_chain_config(
+    default_spec_id: u8,
+    config: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> vm_t {
+    catch_panic("new_vm_with_chain_config", errmsg, |errmsg| {
+        let spec = SpecId::try_from_u8(default_spec_id).unwrap_or(SpecId::OSAKA);
+        match chain_config(config) {
+            Ok(config) => register(AnyVm::Interpreted(interpreted_vm(
+                spec,
+                PrecompileRegistry::default(),
+                ChainRules::new(config),
+            ))),
+            Err(err) => {
+                set_error(err, errmsg);
+                0
+            }
+        }
+    })
+}
+
+/// Replaces the chain config of the VM with the `ChainConfig`, from the next transaction on. A nil
+/// config restores the defaults.
+#[no_mangle]
+pub extern "C" fn set_chain_config(
+    vm: vm_t,
+    config: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("set_chain_config", errmsg, |errmsg| match chain_config(config) {
+        Ok(config) => with_vm!(vm, errmsg, |vm| vm.chain.set(config, vm.evm.cfg_mut())),
+        Err(err) => set_error(err, errmsg),
+    })
+}
+
+/// Frees the VM of the handle, which is invalid afterwards. Calls running on the VM complete
+/// first.
+#[no_mangle]
+pub extern "C" fn free_vm(vm: vm_t, errmsg: Option<&mut UnmanagedVector>) {
+    catch_panic("free_vm", errmsg, |errmsg| {
+        if let Err(err) = unregister(vm) {
+            set_error(err, errmsg);
+        }
+    })
+}
+
+/// Creates a pool of `size` interpreting VMs on the chain of the `ChainConfig`, which serves
+/// `pool_execute_tx` and `pool_simulate_tx` from many threads at once. A nil config keeps the
+/// defaults, for chain id 1. Returns zero if `size` is zero or the config is invalid.
+#[no_mangle]
+pub extern "C" fn new_vm_pool(
+    default_spec_id: u8,
+    size: usize,
+    chain: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> pool_t {
+    catch_panic("new_vm_pool", errmsg, |errmsg| {
+        let spec = SpecId::try_from_u8(default_spec_id).unwrap_or(SpecId::OSAKA);
+        if size == 0 {
+            set_error(EVMError::Custom("A VM pool needs at least one VM".to_string()), errmsg);
+            return 0;
+        }
+        match chain_config(chain) {
+            Ok(config) => register_pool(VmPool::new(spec, size, None, ChainRules::new(config))),
+            Err(err) => {
+                set_error(err, errmsg);
+                0
+            }
+        }
+    })
+}
+
+/// Creates a pool of `size` VMs sharing one compile worker, configured like the worker of
+/// `new_vm_with_compiler`, on the chain of the `ChainConfig` like `new_vm_pool`: a contract is
+/// compiled once for the pool, and runs compiled on all of its VMs. Returns zero if `size` is
+/// zero, a config is invalid or the store cannot be opened.
+#[no_mangle]
+pub extern "C" fn new_vm_pool_with_compiler(
+    default_spec_id: u8,
+    size: usize,
+    config: ByteSliceView,
+    chain: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> pool_t {
+    catch_panic("new_vm_pool_with_compiler", errmsg, |errmsg| {
+        let spec = SpecId::try_from_u8(default_spec_id).unwrap_or(SpecId::OSAKA);
+        if size == 0 {
+            set_error(EVMError::Custom("A VM pool needs at least one VM".to_string()), errmsg);
+            return 0;
+        }
+        let pool = chain_config(chain).and_then(|chain| {
+            let worker = compile_worker(config, spec)?;
+            Ok(VmPool::new(spec, size, Some(worker), ChainRules::new(chain)))
+        });
+        match pool {
+            Ok(pool) => register_pool(pool),
+            Err(err) => {
+                set_error(err, errmsg);
+                0
+            }
+        }
+    })
+}
+
+/// Like `set_chain_config`, for every VM of the pool, from the next call on. Calls running on the
+/// pool at the moment may run with parts of the previous config.
+#[no_mangle]
+pub extern "C" fn set_pool_chain_config(
+    pool: pool_t,
+    config: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("set_pool_chain_config", errmsg, |errmsg| {
+        let result = chain_config(config).and_then(|config| {
+            get_pool(pool)?.chain().replace(config);
+            Ok(())
+        });
+        if let Err(err) = result {
+            set_error(err, errmsg);
+        }
+    })
+}
+
+/// Frees the pool of the handle, which is invalid afterwards. Calls running on the pool complete
+/// first.
+#[no_mangle]
+pub extern "C" fn free_vm_pool(pool: pool_t, errmsg: Option<&mut UnmanagedVector>) {
+    catch_panic("free_vm_pool", errmsg, |errmsg| {
+        if let Err(err) = unregister_pool(pool) {
+            set_error(err, errmsg);
+        }
+    })
+}
+
+/// Returns the `ContractCompileState` of the contract with `code_hash` for the spec of a VM
+/// created with `new_vm_with_compiler`.
+#[no_mangle]
+pub extern "C" fn compile_status(
+    vm: vm_t,
+    code_hash: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("compile_status", errmsg, |errmsg| {
+        let data =
+            with_compiled_vm!(vm, errmsg, |vm| contract_compile_state(vm, code_hash, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Returns the `ContractCompileStates` of every contract seen by a VM created with
+/// `new_vm_with_compiler` for its spec, with the calls counted against the threshold.
+#[no_mangle]
+pub extern "C" fn compile_call_counts(
+    vm: vm_t,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("compile_call_counts", errmsg, |errmsg| {
+        let data = with_compiled_vm!(vm, errmsg, |vm| contract_compile_states(vm));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Queues the contract with `code_hash`, read from `db`, for compilation ahead of the others,
+/// whatever its call count.
+#[no_mangle]
+pub extern "C" fn force_compile(
+    vm: vm_t,
+    db: Db,
+    code_hash: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("force_compile", errmsg, |errmsg| {
+        with_compiled_vm!(vm, errmsg, |vm| queue_compile(vm, db, code_hash, errmsg))
+    })
+}
+
+/// Drops the compiled code of the contract with `code_hash` from memory and from the store. The
+/// contract is interpreted and counted from zero again.
+#[no_mangle]
+pub extern "C" fn evict_compiled(
+    vm: vm_t,
+    code_hash: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("evict_compiled", errmsg, |errmsg| {
+        with_compiled_vm!(vm, errmsg, |vm| evict_contract(vm, code_hash, errmsg))
+    })
+}
+
+/// Returns the `CompilerStats` of a VM created with `new_vm_with_compiler`.
+#[no_mangle]
+pub extern "C" fn compiler_stats(
+    vm: vm_t,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("compiler_stats", errmsg, |errmsg| {
+        let data = with_compiled_vm!(vm, errmsg, |vm| compile_stats(vm, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Enables the parallel execution of `execute_block` on `workers` threads, or disables it when
+/// `workers` is zero. The results are identical to the sequential execution.
+///
+/// Transactions executed in parallel are interpreted, without the compiled code of the AOT
+/// compiler. Blocks are executed sequentially while the VM has custom precompiles, or a chain
+/// config disabling the nonce check or setting blob parameters.
+#[no_mangle]
+pub extern "C" fn set_parallel_execution(
+    vm: vm_t,
+    workers: usize,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("set_parallel_execution", errmsg, |errmsg| {
+        with_vm!(vm, errmsg, |vm| vm.parallel_workers = NonZeroUsize::new(workers))
+    })
+}
+
+/// Forwards the calls to the `Addresses` to the Go precompiles, from the next transaction on. The
+/// addresses are warm like the standard precompiles, and replace any precompile registered for
+/// them before. `precompiles` must stay valid while the VM is used.
+///
+/// The Go precompiles see the Go state db as committed, without the changes the running
+/// transaction made before the call: they must not read state the transaction may have changed.
+#[no_mangle]
+pub extern "C" fn register_precompiles(
+    vm: vm_t,
+    precompiles: GoPrecompiles,
+    addresses: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("register_precompiles", errmsg, |errmsg| match precompile_addresses(addresses) {
+        Ok(addresses) => {
+            with_vm!(vm, errmsg, |vm| { vm.precompiles.insert(&addresses, Arc::new(precompiles)) })
+        }
+        Err(err) => set_error(err, errmsg),
+    })
+}
+
+/// Like `register_precompiles`, for every VM of the pool.
+#[no_mangle]
+pub extern "C" fn register_pool_precompiles(
+    pool: pool_t,
+    precompiles: GoPrecompiles,
+    addresses: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("register_pool_precompiles", errmsg, |errmsg| {
+        let result = precompile_addresses(addresses).and_then(|addresses| {
+            get_pool(pool)?.precompiles().insert(&addresses, Arc::new(precompiles));
+            Ok(())
+        });
+        if let Err(err) = result {
+            set_error(err, errmsg);
+        }
+    })
+}
+
+/// Creates an interpreting VM like `new_vm_with_chain_config`, calling the precompiles of the
+/// plugin at `path` like `load_precompile_plugin`. Returns zero if the config is invalid or the
+/// plugin cannot be loaded.
+#[no_mangle]
+pub extern "C" fn new_vm_with_precompile_plugin(
+    default_spec_id: u8,
+    path: ByteSliceView,
+    chain: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> vm_t {
+    catch_panic("new_vm_with_precompile_plugin", errmsg, |errmsg| {
+        let spec = SpecId::try_from_u8(default_spec_id).unwrap_or(SpecId::OSAKA);
+        match chain_config(chain).and_then(|chain| Ok((chain, plugin_precompiles(path)?))) {
+            Ok((chain, precompiles)) => {
+                let registry = PrecompileRegistry::default();
+                for (address, precompile) in precompiles {
+                    registry.insert(&[address], precompile);
+                }
+                register(AnyVm::Interpreted(interpreted_vm(spec, registry, ChainRules::new(chain))))
+            }
+            Err(err) => {
+                set_error(err, errmsg);
+                0
+            }
+        }
+    })
+}
+
+/// Loads the precompiles written in Rust of the plugin at `path`, a shared library exporting the
+/// versioned C ABI of `PrecompilePlugin`, and calls them for their addresses like
+/// `register_precompiles`. Plugins are meant to be loaded once, right after the VM is created.
+#[no_mangle]
+pub extern "C" fn load_precompile_plugin(
+    vm: vm_t,
+    path: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("load_precompile_plugin", errmsg, |errmsg| match plugin_precompiles(path) {
+        Ok(precompiles) => with_vm!(vm, errmsg, |vm| {
+            for (address, precompile) in &precompiles {
+                vm.precompiles.insert(&[*address], precompile.clone());
+            }
+        }),
+        Err(err) => set_error(err, errmsg),
+    })
+}
+
+/// Like `load_precompile_plugin`, for every VM of the pool.
+#[no_mangle]
+pub extern "C" fn load_pool_precompile_plugin(
+    pool: pool_t,
+    path: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("load_pool_precompile_plugin", errmsg, |errmsg| {
+        let result = plugin_precompiles(path).and_then(|precompiles| {
+            let pool = get_pool(pool)?;
+            for (address, precompile) in precompiles {
+                pool.precompiles().insert(&[address], precompile);
+            }
+            Ok(())
+        });
+        if let Err(err) = result {
+            set_error(err, errmsg);
+        }
+    })
+}
+
+/// Configures the cache of state db reads of the VM from a `CacheConfig`, resetting its counters.
+/// A nil config disables the cache.
+#[no_mangle]
+pub extern "C" fn configure_state_cache(
+    vm: vm_t,
+    config: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("configure_state_cache", errmsg, |errmsg| {
+        with_vm!(vm, errmsg, |vm| configure_cache(vm, config, errmsg))
+    })
+}
+
+/// Returns the `CacheStats` of the state db reads of the VM.
+#[no_mangle]
+pub extern "C" fn state_cache_stats(
+    vm: vm_t,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("state_cache_stats", errmsg, |errmsg| {
+        let data = with_vm!(vm, errmsg, |vm| cache_stats(vm));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Sets the version of the state db the next calls of the VM read from, an epoch Go bumps whenever
+/// the state db changes outside of the VM. A block scoped cache is kept across calls only while the
+/// version and the block stay the same; without a version it is kept for the transactions of one
+/// call.
+#[no_mangle]
+pub extern "C" fn set_state_version(vm: vm_t, version: u64, errmsg: Option<&mut UnmanagedVector>) {
+    catch_panic("set_state_version", errmsg, |errmsg| {
+        with_vm!(vm, errmsg, |vm| vm.evm.context.evm.db.cache.set_version(version))
+    })
+}
+
+/// Drops the state reads cached by the VM, e.g. after the state db was changed outside of the VM
+/// without a new version.
+#[no_mangle]
+pub extern "C" fn invalidate_state_cache(vm: vm_t, errmsg: Option<&mut UnmanagedVector>) {
+    catch_panic("invalidate_state_cache", errmsg, |errmsg| {
+        with_vm!(vm, errmsg, |vm| vm.evm.context.evm.db.cache.clear())
+    })
+}
+
+/// Reads the state the transaction is known to touch into the state cache before it runs: the
+/// sender, the recipient, the coinbase, the access list and the extra `StorageKeys` in `keys`,
+/// where a key without a slot prefetches the account only. The batch callbacks of the db are used
+/// when it implements them.
+///
+/// The prefetched values are read by the next transaction the VM executes, whatever the config of
+/// the cache, so the state db must not change in between.
+#[no_mangle]
+pub extern "C" fn prefetch_tx(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    keys: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("prefetch_tx", errmsg, |errmsg| {
+        with_vm!(vm, errmsg, |vm| prefetch(vm, db, block, tx, keys, errmsg))
+    })
+}
+
+/// Creates a state db kept in memory, seeded with the `Genesis` alloc. The returned db is passed
+/// to the entry points like a Go state db, and must be released with `free_native_db`.
+#[no_mangle]
+pub extern "C" fn new_memory_db(
+    genesis: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> Db {
+    catch_panic("new_memory_db", errmsg, |errmsg| {
+        let genesis = match genesis.read().map(Genesis::decode).transpose() {
+            Ok(genesis) => genesis.unwrap_or_default(),
+            Err(err) => {
+                set_error(EVMError::Custom(err.to_string()), errmsg);
+                return Db::default();
+            }
+        };
+        match MemoryDb::from_genesis(genesis) {
+            Ok(memory) => into_db(Box::new(memory)),
+            Err(err) => {
+                set_error(EVMError::Database(err), errmsg);
+                Db::default()
+            }
+        }
+    })
+}
+
+/// Opens the state db persisted with sled at `path`, creating it if needed. The returned db is
+/// passed to the entry points or to `new_vm_with_db`, and must be released with `free_native_db`.
+///
+/// Committed changes are only persisted by `commit_native_block`, atomically for the whole block.
+#[no_mangle]
+pub extern "C" fn open_sled_db(path: ByteSliceView, errmsg: Option<&mut UnmanagedVector>) -> Db {
+    catch_panic("open_sled_db", errmsg, |errmsg| {
+        let Some(path) = Option::<PathBuf>::from(path) else {
+            set_error(EVMError::Database(BackendError::bad_argument()), errmsg);
+            return Db::default();
+        };
+        match SledDb::open(path) {
+            Ok(sled_db) => into_db(Box::new(sled_db)),
+            Err(err) => {
+                set_error(EVMError::Database(err), errmsg);
+                Db::default()
+            }
+        }
+    })
+}
+
+/// Persists the changes committed to a state db implemented in Rust since the previous block, as
+/// block `number` with `hash`.
+#[no_mangle]
+pub extern "C" fn commit_native_block(
+    db: Db,
+    number: u64,
+    hash: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("commit_native_block", errmsg, |errmsg| {
+        let hash = match hash.read() {
+            Some(hash) if hash.len() == B256::len_bytes() => B256::from_slice(hash),
+            _ => {
+                set_error(EVMError::Database(BackendError::bad_argument()), errmsg);
+                return;
+            }
+        };
+        if db.state.is_null() {
+            set_error(EVMError::Database(BackendError::bad_argument()), errmsg);
+            return;
+        }
+        if let Err(err) = unsafe { native_of(&db) }.commit_block(number, hash) {
+            set_error(EVMError::Database(err), errmsg);
+        }
+    })
+}
+
+/// Releases a state db implemented in Rust, e.g. created by `new_memory_db`.
+#[no_mangle]
+pub extern "C" fn free_native_db(db: Db) {
+    catch_panic("free_native_db", None, |_| {
+        if !db.state.is_null() {
+            unsafe { free_native(db) };
+        }
+    })
+}
+
+/// Executes and commits the transaction, returning an `EvmResult` with its `Receipt`, the
+/// cumulative gas used being the gas of the transaction alone, and the `CommitResult` of the state
+/// db. A transaction which cannot be executed or committed, e.g. with a bad nonce or if the state
+/// db fails, is returned as a typed `Error` result instead of through `errmsg`.
+#[no_mangle]
+pub extern "C" fn execute_tx(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("execute_tx", errmsg, |errmsg| {
+        let data = with_vm!(vm, errmsg, |vm| execute(vm, db, block, tx, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Executes the transaction without committing it, returning an `EvmResult` like `execute_tx`.
+#[no_mangle]
+pub extern "C" fn simulate_tx(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("simulate_tx", errmsg, |errmsg| {
+        let data = with_vm!(vm, errmsg, |vm| simulate(vm, db, block, tx, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Executes the transaction with the validation of `execute_tx`, without committing it. The
+/// returned `EvmResult` carries the `StateChangeset` which `execute_tx` would have committed, for
+/// the caller to apply or discard.
+#[no_mangle]
+pub extern "C" fn execute_tx_without_commit(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("execute_tx_without_commit", errmsg, |errmsg| {
+        let data = with_vm!(vm, errmsg, |vm| execute_without_commit(vm, db, block, tx, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Executes and commits the transaction like `execute_tx`, on a VM lent by the pool. Safe to call
+/// from many threads at once; a call waits for a VM when they are all in use. Concurrent calls
+/// must not commit to the same state db.
+#[no_mangle]
+pub extern "C" fn pool_execute_tx(
+    pool: pool_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("pool_execute_tx", errmsg, |errmsg| {
+        let data = with_pooled_vm!(pool, errmsg, |vm| execute(vm, db, block, tx, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Simulates the transaction like `simulate_tx`, on a VM lent by the pool. Safe to call from many
+/// threads at once; a call waits for a VM when they are all in use.
+#[no_mangle]
+pub extern "C" fn pool_simulate_tx(
+    pool: pool_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("pool_simulate_tx", errmsg, |errmsg| {
+        let data = with_pooled_vm!(pool, errmsg, |vm| simulate(vm, db, block, tx, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Executes and commits the transactions of a block in order, binding the state db and the block
+/// env once, and returns a `BlockResult`.
+///
+/// An invalid transaction makes the block invalid: the block fails with an error, unless
+/// `skip_invalid` is set, in which case invalid transactions are reported as `Error` results in
+/// place and leave the state untouched. A failed block or commit returns an error, the
+/// transactions committed before it are not reverted.
+#[no_mangle]
+pub extern "C" fn execute_block(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    txs: ByteSliceView,
+    skip_invalid: bool,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("execute_block", errmsg, |errmsg| {
+        let data = with_vm!(vm, errmsg, |vm| execute_txs(vm, db, block, txs, skip_invalid, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Starts a session executing transactions on top of each other in the block, on a new VM like
+/// `vm`. Their changes are kept in Rust, the state db is left untouched until `session_commit`, so
+/// `db` must stay valid until the session is committed or freed. Returns 0 on failure.
+///
+/// The session keeps the `state` pointer of `db` across calls, while cgo only keeps a Go pointer
+/// valid for the call it is passed to: Go must pin its state db with a `runtime.Pinner` from this
+/// call until the session is committed or freed.
+#[no_mangle]
+pub extern "C" fn new_session(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> session_t {
+    catch_panic("new_session", errmsg, |errmsg| {
+        let session = decode(block, "block").and_then(|block| {
+            let vm = get_vm(vm)?.lock()?.fresh();
+            Ok(Session::new(vm, db, block))
+        });
+        match session {
+            Ok(session) => register_session(session),
+            Err(err) => {
+                set_error(err, errmsg);
+                0
+            }
+        }
+    })
+}
+
+/// Executes the transaction in the session, on the changes of the transactions executed before
+/// it, and returns an `EvmResult` whose receipt counts the gas of the session.
+#[no_mangle]
+pub extern "C" fn session_execute(
+    session: session_t,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("session_execute", errmsg, |errmsg| {
+        let result = decode(tx, "transaction")
+            .and_then(|tx| with_session(session, |session| Ok(session.execute(tx))));
+        match result {
+            Ok(result) => UnmanagedVector::new(Some(result.try_into_vec().unwrap())),
+            Err(err) => {
+                set_error(err, errmsg);
+                UnmanagedVector::default()
+            }
+        }
+    })
+}
+
+/// Returns a checkpoint of the session, to revert the transactions executed after it with
+/// `session_revert_to`. Checkpoint ids are never reused, zero is returned on failure only.
+#[no_mangle]
+pub extern "C" fn session_checkpoint(
+    session: session_t,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> u64 {
+    catch_panic("session_checkpoint", errmsg, |errmsg| {
+        match with_session(session, |session| Ok(session.checkpoint())) {
+            Ok(checkpoint) => checkpoint,
+            Err(err) => {
+                set_error(err, errmsg);
+                0
+            }
+        }
+    })
+}
+
+/// Reverts the transactions executed in the session since the checkpoint. The checkpoints taken
+/// after it become invalid.
+#[no_mangle]
+pub extern "C" fn session_revert_to(
+    session: session_t,
+    checkpoint: u64,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    catch_panic("session_revert_to", errmsg, |errmsg| {
+        if let Err(err) = with_session(session, |session| session.revert_to(checkpoint)) {
+            set_error(err, errmsg);
+        }
+    })
+}
+
+/// Commits the changes of the session to the state db with one call of its `commit` callback, and
+/// returns its `CommitResult`. The session is freed, committed or not.
+#[no_mangle]
+pub extern "C" fn session_commit(
+    session: session_t,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("session_commit", errmsg, |errmsg| {
+        let commit = unregister_session(session).and_then(|slot| slot.lock()?.commit());
+        match commit {
+            Ok(commit) => {
+                let mut buf = Vec::new();
+                commit.encode(&mut buf).unwrap();
+                UnmanagedVector::new(Some(buf))
+            }
+            Err(err) => {
+                set_error(err, errmsg);
+                UnmanagedVector::default()
+            }
+        }
+    })
+}
+
+/// Frees the session without committing its changes.
+#[no_mangle]
+pub extern "C" fn free_session(session: session_t, errmsg: Option<&mut UnmanagedVector>) {
+    catch_panic("free_session", errmsg, |errmsg| {
+        if let Err(err) = unregister_session(session) {
+            set_error(err, errmsg);
+        }
+    })
+}
+
+/// Executes the transaction with the EIP-3155 struct logger attached and returns a
+/// `StructLogTrace`. State changes are not committed.
+#[no_mangle]
+pub extern "C" fn trace_tx(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    config: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("trace_tx", errmsg, |errmsg| {
+        let data = with_vm!(vm, errmsg, |vm| trace(vm, db, block, tx, config, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Executes the transaction with the call tracer attached and returns a `CallTrace`. State
+/// changes are not committed.
+#[no_mangle]
+pub extern "C" fn trace_call_tx(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("trace_call_tx", errmsg, |errmsg| {
+        let data = with_vm!(vm, errmsg, |vm| trace_call(vm, db, block, tx, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Executes and commits the transaction like `execute_tx`, returning a `PrestateTrace` of the
+/// touched accounts.
+#[no_mangle]
+pub extern "C" fn execute_tx_with_prestate(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    config: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("execute_tx_with_prestate", errmsg, |errmsg| {
+        let data =
+            with_vm!(vm, errmsg, |vm| trace_prestate(vm, db, block, tx, config, true, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Simulates the transaction like `simulate_tx`, returning a `PrestateTrace` of the touched
+/// accounts.
+#[no_mangle]
+pub extern "C" fn simulate_tx_with_prestate(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    config: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("simulate_tx_with_prestate", errmsg, |errmsg| {
+        let data =
+            with_vm!(vm, errmsg, |vm| trace_prestate(vm, db, block, tx, config, false, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Generates an EIP-2930 access list for the transaction, equivalent to geth's
+/// `eth_createAccessList`, and returns an `AccessListResult`. State changes are not committed.
+#[no_mangle]
+pub extern "C" fn create_access_list(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("create_access_list", errmsg, |errmsg| {
+        let data = with_vm!(vm, errmsg, |vm| access_list(vm, db, block, tx, errmsg));
+
+        UnmanagedVector::new(Some(data))
+    })
+}
+
+/// Estimates the gas limit of the transaction, equivalent to geth's `eth_estimateGas`, and returns
+/// an `EstimateGasResult`. `gas_cap` bounds the search when it is not zero.
+#[no_mangle]
+pub extern "C" fn estimate_gas(
+    vm: vm_t,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    gas_cap: u64,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> UnmanagedVector {
+    catch_panic("estimate_gas", errmsg, |errmsg| {
+        let data =
+            with_vm!(vm, errmsg, |vm| estimate_gas_limit(vm, db, block, tx, gas_cap, errmsg));
 
-// byte slice view: golang data type
-// unamangedvector: ffi safe vector data type compliants with rust's ownership and data types, for
-// returning optional error value
-#[allow(non_camel_case_types)]
-#[repr(C)]
-pub struct evm_t {}
+        UnmanagedVector::new(Some(data))
+    })
+}
 
-pub fn to_evm<'a, EXT>(ptr: *mut evm_t) -> Option<&'a mut Evm<'a, EXT, StateDB<'a>>> {
-    if ptr.is_null() {
-        None
-    } else {
-        let evm = unsafe { &mut *(ptr as *mut Evm<'a, EXT, StateDB<'a>>) };
-        Some(evm)
+fn compile_key(spec_id: SpecId, code_hash: &ByteSliceView) -> Option<ArtifactKey> {
+    match code_hash.read() {
+        Some(hash) if hash.len() == B256::len_bytes() => {
+            Some(ArtifactKey { code_hash: B256::from_slice(hash), spec_id })
+        }
+        _ => None,
     }
 }
 
-#[no_mangle]
-pub extern "C" fn new_vm(default_spec_id: u8) -> *mut evm_t {
-    let db = Db::default();
-    let state_db = StateDB::new(&db);
-    let spec = SpecId::try_from_u8(default_spec_id).unwrap_or(SpecId::OSAKA);
-    let builder = EvmBuilder::default();
-    let evm = builder.with_db(state_db).with_spec_id(spec).build();
+fn contract_compile_state(
+    vm: &mut Vm<EXTCompileWorker>,
+    code_hash: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> Vec<u8> {
+    let evm = &mut vm.evm;
+    let Some(key) = compile_key(evm.spec_id(), &code_hash) else {
+        set_error(EVMError::Database(BackendError::bad_argument()), errmsg);
+        return Vec::new();
+    };
+    let mut buf = Vec::new();
+    evm.context.external.state(key).encode(&mut buf).unwrap();
+    buf
+}
 
-    let vm = Box::into_raw(Box::new(evm));
-    vm as *mut evm_t
+fn contract_compile_states(vm: &mut Vm<EXTCompileWorker>) -> Vec<u8> {
+    let evm = &mut vm.evm;
+    let mut buf = Vec::new();
+    evm.context.external.states(evm.spec_id()).encode(&mut buf).unwrap();
+    buf
 }
 
-#[no_mangle]
-pub extern "C" fn new_vm_with_compiler(
-    default_spec_id: u8,
-    thershold: u64,
-    max_concurrent_size: usize,
-) -> *mut evm_t {
-    let db = Db::default();
-    let state_db = StateDB::new(&db);
-    let spec = SpecId::try_from_u8(default_spec_id).unwrap_or(SpecId::OSAKA);
-    let builder = EvmBuilder::default();
+fn queue_compile(
+    vm: &mut Vm<EXTCompileWorker>,
+    db: Db,
+    code_hash: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+    let Some(key) = compile_key(evm.spec_id(), &code_hash) else {
+        set_error(EVMError::Database(BackendError::bad_argument()), errmsg);
+        return;
+    };
 
-    let evm = {
-        let ext = EXTCompileWorker::new(thershold, max_concurrent_size);
-        builder
-            .with_db(state_db)
-            .with_spec_id(spec)
-            .with_external_context::<EXTCompileWorker>(ext)
-            .append_handler_register(register_handler::<StateDB>)
-            .build()
+    let bytecode = match StateDB::new(db).code_by_hash(key.code_hash) {
+        Ok(bytecode) if !bytecode.is_empty() => bytecode,
+        Ok(_) => {
+            let msg = format!("No code with hash {}", key.code_hash);
+            set_error(EVMError::Custom(msg), errmsg);
+            return;
+        }
+        Err(err) => {
+            set_error(EVMError::Database(err), errmsg);
+            return;
+        }
     };
+    evm.context.external.force_compile(key, &bytecode);
+}
 
-    let vm = Box::into_raw(Box::new(evm));
-    vm as *mut evm_t
+fn evict_contract(
+    vm: &mut Vm<EXTCompileWorker>,
+    code_hash: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    let evm = &mut vm.evm;
+    let Some(key) = compile_key(evm.spec_id(), &code_hash) else {
+        set_error(EVMError::Database(BackendError::bad_argument()), errmsg);
+        return;
+    };
+    if let Err(err) = evm.context.external.evict(key) {
+        set_error(EVMError::Custom(format!("Failed to evict contract: {err}")), errmsg);
+    }
 }
 
-#[no_mangle]
-pub extern "C" fn free_vm(vm: *mut evm_t, aot: bool) {
-    if !vm.is_null() {
-        // this will free cache when it goes out of scope
-        if aot {
-            let _ = unsafe { Box::from_raw(vm as *mut Evm<EXTCompileWorker, StateDB>) };
-        } else {
-            let _ = unsafe { Box::from_raw(vm as *mut Evm<(), StateDB>) };
+fn compile_stats(vm: &mut Vm<EXTCompileWorker>, errmsg: Option<&mut UnmanagedVector>) -> Vec<u8> {
+    let evm = &mut vm.evm;
+    match evm.context.external.stats() {
+        Ok(stats) => {
+            let mut buf = Vec::new();
+            stats.encode(&mut buf).unwrap();
+            buf
+        }
+        Err(err) => {
+            set_error(EVMError::Custom(format!("Failed to read AOT store: {err}")), errmsg);
+            Vec::new()
         }
     }
 }
 
-#[no_mangle]
-pub extern "C" fn execute_tx(
-    vm_ptr: *mut evm_t,
-    aot: bool,
+fn configure_cache<EXT>(
+    vm: &mut Vm<EXT>,
+    config: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) {
+    let evm = &mut vm.evm;
+    match decode(config, "cache config") {
+        Ok(config) => evm.context.evm.db.cache.configure(config),
+        Err(err) => set_error(err, errmsg),
+    }
+}
+
+fn cache_stats<EXT>(vm: &mut Vm<EXT>) -> Vec<u8> {
+    let evm = &mut vm.evm;
+    let mut buf = Vec::new();
+    evm.context.evm.db.cache.stats().encode(&mut buf).unwrap();
+    buf
+}
+
+fn prefetch<EXT>(
+    vm: &mut Vm<EXT>,
     db: Db,
     block: ByteSliceView,
     tx: ByteSliceView,
+    keys: ByteSliceView,
     errmsg: Option<&mut UnmanagedVector>,
-) -> UnmanagedVector {
-    let data = if aot {
-        execute::<EXTCompileWorker>(vm_ptr, db, block, tx, errmsg)
-    } else {
-        execute::<()>(vm_ptr, db, block, tx, errmsg)
+) {
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+    let decoded = decode(block, "block")
+        .and_then(|block| Ok((block, decode(tx, "transaction")?, decode(keys, "storage keys")?)));
+    let (block, tx, keys): (BlockEnv, TxEnv, StorageKeys) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => return set_error(err, errmsg),
     };
 
-    UnmanagedVector::new(Some(data))
+    let mut addresses = vec![tx.caller, block.coinbase];
+    if let TxKind::Call(to) = tx.transact_to {
+        addresses.push(to);
+    }
+    let mut slots = Vec::new();
+    for item in &tx.access_list {
+        addresses.push(item.address);
+        slots
+            .extend(item.storage_keys.iter().map(|key| (item.address, U256::from_be_bytes(key.0))));
+    }
+    for key in keys.keys {
+        if key.address.len() != 20 || key.key.len() > 32 {
+            let (address, slot) = (key.address.len(), key.key.len());
+            let msg = format!("Invalid storage key: {address} bytes of address, {slot} of slot");
+            return set_error(EVMError::Custom(msg), errmsg);
+        }
+        let address = Address::from_slice(&key.address);
+        addresses.push(address);
+        if !key.key.is_empty() {
+            slots.push((address, U256::from_be_slice(&key.key)));
+        }
+    }
+
+    evm.context.evm.db.bind(db, block.number.saturating_to());
+    if let Err(err) = evm.context.evm.db.prefetch(&addresses, &slots) {
+        set_error(EVMError::Database(err), errmsg);
+    }
 }
 
-#[no_mangle]
-pub extern "C" fn simulate_tx(
-    vm_ptr: *mut evm_t,
-    aot: bool,
+fn execute<EXT>(
+    vm: &mut Vm<EXT>,
     db: Db,
     block: ByteSliceView,
     tx: ByteSliceView,
     errmsg: Option<&mut UnmanagedVector>,
-) -> UnmanagedVector {
-    let data = if aot {
-        simulate::<EXTCompileWorker>(vm_ptr, db, block, tx, errmsg)
-    } else {
-        simulate::<()>(vm_ptr, db, block, tx, errmsg)
+) -> Vec<u8> {
+    let decoded = decode(block, "block").and_then(|block| Ok((block, decode(tx, "transaction")?)));
+    let (block, tx): (BlockEnv, TypedTxEnv) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
+    };
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+
+    // TODO: check is it safe way to set evm
+    evm.context.evm.db.bind(db, block.number.saturating_to());
+    evm.context.evm.db.cache.begin_tx();
+    evm.context.evm.inner.env.block = block;
+    evm.context.evm.inner.env.tx = tx.env;
+
+    let res = match evm.transact_commit() {
+        Ok(res) => res,
+        Err(err) => return err.try_into_vec().unwrap(),
+    };
+    let commit = match take_commit(evm) {
+        Ok(commit) => commit,
+        Err(err) => return err.try_into_vec().unwrap(),
+    };
+    let gas_used = res.gas_used();
+    let env = &evm.context.evm.inner.env;
+    EvmResult { commit, ..result_with_receipt(res, env, tx.tx_type, gas_used) }
+        .try_into_vec()
+        .unwrap()
+}
+
+fn simulate<EXT>(
+    vm: &mut Vm<EXT>,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> Vec<u8> {
+    let decoded = decode(block, "block").and_then(|block| Ok((block, decode(tx, "transaction")?)));
+    let (block, tx): (BlockEnv, TypedTxEnv) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
     };
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+    // TODO: check is it safe way to set evm
+    evm.context.evm.db.bind(db, block.number.saturating_to());
+    evm.context.evm.db.cache.begin_tx();
+    evm.context.evm.inner.env.block = block;
+    evm.context.evm.inner.env.tx = tx.env;
 
-    UnmanagedVector::new(Some(data))
+    // transact witout verification
+    match evm.transact_preverified() {
+        Ok(res) => {
+            let gas_used = res.result.gas_used();
+            let env = &evm.context.evm.inner.env;
+            result_with_receipt(res.result, env, tx.tx_type, gas_used).try_into_vec().unwrap()
+        }
+        Err(err) => err.try_into_vec().unwrap(),
+    }
 }
 
-fn execute<EXT>(
-    vm_ptr: *mut evm_t,
+fn execute_without_commit<EXT>(
+    vm: &mut Vm<EXT>,
     db: Db,
     block: ByteSliceView,
     tx: ByteSliceView,
     errmsg: Option<&mut UnmanagedVector>,
 ) -> Vec<u8> {
-    let evm = match to_evm::<EXT>(vm_ptr) {
-        Some(vm) => vm,
-        None => {
-            panic!("Failed to get VM");
+    let decoded = decode(block, "block").and_then(|block| Ok((block, decode(tx, "transaction")?)));
+    let (block, tx): (BlockEnv, TypedTxEnv) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
         }
     };
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+    evm.context.evm.db.bind(db, block.number.saturating_to());
+    evm.context.evm.db.cache.begin_tx();
+    evm.context.evm.inner.env.block = block;
+    evm.context.evm.inner.env.tx = tx.env;
 
-    let statedb = StateDB::new(&db);
-    // TODO: check is it safe way to set evm
-    evm.context.evm.db = statedb;
-    evm.context.evm.inner.env.block = block.try_into().unwrap();
-    evm.context.evm.inner.env.tx = tx.try_into().unwrap();
+    let res = match evm.transact() {
+        Ok(res) => res,
+        Err(err) => return err.try_into_vec().unwrap(),
+    };
+    let changeset = match evm.context.evm.db.changeset(&res.state) {
+        Ok(changes) => changes,
+        Err(err) => return EVMError::Database(err).try_into_vec().unwrap(),
+    };
+    let gas_used = res.result.gas_used();
+    let env = &evm.context.evm.inner.env;
+    EvmResult {
+        changeset: Some(changeset.into()),
+        ..result_with_receipt(res.result, env, tx.tx_type, gas_used)
+    }
+    .try_into_vec()
+    .unwrap()
+}
+
+fn execute_txs<EXT>(
+    vm: &mut Vm<EXT>,
+    db: Db,
+    block: ByteSliceView,
+    txs: ByteSliceView,
+    skip_invalid: bool,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> Vec<u8> {
+    let decoded =
+        decode(block, "block").and_then(|block| Ok((block, decode(txs, "transactions")?)));
+    let (block, txs): (BlockEnv, Vec<TypedTxEnv>) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
+    };
+    let db = vm.db_or(db);
+    let (tx_types, txs): (Vec<u8>, Vec<TxEnv>) =
+        txs.into_iter().map(|tx| (tx.tx_type, tx.env)).unzip();
+
+    let evm = &mut vm.evm;
+    evm.context.evm.db.bind(db, block.number.saturating_to());
+    evm.context.evm.inner.env.block = block;
+    let basefee = evm.block().basefee;
+
+    // custom precompiles see the state of their caller, not the one versioned by the executor, and
+    // the executor does not run the handler enforcing the chain rules
+    let workers =
+        vm.parallel_workers.filter(|_| vm.precompiles.is_empty() && !vm.chain.needs_handler());
+    let outcomes = match workers {
+        Some(workers) => transact_parallel(evm, db, &txs, workers, skip_invalid),
+        None => transact_sequential(evm, &txs, skip_invalid),
+    };
+    let outcomes = match outcomes {
+        Ok(outcomes) => outcomes,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
+    };
+
+    let mut block_result = BlockResult::default();
+    let mut total_fees = U256::ZERO;
+    for ((tx, tx_type), outcome) in txs.into_iter().zip(tx_types).zip(outcomes) {
+        evm.context.evm.inner.env.tx = tx;
+        let effective_tip = evm.context.evm.inner.env.effective_gas_price().saturating_sub(basefee);
+
+        let result: EvmResult = match outcome {
+            Ok((res, commit)) => {
+                block_result.gas_used += res.gas_used();
+                block_result.blob_gas_used += evm.context.evm.inner.env.tx.get_total_blob_gas();
+                total_fees += effective_tip * U256::from(res.gas_used());
+                block_result.logs.extend(res.logs().iter().cloned().map(Log::from));
+                let env = &evm.context.evm.inner.env;
+                EvmResult {
+                    commit,
+                    ..result_with_receipt(res, env, tx_type, block_result.gas_used)
+                }
+            }
+            Err(err) => EVMError::Transaction(err).into(),
+        };
+        block_result.results.push(TransactionResult {
+            result: Some(result),
+            cumulative_gas_used: block_result.gas_used,
+        });
+    }
+    block_result.total_fees = total_fees.to_be_bytes_vec();
+    let excess_blob_gas = evm.block().get_blob_excess_gas().unwrap_or_default();
+    block_result.excess_blob_gas =
+        vm.chain.next_excess_blob_gas(excess_blob_gas, block_result.blob_gas_used);
+
+    let mut buf = Vec::new();
+    block_result.encode(&mut buf).unwrap();
+    buf
+}
+
+/// The result of a transaction of a block with the outcome of its commit, or why it is invalid.
+type TxOutcome = Result<(ExecutionResult, Option<CommitResult>), InvalidTransaction>;
+
+/// Executes and commits `txs` one after the other. Invalid transactions are reported in place
+/// with `skip_invalid`, otherwise the first one fails the block.
+fn transact_sequential<EXT>(
+    evm: &mut Evm<'_, EXT, StateDB>,
+    txs: &[TxEnv],
+    skip_invalid: bool,
+) -> Result<Vec<TxOutcome>, EVMError<BackendError>> {
+    let mut outcomes = Vec::with_capacity(txs.len());
+    for tx in txs {
+        evm.context.evm.db.cache.begin_tx();
+        evm.context.evm.inner.env.tx = tx.clone();
+        match evm.transact_commit() {
+            Ok(res) => outcomes.push(Ok((res, take_commit(evm)?))),
+            Err(EVMError::Transaction(err)) if skip_invalid => outcomes.push(Err(err)),
+            Err(EVMError::Transaction(err)) => return Err(invalid_tx(outcomes.len(), err)),
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(outcomes)
+}
+
+/// Executes `txs` on the parallel executor, then commits their state changes in order. Without
+/// `skip_invalid`, nothing is committed when a transaction is invalid.
+fn transact_parallel<EXT>(
+    evm: &mut Evm<'_, EXT, StateDB>,
+    db: Db,
+    txs: &[TxEnv],
+    workers: NonZeroUsize,
+    skip_invalid: bool,
+) -> Result<Vec<TxOutcome>, EVMError<BackendError>> {
+    let executor = ParallelExecutor::new(workers);
+    let outcomes =
+        executor.execute(StateDB::new(db), &evm.context.evm.inner.env, evm.spec_id(), txs)?;
+    if !skip_invalid {
+        if let Some((index, Err(err))) = outcomes.iter().enumerate().find(|(_, res)| res.is_err()) {
+            return Err(invalid_tx(index, err.clone()));
+        }
+    }
+
+    let mut committed = Vec::with_capacity(outcomes.len());
+    for outcome in outcomes {
+        committed.push(match outcome {
+            Ok(res) => {
+                evm.context.evm.db.commit(res.state);
+                Ok((res.result, take_commit(evm)?))
+            }
+            Err(err) => Err(err),
+        });
+    }
+    Ok(committed)
+}
+
+fn invalid_tx(index: usize, err: InvalidTransaction) -> EVMError<BackendError> {
+    EVMError::Custom(format!("Transaction {index} of the block is invalid: {err}"))
+}
+
+/// The outcome of the commit of the last transaction. A failed commit fails the call, the state db
+/// may be left with part of the changes.
+fn take_commit<EXT>(
+    evm: &mut Evm<'_, EXT, StateDB>,
+) -> Result<Option<CommitResult>, EVMError<BackendError>> {
+    evm.context.evm.db.take_commit().transpose().map_err(EVMError::Database)
+}
+
+fn trace<EXT>(
+    vm: &mut Vm<EXT>,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    config: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> Vec<u8> {
+    let decoded = decode(config, "struct logger config")
+        .and_then(|config| Ok((config, decode(block, "block")?, decode(tx, "transaction")?)));
+    let (config, block, tx): (StructLoggerConfig, BlockEnv, TxEnv) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
+    };
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+    let logger = StructLogger::new(config);
 
-    let result = evm.transact_commit();
+    let result = inspect(evm, &vm.precompiles, &vm.chain, db, block, tx, logger);
     match result {
-        Ok(res) => res.try_into_vec().unwrap(),
+        Ok((res, logger)) => {
+            let trace =
+                StructLogTrace { result: Some(res.result.into()), struct_logs: logger.into_logs() };
+            let mut buf = Vec::new();
+            trace.encode(&mut buf).unwrap();
+            buf
+        }
         Err(err) => {
             set_error(err, errmsg);
             Vec::new()
@@ -138,32 +1377,503 @@ fn execute<EXT>(
     }
 }
 
-fn simulate<EXT>(
-    vm_ptr: *mut evm_t,
+fn trace_call<EXT>(
+    vm: &mut Vm<EXT>,
     db: Db,
     block: ByteSliceView,
     tx: ByteSliceView,
     errmsg: Option<&mut UnmanagedVector>,
 ) -> Vec<u8> {
-    let evm = match to_evm::<EXT>(vm_ptr) {
-        Some(vm) => vm,
-        None => {
-            panic!("Failed to get VM");
+    let decoded = decode(block, "block").and_then(|block| Ok((block, decode(tx, "transaction")?)));
+    let (block, tx): (BlockEnv, TxEnv) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
         }
     };
-    let state_db = StateDB::new(&db);
-    // TODO: check is it safe way to set evm
-    evm.context.evm.db = state_db;
-    evm.context.evm.inner.env.block = block.try_into().unwrap();
-    evm.context.evm.inner.env.tx = tx.try_into().unwrap();
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+    let gas_limit = tx.gas_limit;
 
-    // transact witout verification
-    let result = evm.transact_preverified();
+    let result = inspect(evm, &vm.precompiles, &vm.chain, db, block, tx, CallTracer::new());
+    match result {
+        Ok((res, tracer)) => {
+            // the outermost frame reports the gas of the whole transaction, like geth does
+            let frame = tracer.into_root().map(|mut frame| {
+                frame.gas = gas_limit;
+                frame.gas_used = res.result.gas_used();
+                frame
+            });
+            let trace = CallTrace { result: Some(res.result.into()), frame };
+            let mut buf = Vec::new();
+            trace.encode(&mut buf).unwrap();
+            buf
+        }
+        Err(err) => {
+            set_error(err, errmsg);
+            Vec::new()
+        }
+    }
+}
+
+fn trace_prestate<EXT>(
+    vm: &mut Vm<EXT>,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    config: ByteSliceView,
+    commit: bool,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> Vec<u8> {
+    let decoded = decode(config, "prestate tracer config")
+        .and_then(|config| Ok((config, decode(block, "block")?, decode(tx, "transaction")?)));
+    let (config, block, tx): (PrestateTracerConfig, BlockEnv, TxEnv) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
+    };
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+    let tracer = PrestateTracer::new(config);
+
+    evm.context.evm.db.bind(db, block.number.saturating_to());
+    evm.context.evm.db.cache.begin_tx();
+    evm.context.evm.inner.env.block = block;
+    evm.context.evm.inner.env.tx = tx;
+
+    let result = if commit { evm.transact() } else { evm.transact_preverified() };
+    let res = match result {
+        Ok(res) => res,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
+    };
+    // the pre state is read before the changes reach the db
+    let (pre, post) = match tracer.trace(&mut evm.context.evm.db, &res.state) {
+        Ok(states) => states,
+        Err(err) => {
+            set_error(EVMError::Database(err), errmsg);
+            return Vec::new();
+        }
+    };
+    let mut result = EvmResult::from(res.result);
+    if commit {
+        evm.context.evm.db.commit(res.state);
+        result.commit = match take_commit(evm) {
+            Ok(commit) => commit,
+            Err(err) => {
+                set_error(err, errmsg);
+                return Vec::new();
+            }
+        };
+    }
+
+    let trace = PrestateTrace { result: Some(result), pre, post };
+    let mut buf = Vec::new();
+    trace.encode(&mut buf).unwrap();
+    buf
+}
+
+fn access_list<EXT>(
+    vm: &mut Vm<EXT>,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> Vec<u8> {
+    let decoded = decode(block, "block").and_then(|block| Ok((block, decode(tx, "transaction")?)));
+    let (block, tx): (BlockEnv, TxEnv) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
+    };
+    let db = vm.db_or(db);
+    let evm = &vm.evm;
+
+    let precompiles = vm.precompiles.addresses();
+    let result = create_access_list(
+        &mut StateDB::new(db),
+        evm.spec_id(),
+        tx,
+        precompiles,
+        |tx, inspector| {
+            inspect(evm, &vm.precompiles, &vm.chain, db, block.clone(), tx.clone(), inspector)
+        },
+    );
     match result {
-        Ok(res) => res.result.try_into_vec().unwrap(),
+        Ok((access_list, result)) => {
+            let result = AccessListResult {
+                access_list: access_list.into_iter().map(Into::into).collect(),
+                gas_used: result.gas_used(),
+                result: Some(result.into()),
+            };
+            let mut buf = Vec::new();
+            result.encode(&mut buf).unwrap();
+            buf
+        }
         Err(err) => {
             set_error(err, errmsg);
             Vec::new()
         }
     }
 }
+
+fn estimate_gas_limit<EXT>(
+    vm: &mut Vm<EXT>,
+    db: Db,
+    block: ByteSliceView,
+    tx: ByteSliceView,
+    gas_cap: u64,
+    errmsg: Option<&mut UnmanagedVector>,
+) -> Vec<u8> {
+    let decoded = decode(block, "block").and_then(|block| Ok((block, decode(tx, "transaction")?)));
+    let (block, tx): (BlockEnv, TxEnv) = match decoded {
+        Ok(decoded) => decoded,
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
+    };
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+    let mut env = evm.context.evm.inner.env.clone();
+    env.block = block;
+    env.tx = tx;
+
+    // state reads are cached across the runs of the search
+    let state = State::builder().with_database(StateDB::new(db)).build();
+    let mut estimating = EvmBuilder::default()
+        .with_db(state)
+        .with_env(env)
+        .with_spec_id(evm.spec_id())
+        .append_handler_register_box(vm.precompiles.handler_register())
+        .append_handler_register_box(vm.chain.handler_register())
+        .build();
+
+    let result = match estimate(&mut estimating, gas_cap) {
+        Ok(GasEstimate::Gas(gas)) => EstimateGasResult { gas, failure: None },
+        Ok(GasEstimate::Failure(res)) => EstimateGasResult { gas: 0, failure: Some(res.into()) },
+        Err(err) => {
+            set_error(err, errmsg);
+            return Vec::new();
+        }
+    };
+    let mut buf = Vec::new();
+    result.encode(&mut buf).unwrap();
+    buf
+}
+
+/// Runs the transaction on a one-off EVM sharing the env, spec, custom precompiles and chain rules
+/// of `evm`, with `inspector` attached. Nothing is committed; the state changes are returned with
+/// the result.
+fn inspect<EXT, I>(
+    evm: &Evm<'_, EXT, StateDB>,
+    precompiles: &PrecompileRegistry,
+    chain: &ChainRules,
+    db: Db,
+    block: BlockEnv,
+    tx: TxEnv,
+    inspector: I,
+) -> Result<(ResultAndState, I), EVMError<BackendError>>
+where
+    I: Inspector<StateDB>,
+{
+    let mut env = evm.context.evm.inner.env.clone();
+    env.block = block;
+    env.tx = tx;
+
+    let mut inspecting = EvmBuilder::default()
+        .with_db(StateDB::new(db))
+        .with_external_context(inspector)
+        .with_env(env)
+        .with_spec_id(evm.spec_id())
+        .append_handler_register_box(precompiles.handler_register())
+        .append_handler_register_box(chain.handler_register())
+        .append_handler_register(inspector_handle_register)
+        .build();
+    let result = inspecting.transact()?;
+
+    Ok((result, inspecting.into_context().external))
+}
+
+/// The addresses of an `Addresses` proto, 20 bytes each.
+fn precompile_addresses(addresses: ByteSliceView) -> Result<Vec<Address>, EVMError<BackendError>> {
+    let addresses = addresses.read().unwrap_or_default();
+    let addresses = Addresses::decode(addresses)
+        .map_err(|err| EVMError::Database(BackendError::unknown(err.to_string())))?;
+    addresses
+        .addresses
+        .iter()
+        .map(|address| {
+            Address::try_from(address.as_slice())
+                .map_err(|_| EVMError::Database(BackendError::bad_argument()))
+        })
+        .collect()
+}
+
+fn plugin_precompiles(
+    path: ByteSliceView,
+) -> Result<Vec<(Address, Arc<dyn CustomPrecompile>)>, EVMError<BackendError>> {
+    match Option::<PathBuf>::from(path) {
+        Some(path) => load_plugin(&path),
+        None => Err(EVMError::Database(BackendError::bad_argument())),
+    }
+}
+
+/// Decodes an argument of an entry point, `what` naming it in the error.
+fn decode<T>(view: ByteSliceView, what: &'static str) -> Result<T, EVMError<BackendError>>
+where
+    T: TryFrom<ByteSliceView, Error = DecodeError>,
+{
+    T::try_from(view).map_err(|err| EVMError::Database(BackendError::decode(what, err)))
+}
+
+fn chain_config(config: ByteSliceView) -> Result<ChainConfig, EVMError<BackendError>> {
+    decode(config, "chain config")
+}
+
+/// Starts the compile worker of a `CompilerConfig`.
+fn compile_worker(
+    config: ByteSliceView,
+    spec_id: SpecId,
+) -> Result<EXTCompileWorker, EVMError<BackendError>> {
+    let config: CompilerConfig = decode(config, "compiler config")?;
+    EXTCompileWorker::new(config, spec_id)
+        .map_err(|err| EVMError::Custom(format!("Failed to open AOT store: {err}")))
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use alloy_primitives::address;
+
+    use super::*;
+    use crate::v1::types::{Block, GenesisAccount, Transaction, Transactions};
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+    const EMITTER: Address = address!("2000000000000000000000000000000000000002");
+
+    fn transaction(nonce: u64) -> Transaction {
+        Transaction {
+            caller: SENDER.to_vec(),
+            gas_limit: 100_000,
+            gas_price: U256::from(12).to_be_bytes_vec(),
+            gas_priority_fee: U256::from(2).to_be_bytes_vec(),
+            nonce: Some(nonce),
+            transact_to: EMITTER.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    fn block() -> Vec<u8> {
+        Block {
+            coinbase: Address::ZERO.to_vec(),
+            gas_limit: U256::from(30_000_000).to_be_bytes_vec(),
+            basefee: U256::from(10).to_be_bytes_vec(),
+            prevrandao: B256::with_last_byte(1).to_vec(),
+            ..Default::default()
+        }
+        .encode_to_vec()
+    }
+
+    #[test]
+    fn execute_block_fails_on_invalid_transactions() {
+        let balance = U256::from(1_000_000_000).to_be_bytes_vec();
+        // LOG0(0, 0)
+        let code = vec![0x60, 0x00, 0x60, 0x00, 0xa0, 0x00];
+        let alloc = [
+            (SENDER.to_string(), GenesisAccount { balance, ..Default::default() }),
+            (EMITTER.to_string(), GenesisAccount { code, ..Default::default() }),
+        ];
+        let genesis = Genesis { alloc: alloc.into() };
+        let db = into_db(Box::new(MemoryDb::from_genesis(genesis).unwrap()));
+        let mut vm = interpreted_vm(SpecId::SHANGHAI, Default::default(), Default::default());
+
+        let block = block();
+        // the second transaction reuses the nonce of the first one
+        let txs =
+            Transactions { transactions: vec![transaction(0), transaction(0), transaction(1)] }
+                .encode_to_vec();
+
+        let mut errmsg = UnmanagedVector::default();
+        let view = |bytes: &Vec<u8>| ByteSliceView::new(bytes);
+        let data = execute_txs(&mut vm, db, view(&block), view(&txs), false, Some(&mut errmsg));
+        assert!(data.is_empty());
+        let msg = String::from_utf8(errmsg.consume().unwrap()).unwrap();
+        assert!(msg.contains("Transaction 1 of the block is invalid"), "{msg}");
+
+        // the first transaction was committed before the block failed
+        let txs =
+            Transactions { transactions: vec![transaction(1), transaction(1), transaction(2)] }
+                .encode_to_vec();
+        let data = execute_txs(&mut vm, db, view(&block), view(&txs), true, None);
+        let result = BlockResult::decode(data.as_slice()).unwrap();
+        // two calls emitting a log, PUSH1 twice and LOG0
+        let gas = 21_000 + 3 + 3 + 375;
+        assert_eq!(result.gas_used, 2 * gas);
+        assert_eq!(result.logs.len(), 2);
+        // a tip of 2 per gas
+        assert_eq!(result.total_fees, U256::from(2 * 2 * gas).to_be_bytes_vec());
+        let cumulative: Vec<u64> =
+            result.results.iter().map(|result| result.cumulative_gas_used).collect();
+        assert_eq!(cumulative, [gas, gas, 2 * gas]);
+        assert!(matches!(
+            result.results[1].result.as_ref().unwrap().result,
+            Some(crate::v1::types::evm_result::Result::Error(_))
+        ));
+
+        drop(vm);
+        unsafe { free_native(db) };
+    }
+
+    #[test]
+    fn compiler_calls_reject_interpreted_vms() {
+        let vm = register(AnyVm::Interpreted(interpreted_vm(
+            SpecId::CANCUN,
+            Default::default(),
+            Default::default(),
+        )));
+        let code_hash = B256::repeat_byte(0xaa);
+        let code_hash = || ByteSliceView::new(code_hash.as_slice());
+        let rejected = |call: &dyn Fn(Option<&mut UnmanagedVector>)| {
+            let mut errmsg = UnmanagedVector::default();
+            call(Some(&mut errmsg));
+            let msg = String::from_utf8(errmsg.consume().unwrap()).unwrap();
+            assert!(msg.contains("created without the AOT compiler"), "{msg}");
+        };
+
+        rejected(&|errmsg| {
+            let _ = compile_status(vm, code_hash(), errmsg);
+        });
+        rejected(&|errmsg| {
+            let _ = compile_call_counts(vm, errmsg);
+        });
+        rejected(&|errmsg| force_compile(vm, Db::default(), code_hash(), errmsg));
+        rejected(&|errmsg| evict_compiled(vm, code_hash(), errmsg));
+        rejected(&|errmsg| {
+            let _ = compiler_stats(vm, errmsg);
+        });
+
+        unregister(vm).unwrap();
+    }
+
+    #[test]
+    fn execute_without_commit_returns_the_committed_changes() {
+        let balance = U256::from(1_000_000_000).to_be_bytes_vec();
+        // PUSH1 0x2a PUSH0 SSTORE STOP
+        let code = vec![0x60, 0x2a, 0x5f, 0x55, 0x00];
+        let alloc = [
+            (SENDER.to_string(), GenesisAccount { balance, ..Default::default() }),
+            (EMITTER.to_string(), GenesisAccount { code, ..Default::default() }),
+        ];
+        let genesis = Genesis { alloc: alloc.into() };
+        let db = into_db(Box::new(MemoryDb::from_genesis(genesis).unwrap()));
+        let mut vm = interpreted_vm(SpecId::SHANGHAI, Default::default(), Default::default());
+        let (block, tx) = (block(), transaction(0).encode_to_vec());
+        let view = |bytes: &Vec<u8>| ByteSliceView::new(bytes);
+
+        let uncommitted = execute_without_commit(&mut vm, db, view(&block), view(&tx), None);
+        let uncommitted = EvmResult::decode(uncommitted.as_slice()).unwrap();
+        let changeset = uncommitted.changeset.clone().unwrap();
+
+        // the db is untouched, the transaction executes again the same way
+        let again = execute_without_commit(&mut vm, db, view(&block), view(&tx), None);
+        assert_eq!(EvmResult::decode(again.as_slice()).unwrap(), uncommitted);
+
+        let committed = execute(&mut vm, db, view(&block), view(&tx), None);
+        let committed = EvmResult::decode(committed.as_slice()).unwrap();
+        assert_eq!(committed.changeset, None);
+        assert_eq!(committed.receipt, uncommitted.receipt);
+
+        // the db holds what the changeset listed
+        let mut state = StateDB::new(db);
+        let accounts = changeset.accounts.unwrap().accounts;
+        assert!(accounts.contains_key(&SENDER.to_string()));
+        for (address, account) in accounts {
+            let info = state.basic(Address::from_str(&address).unwrap()).unwrap().unwrap();
+            assert_eq!(info.nonce, account.nonce);
+            assert_eq!(info.balance, U256::from_be_slice(&account.balance));
+        }
+        let storages = changeset.storages.unwrap().storages;
+        let slots = &storages[&EMITTER.to_string()].storage;
+        assert_eq!(slots.len(), 1);
+        for (slot, value) in slots {
+            let value = U256::from_be_slice(value);
+            assert_eq!(state.storage(EMITTER, U256::from_str(slot).unwrap()).unwrap(), value);
+        }
+        assert_eq!(state.storage(EMITTER, U256::ZERO).unwrap(), U256::from(0x2a));
+
+        drop(vm);
+        unsafe { free_native(db) };
+    }
+
+    #[test]
+    fn fails_on_undecodable_arguments() {
+        let mut vm = interpreted_vm(SpecId::CANCUN, Default::default(), Default::default());
+        let (block, tx) = (block(), transaction(0).encode_to_vec());
+        let view = |bytes: &Vec<u8>| ByteSliceView::new(bytes);
+        let failure = |call: &mut dyn FnMut(Option<&mut UnmanagedVector>) -> Vec<u8>| {
+            let mut errmsg = UnmanagedVector::default();
+            assert!(call(Some(&mut errmsg)).is_empty());
+            String::from_utf8(errmsg.consume().unwrap()).unwrap()
+        };
+
+        let msg = failure(&mut |errmsg| {
+            execute(&mut vm, Db::default(), ByteSliceView::new(&[0xff, 0xff]), view(&tx), errmsg)
+        });
+        assert!(msg.contains("Cannot decode the block"), "{msg}");
+        let msg = failure(&mut |errmsg| {
+            simulate(&mut vm, Db::default(), view(&block), ByteSliceView::nil(), errmsg)
+        });
+        assert!(msg.contains("Cannot decode the transaction"), "{msg}");
+        let msg = failure(&mut |errmsg| {
+            let config = ByteSliceView::new(&[0xff, 0xff]);
+            trace(&mut vm, Db::default(), view(&block), view(&tx), config, errmsg)
+        });
+        assert!(msg.contains("Cannot decode the struct logger config"), "{msg}");
+    }
+
+    #[test]
+    fn new_vms_take_the_chain_config() {
+        let config = ChainConfig { chain_id: 7, ..Default::default() }.encode_to_vec();
+        let vm =
+            new_vm_with_db(SpecId::CANCUN as u8, Db::default(), ByteSliceView::new(&config), None);
+        let chain_id = match &mut *get_vm(vm).unwrap().lock().unwrap() {
+            AnyVm::Interpreted(vm) => vm.evm.cfg_mut().chain_id,
+            AnyVm::Compiled(_) => unreachable!("the VM has no compile worker"),
+        };
+        assert_eq!(chain_id, 7);
+        free_vm(vm, None);
+
+        // an invalid config fails before the store of the compiler is opened
+        let mut errmsg = UnmanagedVector::default();
+        let invalid = ByteSliceView::new(&[0xff, 0xff]);
+        let vm = new_vm_with_compiler(
+            SpecId::CANCUN as u8,
+            ByteSliceView::nil(),
+            invalid,
+            Some(&mut errmsg),
+        );
+        assert_eq!(vm, 0);
+        assert!(errmsg.consume().is_some());
+    }
+
+    #[test]
+    fn new_vm_fails_without_its_plugin() {
+        let mut errmsg = UnmanagedVector::default();
+        let path = ByteSliceView::new(b"/nonexistent/libplugin.so");
+        let chain = ByteSliceView::nil();
+        let vm =
+            new_vm_with_precompile_plugin(SpecId::CANCUN as u8, path, chain, Some(&mut errmsg));
+        assert_eq!(vm, 0);
+        let msg = String::from_utf8(errmsg.consume().unwrap()).unwrap();
+        assert!(msg.contains("/nonexistent/libplugin.so"), "{msg}");
+    }
+}
@@ -1,8 +1,17 @@
+mod chain;
+mod compiler;
 mod error;
+mod estimate;
+mod inspectors;
 mod interface;
 mod memory;
+mod parallel;
+mod pool;
+mod precompiles;
+mod session;
 mod states;
 mod types;
+mod vm;
 
 mod evm {
     pub mod v1 {
@@ -14,3 +23,6 @@ mod evm {
 
 pub use evm::*;
 pub use interface::*;
+pub use pool::pool_t;
+pub use session::session_t;
+pub use vm::vm_t;
@@ -6,6 +6,8 @@ use std::{
 
 use serde::Serialize;
 
+use crate::error::catch_panic;
+
 /// A view into an externally owned byte slice (Go `[]byte`).
 /// Use this for the current call only. A view cannot be copied for safety reasons.
 /// If you need a copy, use [`ByteSliceView::to_owned`].
@@ -92,6 +94,20 @@ impl U8SliceView {
             None => Self { is_none: true, ptr: std::ptr::null::<u8>(), len: 0 },
         }
     }
+
+    /// Provides a reference to the viewed data, for state dbs implemented in Rust.
+    /// This is safe as long as the `U8SliceView` is constructed correctly.
+    pub fn read(&self) -> Option<&[u8]> {
+        if self.is_none {
+            None
+        } else if self.len == 0 {
+            // slice::from_raw_parts needs a non-null pointer even for empty slices, see
+            // `new_unmanaged_vector`
+            Some(&[])
+        } else {
+            Some(unsafe { slice::from_raw_parts(self.ptr, self.len) })
+        }
+    }
 }
 
 /// An optional Vector type that requires explicit creation and destruction
@@ -213,22 +229,26 @@ pub unsafe extern "C" fn new_unmanaged_vector(
     ptr: *const u8,
     length: usize,
 ) -> UnmanagedVector {
-    if nil {
-        UnmanagedVector::new(None)
-    } else if length == 0 {
-        UnmanagedVector::new(Some(Vec::new()))
-    } else {
-        // In slice::from_raw_parts, `data` must be non-null and aligned even for zero-length
-        // slices. For this reason we cover the length == 0 case separately above.
-        let external_memory = unsafe { slice::from_raw_parts(ptr, length) };
-        let copy = Vec::from(external_memory);
-        UnmanagedVector::new(Some(copy))
-    }
+    catch_panic("new_unmanaged_vector", None, |_| {
+        if nil {
+            UnmanagedVector::new(None)
+        } else if length == 0 {
+            UnmanagedVector::new(Some(Vec::new()))
+        } else {
+            // In slice::from_raw_parts, `data` must be non-null and aligned even for zero-length
+            // slices. For this reason we cover the length == 0 case separately above.
+            let external_memory = unsafe { slice::from_raw_parts(ptr, length) };
+            let copy = Vec::from(external_memory);
+            UnmanagedVector::new(Some(copy))
+        }
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn destroy_unmanaged_vector(v: UnmanagedVector) {
-    let _ = v.consume();
+    catch_panic("destroy_unmanaged_vector", None, |_| {
+        let _ = v.consume();
+    })
 }
 
 #[cfg(test)]
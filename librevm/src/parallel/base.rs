@@ -0,0 +1,87 @@
+use std::sync::Mutex;
+
+use alloy_primitives::{map::HashMap, Address, B256, U256};
+use revm::{
+    primitives::{AccountInfo, Bytecode},
+    Database,
+};
+
+/// The state before the block, shared by all workers.
+///
+/// Reads are serialized through a lock since the backing store (e.g. the Go `StateDB`) is not
+/// thread safe, and cached so that re-executions do not read the same keys again.
+pub struct BaseState<DB> {
+    inner: Mutex<BaseCache<DB>>,
+}
+
+struct BaseCache<DB> {
+    db: DB,
+    accounts: HashMap<Address, Option<AccountInfo>>,
+    storage: HashMap<(Address, U256), U256>,
+    codes: HashMap<B256, Bytecode>,
+    block_hashes: HashMap<u64, B256>,
+}
+
+impl<DB: Database> BaseState<DB> {
+    pub fn new(db: DB) -> Self {
+        Self {
+            inner: Mutex::new(BaseCache {
+                db,
+                accounts: HashMap::default(),
+                storage: HashMap::default(),
+                codes: HashMap::default(),
+                block_hashes: HashMap::default(),
+            }),
+        }
+    }
+
+    /// Account info without code, the code is read through [`BaseState::code_by_hash`].
+    pub fn basic(&self, address: Address) -> Result<Option<AccountInfo>, DB::Error> {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(info) = inner.accounts.get(&address) {
+            return Ok(info.clone());
+        }
+
+        let mut info = inner.db.basic(address)?;
+        if let Some(info) = info.as_mut() {
+            if let Some(code) = info.code.take() {
+                inner.codes.insert(info.code_hash, code);
+            }
+        }
+        inner.accounts.insert(address, info.clone());
+        Ok(info)
+    }
+
+    pub fn storage(&self, address: Address, index: U256) -> Result<U256, DB::Error> {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(value) = inner.storage.get(&(address, index)) {
+            return Ok(*value);
+        }
+
+        let value = inner.db.storage(address, index)?;
+        inner.storage.insert((address, index), value);
+        Ok(value)
+    }
+
+    pub fn code_by_hash(&self, code_hash: B256) -> Result<Bytecode, DB::Error> {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(code) = inner.codes.get(&code_hash) {
+            return Ok(code.clone());
+        }
+
+        let code = inner.db.code_by_hash(code_hash)?;
+        inner.codes.insert(code_hash, code.clone());
+        Ok(code)
+    }
+
+    pub fn block_hash(&self, number: u64) -> Result<B256, DB::Error> {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(hash) = inner.block_hashes.get(&number) {
+            return Ok(*hash);
+        }
+
+        let hash = inner.db.block_hash(number)?;
+        inner.block_hashes.insert(number, hash);
+        Ok(hash)
+    }
+}
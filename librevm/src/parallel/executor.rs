@@ -0,0 +1,248 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+use alloy_primitives::{Address, B256, U256};
+use revm::{
+    handler::register::EvmHandler,
+    interpreter::Gas,
+    primitives::{
+        Account, AccountInfo, Bytecode, EVMError, Env, EvmState, InvalidTransaction,
+        ResultAndState, SpecId, TxEnv,
+    },
+    Context, Database, EvmBuilder,
+};
+
+use super::{
+    base::BaseState,
+    mv_memory::{Location, MvMemory, Value},
+};
+
+/// Outcome of a transaction of the block: invalid transactions are reported in place, like
+/// [`revm::Evm::transact_commit`] does, and leave the state untouched.
+pub type TxOutcome = Result<ResultAndState, InvalidTransaction>;
+
+/// Block-STM style executor.
+///
+/// Transactions are executed optimistically on `workers` threads against a multi-version memory
+/// of the writes of the transactions before them. The reads of every execution are validated once
+/// the round is over; the ones which read a stale value are executed again in the next round. The
+/// outcomes are identical to executing the transactions one after the other.
+///
+/// The coinbase is credited lazily, otherwise every transaction would conflict on its balance.
+pub struct ParallelExecutor {
+    workers: NonZeroUsize,
+}
+
+struct Execution<E> {
+    result: Result<ResultAndState, EVMError<E>>,
+    reads: Vec<(Location, Value)>,
+}
+
+impl ParallelExecutor {
+    pub fn new(workers: NonZeroUsize) -> Self {
+        Self { workers }
+    }
+
+    /// Executes `txs` on top of `db` in the block of `env`. Nothing is committed; the state changes
+    /// of each transaction are returned, in order, to be committed by the caller.
+    pub fn execute<DB>(
+        &self,
+        db: DB,
+        env: &Env,
+        spec: SpecId,
+        txs: &[TxEnv],
+    ) -> Result<Vec<TxOutcome>, EVMError<DB::Error>>
+    where
+        DB: Database + Send,
+        DB::Error: Send,
+    {
+        let coinbase = env.block.coinbase;
+        let base = BaseState::new(db);
+        let mut mv = MvMemory::new(coinbase, txs.len());
+        let mut executions: Vec<Option<Execution<DB::Error>>> = txs.iter().map(|_| None).collect();
+
+        let mut pending: Vec<usize> = (0..txs.len()).collect();
+        let mut finalized = 0;
+        while finalized < txs.len() {
+            // the memory is only read while a round runs
+            for (index, execution) in self.run(&pending, &mv, &base, env, spec, txs) {
+                match &execution.result {
+                    Ok(res) => {
+                        let fee = fee_of(env, spec, &txs[index], res.result.gas_used());
+                        mv.record(index, &res.state, fee);
+                    }
+                    Err(_) => mv.record(index, &EvmState::default(), U256::ZERO),
+                }
+                executions[index] = Some(execution);
+            }
+
+            // the lowest invalid transaction read final values, so each round finalizes at least
+            // one more transaction
+            pending.clear();
+            for index in finalized..txs.len() {
+                let execution = executions[index].as_ref().expect("executed");
+                let mut valid = true;
+                for (location, value) in &execution.reads {
+                    if mv.read(location, index, &base).map_err(EVMError::Database)? != *value {
+                        valid = false;
+                        break;
+                    }
+                }
+                if !valid {
+                    pending.push(index);
+                } else if pending.is_empty() {
+                    finalized = index + 1;
+                }
+            }
+        }
+
+        let mut outcomes = Vec::with_capacity(txs.len());
+        for (index, execution) in executions.into_iter().enumerate() {
+            match execution.expect("executed").result {
+                Ok(mut res) => {
+                    // credit the fees up to and including this transaction
+                    let info = mv
+                        .account(coinbase, index + 1, &base)
+                        .map_err(EVMError::Database)?
+                        .unwrap_or_default();
+                    credit_coinbase(&mut res.state, coinbase, info);
+                    outcomes.push(Ok(res));
+                }
+                Err(EVMError::Transaction(err)) => outcomes.push(Err(err)),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(outcomes)
+    }
+
+    fn run<DB>(
+        &self,
+        pending: &[usize],
+        mv: &MvMemory,
+        base: &BaseState<DB>,
+        env: &Env,
+        spec: SpecId,
+        txs: &[TxEnv],
+    ) -> Vec<(usize, Execution<DB::Error>)>
+    where
+        DB: Database + Send,
+        DB::Error: Send,
+    {
+        let next = AtomicUsize::new(0);
+        let workers = self.workers.get().min(pending.len());
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut executions = Vec::new();
+                        loop {
+                            let Some(&index) = pending.get(next.fetch_add(1, Ordering::Relaxed))
+                            else {
+                                break;
+                            };
+                            executions.push((index, execute_tx(index, mv, base, env, spec, txs)));
+                        }
+                        executions
+                    })
+                })
+                .collect();
+
+            handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+        })
+    }
+}
+
+fn execute_tx<DB: Database>(
+    index: usize,
+    mv: &MvMemory,
+    base: &BaseState<DB>,
+    env: &Env,
+    spec: SpecId,
+    txs: &[TxEnv],
+) -> Execution<DB::Error> {
+    let mut env = env.clone();
+    env.tx = txs[index].clone();
+
+    let view = TxView { index, mv, base, reads: Vec::new() };
+    let mut evm = EvmBuilder::default()
+        .with_db(view)
+        .with_env(Box::new(env))
+        .with_spec_id(spec)
+        .append_handler_register(defer_reward)
+        .build();
+    let result = evm.transact();
+
+    Execution { result, reads: evm.into_context().evm.inner.db.reads }
+}
+
+/// Priority fee paid to the coinbase, see `reward_beneficiary` of revm's mainnet handler.
+fn fee_of(env: &Env, spec: SpecId, tx: &TxEnv, gas_used: u64) -> U256 {
+    let mut env = env.clone();
+    env.tx = tx.clone();
+    let price = env.effective_gas_price();
+    let tip = if SpecId::enabled(spec, SpecId::LONDON) {
+        price.saturating_sub(env.block.basefee)
+    } else {
+        price
+    };
+    tip * U256::from(gas_used)
+}
+
+fn credit_coinbase(state: &mut EvmState, coinbase: Address, info: AccountInfo) {
+    let account = state.entry(coinbase).or_insert_with(|| Account::from(info.clone()));
+    account.info.balance = info.balance;
+    // the reward touches the coinbase even when it is zero
+    account.mark_touch();
+}
+
+/// The fees are credited by [`ParallelExecutor`] instead.
+fn defer_reward<EXT, DB: Database>(handler: &mut EvmHandler<'_, EXT, DB>) {
+    handler.post_execution.reward_beneficiary = Arc::new(no_reward::<EXT, DB>);
+}
+
+fn no_reward<EXT, DB: Database>(
+    _context: &mut Context<EXT, DB>,
+    _gas: &Gas,
+) -> Result<(), EVMError<DB::Error>> {
+    Ok(())
+}
+
+/// The state as seen by one execution of a transaction. Every read is recorded for validation.
+struct TxView<'a, DB> {
+    index: usize,
+    mv: &'a MvMemory,
+    base: &'a BaseState<DB>,
+    reads: Vec<(Location, Value)>,
+}
+
+impl<DB: Database> Database for TxView<'_, DB> {
+    type Error = DB::Error;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        let info = self.mv.account(address, self.index, self.base)?;
+        self.reads.push((Location::Account(address), Value::Account(info.clone())));
+        Ok(info)
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        // codes are content addressed, they cannot be stale
+        self.mv.code_by_hash(code_hash, self.base)
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        let value = self.mv.storage(address, index, self.index, self.base)?;
+        self.reads.push((Location::Storage(address, index), Value::Storage(value)));
+        Ok(value)
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        self.base.block_hash(number)
+    }
+}
@@ -0,0 +1,8 @@
+mod base;
+mod executor;
+mod mv_memory;
+
+#[cfg(test)]
+mod tests;
+
+pub use executor::*;
@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+
+use alloy_primitives::{map::HashMap, Address, B256, U256};
+use revm::{
+    primitives::{AccountInfo, Bytecode, EvmState},
+    Database,
+};
+
+use super::base::BaseState;
+
+/// A piece of state a transaction can read or write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Location {
+    Account(Address),
+    Storage(Address, U256),
+    /// Storage of the account is wiped, by a creation or a self destruct.
+    StorageReset(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// Account info without code
+    Account(Option<AccountInfo>),
+    Storage(U256),
+    StorageReset,
+}
+
+/// Multi-version memory: the writes of every transaction of the block, indexed by the position of
+/// the transaction in the block.
+///
+/// A transaction reads the latest write made by a transaction before it, or the base state when
+/// there is none.
+pub struct MvMemory {
+    coinbase: Address,
+    writes: HashMap<Location, BTreeMap<usize, Value>>,
+    // locations written by each transaction, to drop them on re-execution
+    written: Vec<Vec<Location>>,
+    // codes deployed by the block, content addressed so never stale
+    codes: HashMap<B256, Bytecode>,
+    // priority fees of each transaction, credited to the coinbase lazily
+    fees: Vec<U256>,
+}
+
+impl MvMemory {
+    pub fn new(coinbase: Address, len: usize) -> Self {
+        Self {
+            coinbase,
+            writes: HashMap::default(),
+            written: vec![Vec::new(); len],
+            codes: HashMap::default(),
+            fees: vec![U256::ZERO; len],
+        }
+    }
+
+    /// Replaces the writes of transaction `index` with the ones of its latest execution.
+    pub fn record(&mut self, index: usize, state: &EvmState, fee: U256) {
+        for location in std::mem::take(&mut self.written[index]) {
+            if let Some(versions) = self.writes.get_mut(&location) {
+                versions.remove(&index);
+            }
+        }
+
+        for (address, account) in state {
+            if !account.is_touched() {
+                continue;
+            }
+            let address = *address;
+            if account.is_selfdestructed() {
+                self.write(index, Location::Account(address), Value::Account(None));
+                self.write(index, Location::StorageReset(address), Value::StorageReset);
+                continue;
+            }
+
+            let mut info = account.info.clone();
+            if let Some(code) = info.code.take() {
+                if !info.is_empty_code_hash() {
+                    self.codes.insert(info.code_hash, code);
+                }
+            }
+            self.write(index, Location::Account(address), Value::Account(Some(info)));
+            if account.is_created() {
+                self.write(index, Location::StorageReset(address), Value::StorageReset);
+            }
+            for (slot, value) in account.storage.iter().filter(|(_, value)| value.is_changed()) {
+                self.write(
+                    index,
+                    Location::Storage(address, *slot),
+                    Value::Storage(value.present_value),
+                );
+            }
+        }
+
+        self.fees[index] = fee;
+    }
+
+    /// The value of `location` as seen by transaction `index`.
+    pub fn read<DB: Database>(
+        &self,
+        location: &Location,
+        index: usize,
+        base: &BaseState<DB>,
+    ) -> Result<Value, DB::Error> {
+        match *location {
+            Location::Account(address) => self.account(address, index, base).map(Value::Account),
+            Location::Storage(address, slot) => {
+                self.storage(address, slot, index, base).map(Value::Storage)
+            }
+            Location::StorageReset(_) => Ok(Value::StorageReset),
+        }
+    }
+
+    pub fn account<DB: Database>(
+        &self,
+        address: Address,
+        index: usize,
+        base: &BaseState<DB>,
+    ) -> Result<Option<AccountInfo>, DB::Error> {
+        let (from, info) = match self.latest(&Location::Account(address), index) {
+            Some((writer, Value::Account(info))) => (writer, info.clone()),
+            _ => (0, base.basic(address)?),
+        };
+        if address != self.coinbase {
+            return Ok(info);
+        }
+
+        // a write of the coinbase excludes the fee of its own transaction
+        let fees = self.fees[from..index].iter().fold(U256::ZERO, |acc, fee| acc + fee);
+        if fees.is_zero() {
+            return Ok(info);
+        }
+        let mut info = info.unwrap_or_default();
+        info.balance += fees;
+        Ok(Some(info))
+    }
+
+    pub fn storage<DB: Database>(
+        &self,
+        address: Address,
+        slot: U256,
+        index: usize,
+        base: &BaseState<DB>,
+    ) -> Result<U256, DB::Error> {
+        let written = self.latest(&Location::Storage(address, slot), index);
+        let reset = self.latest(&Location::StorageReset(address), index).map(|(writer, _)| writer);
+        match (written, reset) {
+            // a transaction resetting the storage may write it again afterwards
+            (Some((writer, Value::Storage(value))), reset)
+                if reset.map_or(true, |r| writer >= r) =>
+            {
+                Ok(*value)
+            }
+            (_, Some(_)) => Ok(U256::ZERO),
+            _ => base.storage(address, slot),
+        }
+    }
+
+    pub fn code_by_hash<DB: Database>(
+        &self,
+        code_hash: B256,
+        base: &BaseState<DB>,
+    ) -> Result<Bytecode, DB::Error> {
+        match self.codes.get(&code_hash) {
+            Some(code) => Ok(code.clone()),
+            None => base.code_by_hash(code_hash),
+        }
+    }
+
+    fn write(&mut self, index: usize, location: Location, value: Value) {
+        self.writes.entry(location).or_default().insert(index, value);
+        self.written[index].push(location);
+    }
+
+    fn latest(&self, location: &Location, index: usize) -> Option<(usize, &Value)> {
+        self.writes
+            .get(location)?
+            .range(..index)
+            .next_back()
+            .map(|(writer, value)| (*writer, value))
+    }
+}
@@ -0,0 +1,201 @@
+use std::{collections::BTreeMap, num::NonZeroUsize};
+
+use alloy_primitives::{address, Address, Bytes, B256, U256};
+use revm::{
+    db::{AccountState, InMemoryDB},
+    primitives::{
+        AccountInfo, BlockEnv, Bytecode, EVMError, Env, ExecutionResult, InvalidTransaction,
+        SpecId, TxEnv, TxKind,
+    },
+    DatabaseCommit, Evm,
+};
+
+use super::ParallelExecutor;
+
+const COINBASE: Address = address!("c000000000000000000000000000000000000000");
+const COUNTER: Address = address!("a000000000000000000000000000000000000001");
+const COINBASE_READER: Address = address!("a000000000000000000000000000000000000002");
+const SENDERS: [Address; 4] = [
+    address!("1000000000000000000000000000000000000001"),
+    address!("1000000000000000000000000000000000000002"),
+    address!("1000000000000000000000000000000000000003"),
+    address!("1000000000000000000000000000000000000004"),
+];
+
+// PUSH0 SLOAD PUSH1 0x01 ADD PUSH0 SSTORE STOP
+const COUNTER_CODE: [u8; 8] = [0x5f, 0x54, 0x60, 0x01, 0x01, 0x5f, 0x55, 0x00];
+// COINBASE BALANCE PUSH0 SSTORE STOP
+const COINBASE_READER_CODE: [u8; 5] = [0x41, 0x31, 0x5f, 0x55, 0x00];
+// PUSH1 0x2a PUSH0 SSTORE STOP
+const INIT_CODE: [u8; 5] = [0x60, 0x2a, 0x5f, 0x55, 0x00];
+
+type Outcome = Result<ExecutionResult, InvalidTransaction>;
+/// address => (balance, nonce, code hash, non zero storage)
+type NormalizedState = BTreeMap<Address, (U256, u64, B256, BTreeMap<U256, U256>)>;
+
+fn genesis() -> InMemoryDB {
+    let mut db = InMemoryDB::default();
+    let balance = U256::from(10).pow(U256::from(18));
+    for sender in SENDERS.into_iter().chain([COINBASE]) {
+        db.insert_account_info(sender, AccountInfo { balance, ..Default::default() });
+    }
+    for (address, code) in [(COUNTER, &COUNTER_CODE[..]), (COINBASE_READER, &COINBASE_READER_CODE)]
+    {
+        let code = Bytecode::new_raw(Bytes::copy_from_slice(code));
+        db.insert_account_info(
+            address,
+            AccountInfo { code_hash: code.hash_slow(), code: Some(code), ..Default::default() },
+        );
+    }
+    db
+}
+
+fn env() -> Env {
+    Env {
+        block: BlockEnv { coinbase: COINBASE, basefee: U256::ZERO, ..Default::default() },
+        ..Default::default()
+    }
+}
+
+/// Builds the transactions of `(sender, to, value, data)`, numbering the nonces per sender.
+fn txs(calls: &[(Address, TxKind, u64, &[u8])]) -> Vec<TxEnv> {
+    let mut nonces = BTreeMap::<Address, u64>::new();
+    calls
+        .iter()
+        .map(|&(caller, transact_to, value, data)| {
+            let nonce = nonces.entry(caller).or_default();
+            *nonce += 1;
+            TxEnv {
+                caller,
+                transact_to,
+                value: U256::from(value),
+                data: Bytes::copy_from_slice(data),
+                gas_limit: 100_000,
+                gas_price: U256::from(1),
+                nonce: Some(*nonce - 1),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn execute_sequential(txs: &[TxEnv]) -> (Vec<Outcome>, NormalizedState) {
+    let mut evm = Evm::builder()
+        .with_db(genesis())
+        .with_env(Box::new(env()))
+        .with_spec_id(SpecId::CANCUN)
+        .build();
+
+    let mut outcomes = Vec::new();
+    for tx in txs {
+        *evm.tx_mut() = tx.clone();
+        match evm.transact_commit() {
+            Ok(res) => outcomes.push(Ok(res)),
+            Err(EVMError::Transaction(err)) => outcomes.push(Err(err)),
+            Err(err) => panic!("{err:?}"),
+        }
+    }
+    let state = normalize(evm.db_mut());
+    (outcomes, state)
+}
+
+fn execute_parallel(txs: &[TxEnv], workers: usize) -> (Vec<Outcome>, NormalizedState) {
+    let mut db = genesis();
+    let executor = ParallelExecutor::new(NonZeroUsize::new(workers).unwrap());
+    let outcomes = executor.execute(db.clone(), &env(), SpecId::CANCUN, txs).unwrap();
+
+    let outcomes = outcomes
+        .into_iter()
+        .map(|outcome| {
+            outcome.map(|res| {
+                db.commit(res.state);
+                res.result
+            })
+        })
+        .collect();
+    (outcomes, normalize(&db))
+}
+
+fn normalize(db: &InMemoryDB) -> NormalizedState {
+    db.accounts
+        .iter()
+        .filter(|(_, account)| account.account_state != AccountState::NotExisting)
+        .map(|(address, account)| {
+            let storage = account
+                .storage
+                .iter()
+                .filter(|(_, value)| !value.is_zero())
+                .map(|(slot, value)| (*slot, *value))
+                .collect();
+            (*address, (account.info.balance, account.info.nonce, account.info.code_hash, storage))
+        })
+        .collect()
+}
+
+fn assert_deterministic(txs: &[TxEnv]) {
+    let expected = execute_sequential(txs);
+    for workers in [1, 2, 4, 8] {
+        // repeated to shake out scheduling dependent results
+        for _ in 0..5 {
+            assert_eq!(execute_parallel(txs, workers), expected, "{workers} workers");
+        }
+    }
+}
+
+#[test]
+fn executes_empty_block() {
+    assert_deterministic(&[]);
+}
+
+#[test]
+fn matches_sequential_execution_of_independent_txs() {
+    let txs = txs(&[
+        (SENDERS[0], TxKind::Call(address!("b000000000000000000000000000000000000001")), 1, &[]),
+        (SENDERS[1], TxKind::Call(address!("b000000000000000000000000000000000000002")), 2, &[]),
+        (SENDERS[2], TxKind::Call(address!("b000000000000000000000000000000000000003")), 3, &[]),
+        (SENDERS[3], TxKind::Call(address!("b000000000000000000000000000000000000004")), 4, &[]),
+    ]);
+    assert_deterministic(&txs);
+}
+
+#[test]
+fn matches_sequential_execution_of_conflicting_txs() {
+    let calls: Vec<_> =
+        (0..16).map(|i| (SENDERS[i % SENDERS.len()], TxKind::Call(COUNTER), 0, &[][..])).collect();
+    assert_deterministic(&txs(&calls));
+}
+
+#[test]
+fn matches_sequential_execution_of_mixed_block() {
+    let txs = txs(&[
+        // transfers along a cycle of senders
+        (SENDERS[0], TxKind::Call(SENDERS[1]), 100, &[]),
+        (SENDERS[1], TxKind::Call(SENDERS[2]), 200, &[]),
+        (SENDERS[2], TxKind::Call(SENDERS[0]), 300, &[]),
+        (SENDERS[3], TxKind::Call(COUNTER), 0, &[]),
+        // reads the fees credited so far
+        (SENDERS[0], TxKind::Call(COINBASE_READER), 0, &[]),
+        (SENDERS[1], TxKind::Create, 0, &INIT_CODE),
+        // the coinbase spends and receives
+        (COINBASE, TxKind::Call(SENDERS[3]), 1_000, &[]),
+        (SENDERS[2], TxKind::Call(COINBASE), 500, &[]),
+        (SENDERS[3], TxKind::Call(COUNTER), 0, &[]),
+        (SENDERS[1], TxKind::Call(COINBASE_READER), 0, &[]),
+        (COINBASE, TxKind::Call(COUNTER), 0, &[]),
+    ]);
+    assert_deterministic(&txs);
+}
+
+#[test]
+fn reports_invalid_txs_in_place() {
+    let mut txs = txs(&[
+        (SENDERS[0], TxKind::Call(COUNTER), 0, &[]),
+        (SENDERS[1], TxKind::Call(COUNTER), 0, &[]),
+        (SENDERS[0], TxKind::Call(COUNTER), 0, &[]),
+    ]);
+    txs[1].nonce = Some(7);
+
+    let (outcomes, _) = execute_parallel(&txs, 4);
+    assert!(matches!(outcomes[1], Err(InvalidTransaction::NonceTooHigh { tx: 7, state: 0 })));
+    assert_deterministic(&txs);
+}
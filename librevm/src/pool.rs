@@ -0,0 +1,248 @@
+use std::{
+    collections::BTreeMap,
+    ops::{Deref, DerefMut},
+    sync::{Arc, Condvar, Mutex},
+    thread,
+};
+
+use revm::primitives::{EVMError, SpecId};
+
+use crate::{
+    chain::ChainRules,
+    compiler::EXTCompileWorker,
+    error::BackendError,
+    precompiles::PrecompileRegistry,
+    vm::{compiled_vm, interpreted_vm, next_handle, AnyVm},
+};
+
+/// Opaque handle of a VM pool created through the FFI. Like VM handles, pool handles are never
+/// reused and zero is never a handle.
+#[allow(non_camel_case_types)]
+pub type pool_t = u64;
+
+/// VMs of one spec, lent to one call each, so that calls on the pool run concurrently.
+///
+/// With a compile worker, the VMs share its compile threads and compiled code: a contract is
+/// compiled once for the pool and runs compiled on every VM.
+pub struct VmPool {
+    spec_id: SpecId,
+    worker: Option<EXTCompileWorker>,
+    /// Custom precompiles of every VM of the pool.
+    precompiles: PrecompileRegistry,
+    /// Chain config of every VM of the pool, applied to the config of a VM when it is lent.
+    chain: ChainRules,
+    idle: Mutex<Vec<AnyVm>>,
+    // signaled when a VM is given back
+    returned: Condvar,
+}
+
+// SAFETY: the VMs are only reached by the call they are lent to, and nothing they hold is bound
+// to the thread which created them.
+unsafe impl Send for VmPool {}
+unsafe impl Sync for VmPool {}
+
+impl VmPool {
+    /// Creates `size` VMs of `spec_id` on the chain of `chain`, running the contracts compiled by
+    /// `worker` if any.
+    pub fn new(
+        spec_id: SpecId,
+        size: usize,
+        worker: Option<EXTCompileWorker>,
+        chain: ChainRules,
+    ) -> Self {
+        let pool = VmPool {
+            spec_id,
+            worker,
+            precompiles: PrecompileRegistry::default(),
+            chain,
+            idle: Mutex::default(),
+            returned: Condvar::new(),
+        };
+        let vms = (0..size).map(|_| pool.new_vm()).collect();
+        *pool.idle.lock().unwrap() = vms;
+        pool
+    }
+
+    /// Lends a VM, waiting for one to be given back when they are all in use.
+    pub fn take(&self) -> PooledVm<'_> {
+        let mut idle = self.idle.lock().unwrap_or_else(|err| err.into_inner());
+        loop {
+            if let Some(mut vm) = idle.pop() {
+                // the config may have been replaced since the VM was last lent
+                match &mut vm {
+                    AnyVm::Interpreted(vm) => self.chain.apply(vm.evm.cfg_mut()),
+                    AnyVm::Compiled(vm) => self.chain.apply(vm.evm.cfg_mut()),
+                }
+                return PooledVm { pool: self, vm: Some(vm) };
+            }
+            idle = self.returned.wait(idle).unwrap_or_else(|err| err.into_inner());
+        }
+    }
+
+    /// The custom precompiles of the VMs of the pool, including the ones lent at the moment.
+    pub fn precompiles(&self) -> &PrecompileRegistry {
+        &self.precompiles
+    }
+
+    /// The chain config of the VMs of the pool. A replaced config applies to a VM from the next
+    /// time it is lent.
+    pub fn chain(&self) -> &ChainRules {
+        &self.chain
+    }
+
+    fn new_vm(&self) -> AnyVm {
+        let (precompiles, chain) = (self.precompiles.clone(), self.chain.clone());
+        match &self.worker {
+            Some(worker) => {
+                AnyVm::Compiled(compiled_vm(self.spec_id, worker.clone(), precompiles, chain))
+            }
+            None => AnyVm::Interpreted(interpreted_vm(self.spec_id, precompiles, chain)),
+        }
+    }
+
+    fn give_back(&self, vm: AnyVm) {
+        self.idle.lock().unwrap_or_else(|err| err.into_inner()).push(vm);
+        self.returned.notify_one();
+    }
+}
+
+/// A VM lent by a [`VmPool`], given back when dropped.
+pub struct PooledVm<'a> {
+    pool: &'a VmPool,
+    vm: Option<AnyVm>,
+}
+
+impl Deref for PooledVm<'_> {
+    type Target = AnyVm;
+
+    fn deref(&self) -> &AnyVm {
+        self.vm.as_ref().expect("the VM is only taken on drop")
+    }
+}
+
+impl DerefMut for PooledVm<'_> {
+    fn deref_mut(&mut self) -> &mut AnyVm {
+        self.vm.as_mut().expect("the VM is only taken on drop")
+    }
+}
+
+impl Drop for PooledVm<'_> {
+    fn drop(&mut self) {
+        let Some(vm) = self.vm.take() else { return };
+        // a VM which panicked may be left inconsistent, a new one takes its place
+        if thread::panicking() {
+            drop(vm);
+            self.pool.give_back(self.pool.new_vm());
+        } else {
+            self.pool.give_back(vm);
+        }
+    }
+}
+
+static POOLS: Mutex<BTreeMap<pool_t, Arc<VmPool>>> = Mutex::new(BTreeMap::new());
+
+pub fn register(pool: VmPool) -> pool_t {
+    let handle = next_handle();
+    POOLS.lock().unwrap_or_else(|err| err.into_inner()).insert(handle, Arc::new(pool));
+    handle
+}
+
+/// Removes the pool of `handle`. It is dropped once the calls running on it return.
+pub fn unregister(handle: pool_t) -> Result<(), EVMError<BackendError>> {
+    let pool = POOLS.lock().unwrap_or_else(|err| err.into_inner()).remove(&handle);
+    match pool {
+        // dropped outside of the registry lock, it may join the compile threads
+        Some(pool) => {
+            drop(pool);
+            Ok(())
+        }
+        None => Err(unknown_handle(handle)),
+    }
+}
+
+pub fn get(handle: pool_t) -> Result<Arc<VmPool>, EVMError<BackendError>> {
+    let pools = POOLS.lock().unwrap_or_else(|err| err.into_inner());
+    pools.get(&handle).cloned().ok_or_else(|| unknown_handle(handle))
+}
+
+fn unknown_handle(handle: pool_t) -> EVMError<BackendError> {
+    EVMError::Custom(format!(
+        "Unknown VM pool handle {handle}, the pool was freed or never created"
+    ))
+}
+
+/// Evaluates `$body` with `$vm` bound to a [`Vm`](crate::vm::Vm) lent by the pool of the handle.
+/// Errors are written to `$errmsg` and evaluate to the default value.
+macro_rules! with_pooled_vm {
+    ($handle:expr, $errmsg:ident, | $vm:ident | $body:expr) => {
+        match $crate::pool::get($handle) {
+            Ok(pool) => {
+                let mut vm = pool.take();
+                match &mut *vm {
+                    $crate::vm::AnyVm::Interpreted($vm) => $body,
+                    $crate::vm::AnyVm::Compiled($vm) => $body,
+                }
+            }
+            Err(err) => {
+                $crate::error::set_error(err, $errmsg);
+                Default::default()
+            }
+        }
+    };
+}
+
+pub(crate) use with_pooled_vm;
+
+#[cfg(test)]
+mod test {
+    use std::sync::mpsc;
+
+    use super::*;
+    use crate::v1::types::ChainConfig;
+
+    #[test]
+    fn lends_each_vm_to_one_call() {
+        let pool = Arc::new(VmPool::new(SpecId::CANCUN, 2, None, ChainRules::default()));
+        let first = pool.take();
+        let second = pool.take();
+
+        // the third call waits for a VM to be given back
+        let (sender, receiver) = mpsc::channel();
+        let waiting = {
+            let pool = pool.clone();
+            thread::spawn(move || {
+                let _vm = pool.take();
+                sender.send(()).unwrap();
+            })
+        };
+        assert!(receiver.recv_timeout(std::time::Duration::from_millis(50)).is_err());
+        drop(first);
+        receiver.recv().unwrap();
+        waiting.join().unwrap();
+
+        drop(second);
+        assert_eq!(pool.idle.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn applies_the_chain_config_to_lent_vms() {
+        let pool = VmPool::new(SpecId::CANCUN, 1, None, ChainRules::default());
+        pool.chain().replace(ChainConfig { chain_id: 10, ..Default::default() });
+        let mut vm = pool.take();
+        match &mut *vm {
+            AnyVm::Interpreted(vm) => assert_eq!(vm.evm.cfg_mut().chain_id, 10),
+            AnyVm::Compiled(_) => unreachable!("the pool has no compile worker"),
+        }
+    }
+
+    #[test]
+    fn replaces_vms_which_panicked() {
+        let pool = VmPool::new(SpecId::CANCUN, 1, None, ChainRules::default());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _vm = pool.take();
+            panic!("call failed");
+        }));
+        assert!(result.is_err());
+        assert_eq!(pool.idle.lock().unwrap().len(), 1);
+    }
+}
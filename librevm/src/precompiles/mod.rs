@@ -0,0 +1,284 @@
+mod plugin;
+mod vtable;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use alloy_primitives::{Address, Bytes, U256};
+use revm::{
+    handler::register::{EvmHandler, HandleRegisterBox},
+    interpreter::{
+        CallInputs, CallOutcome, CallValue, Gas, InstructionResult, InterpreterResult,
+        CALL_STACK_LIMIT,
+    },
+    primitives::EVMError,
+    Context, Database, FrameOrResult, FrameResult, InnerEvmContext,
+};
+
+use crate::{
+    error::BackendError,
+    v1::types::{PrecompileCall, PrecompileOutput},
+};
+
+pub use plugin::*;
+pub use vtable::*;
+
+/// A precompile implemented outside of revm. Unlike revm's precompiles, it is called with the
+/// frame of the call, and its state changes are applied to the journal of the transaction.
+///
+/// The call carries no view of the state: a precompile reading its own state db sees what was
+/// committed to it, without the changes the transaction made so far, which are only in the
+/// journal.
+pub trait CustomPrecompile: Send + Sync {
+    /// Runs the call. An error aborts the transaction. A `failed` output halts the call with
+    /// `PrecompileError` and consumes all of its gas, a `reverted` one reverts it with the output
+    /// as revert data; the state changes of either are dropped.
+    fn call(&self, call: &PrecompileCall) -> Result<PrecompileOutput, BackendError>;
+}
+
+/// The custom precompiles of a VM, by address. The registry is shared with the EVM handler, the
+/// precompiles registered after the VM is built are called from the next transaction on.
+#[derive(Clone, Default)]
+pub struct PrecompileRegistry(Arc<RwLock<HashMap<Address, Arc<dyn CustomPrecompile>>>>);
+
+impl PrecompileRegistry {
+    /// Calls `precompile` for `addresses`, in place of any precompile registered for them before.
+    pub fn insert(&self, addresses: &[Address], precompile: Arc<dyn CustomPrecompile>) {
+        let mut precompiles = self.0.write().unwrap_or_else(|err| err.into_inner());
+        for address in addresses {
+            precompiles.insert(*address, precompile.clone());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.read().unwrap_or_else(|err| err.into_inner()).is_empty()
+    }
+
+    fn get(&self, address: &Address) -> Option<Arc<dyn CustomPrecompile>> {
+        self.0.read().unwrap_or_else(|err| err.into_inner()).get(address).cloned()
+    }
+
+    pub fn addresses(&self) -> Vec<Address> {
+        self.0.read().unwrap_or_else(|err| err.into_inner()).keys().copied().collect()
+    }
+
+    /// Calls the precompiles of the registry in place of the frames of their addresses, which are
+    /// warm from the start of the transactions like the standard precompiles.
+    pub fn handler_register<'a, EXT: 'a, DB: Database + 'a>(
+        &self,
+    ) -> HandleRegisterBox<'a, EXT, DB> {
+        let registry = self.clone();
+        Box::new(move |handler: &mut EvmHandler<'a, EXT, DB>| {
+            let precompiles = registry.clone();
+            let prev = handler.execution.call.clone();
+            handler.execution.call =
+                Arc::new(move |context, inputs| match precompiles.get(&inputs.bytecode_address) {
+                    Some(precompile) => call(precompile.as_ref(), context, inputs),
+                    None => prev(context, inputs),
+                });
+
+            let precompiles = registry.clone();
+            let prev = handler.pre_execution.load_accounts.clone();
+            handler.pre_execution.load_accounts = Arc::new(move |context| {
+                prev(context)?;
+                let warm = &mut context.evm.journaled_state.warm_preloaded_addresses;
+                warm.extend(precompiles.addresses());
+                Ok(())
+            });
+        })
+    }
+}
+
+/// Runs the frame of a call to a custom precompile, like revm runs the frames of its precompiles:
+/// the value is transferred first, and the changes are reverted unless the call returns.
+fn call<EXT, DB: Database>(
+    precompile: &dyn CustomPrecompile,
+    context: &mut Context<EXT, DB>,
+    inputs: Box<CallInputs>,
+) -> Result<FrameOrResult, EVMError<DB::Error>> {
+    let outcome = |result: InstructionResult, output: Bytes, gas: Gas| {
+        let result = InterpreterResult { result, output, gas };
+        Ok(FrameOrResult::Result(FrameResult::Call(CallOutcome::new(
+            result,
+            inputs.return_memory_offset.clone(),
+        ))))
+    };
+    let gas = Gas::new(inputs.gas_limit);
+    if context.evm.journaled_state.depth() > CALL_STACK_LIMIT {
+        return outcome(InstructionResult::CallTooDeep, Bytes::new(), gas);
+    }
+
+    let evm = &mut context.evm.inner;
+    let checkpoint = evm.journaled_state.checkpoint();
+    match inputs.value {
+        CallValue::Transfer(value) if value.is_zero() => {
+            evm.journaled_state.load_account(inputs.target_address, &mut evm.db)?;
+            evm.journaled_state.touch(&inputs.target_address);
+        }
+        CallValue::Transfer(value) => {
+            let transfer = evm.journaled_state.transfer(
+                &inputs.caller,
+                &inputs.target_address,
+                value,
+                &mut evm.db,
+            )?;
+            if let Some(err) = transfer {
+                evm.journaled_state.checkpoint_revert(checkpoint);
+                return outcome(err.into(), Bytes::new(), gas);
+            }
+        }
+        CallValue::Apparent(_) => {}
+    }
+
+    let call = PrecompileCall {
+        address: inputs.bytecode_address.to_vec(),
+        caller: inputs.caller.to_vec(),
+        value: inputs.call_value().to_be_bytes_vec(),
+        input: inputs.input.to_vec(),
+        gas_limit: inputs.gas_limit,
+        is_static: inputs.is_static,
+    };
+    let output = match precompile.call(&call) {
+        Ok(output) => output,
+        Err(err) => return Err(EVMError::Precompile(err.to_string())),
+    };
+
+    let mut gas = gas;
+    if !gas.record_cost(output.gas_used) {
+        evm.journaled_state.checkpoint_revert(checkpoint);
+        return outcome(
+            InstructionResult::PrecompileOOG,
+            Bytes::new(),
+            Gas::new_spent(inputs.gas_limit),
+        );
+    }
+    if output.failed {
+        evm.journaled_state.checkpoint_revert(checkpoint);
+        let gas = Gas::new_spent(inputs.gas_limit);
+        return outcome(InstructionResult::PrecompileError, Bytes::new(), gas);
+    }
+    if output.reverted {
+        evm.journaled_state.checkpoint_revert(checkpoint);
+        return outcome(InstructionResult::Revert, output.output.into(), gas);
+    }
+    if inputs.is_static && (!output.transfers.is_empty() || !output.storage.is_empty()) {
+        evm.journaled_state.checkpoint_revert(checkpoint);
+        return outcome(InstructionResult::StateChangeDuringStaticCall, Bytes::new(), gas);
+    }
+    if let Some(result) = apply(evm, &output)? {
+        evm.journaled_state.checkpoint_revert(checkpoint);
+        return outcome(result, Bytes::new(), gas);
+    }
+    evm.journaled_state.checkpoint_commit();
+    outcome(InstructionResult::Return, output.output.into(), gas)
+}
+
+/// Applies the state changes of a call to the journal. Returns the result failing the call when a
+/// change cannot be applied, e.g. a transfer without funds.
+fn apply<DB: Database>(
+    evm: &mut InnerEvmContext<DB>,
+    output: &PrecompileOutput,
+) -> Result<Option<InstructionResult>, EVMError<DB::Error>> {
+    for transfer in &output.transfers {
+        let (Some(from), Some(to), Some(value)) =
+            (address(&transfer.from), address(&transfer.to), word(&transfer.value))
+        else {
+            return Ok(Some(InstructionResult::PrecompileError));
+        };
+        if let Some(err) = evm.journaled_state.transfer(&from, &to, value, &mut evm.db)? {
+            return Ok(Some(err.into()));
+        }
+    }
+    for write in &output.storage {
+        let (Some(address), Some(key), Some(value)) =
+            (address(&write.address), word(&write.key), word(&write.value))
+        else {
+            return Ok(Some(InstructionResult::PrecompileError));
+        };
+        evm.journaled_state.load_account(address, &mut evm.db)?;
+        evm.journaled_state.sstore(address, key, value, &mut evm.db)?;
+    }
+    Ok(None)
+}
+
+fn address(bytes: &[u8]) -> Option<Address> {
+    (bytes.len() == Address::len_bytes()).then(|| Address::from_slice(bytes))
+}
+
+fn word(bytes: &[u8]) -> Option<U256> {
+    U256::try_from_be_slice(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::{address, bytes};
+    use revm::{
+        db::InMemoryDB,
+        primitives::{AccountInfo, ExecutionResult, TxKind},
+        Evm,
+    };
+
+    use super::*;
+    use crate::v1::types::StorageWrite;
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+    const PRECOMPILE: Address = address!("0000000000000000000000000000000000000900");
+
+    /// Returns its input and stores it, reverts without input.
+    struct Echo;
+
+    impl CustomPrecompile for Echo {
+        fn call(&self, call: &PrecompileCall) -> Result<PrecompileOutput, BackendError> {
+            Ok(PrecompileOutput {
+                output: call.input.clone(),
+                gas_used: 100,
+                reverted: call.input.is_empty(),
+                storage: vec![StorageWrite {
+                    address: call.address.clone(),
+                    key: vec![1],
+                    value: call.input.clone(),
+                }],
+                ..Default::default()
+            })
+        }
+    }
+
+    #[test]
+    fn journals_the_changes_of_the_calls() {
+        let registry = PrecompileRegistry::default();
+        registry.insert(&[PRECOMPILE], Arc::new(Echo));
+        let mut db = InMemoryDB::default();
+        let balance = U256::from(1_000_000);
+        db.insert_account_info(SENDER, AccountInfo { balance, ..Default::default() });
+
+        let mut evm = Evm::builder()
+            .with_db(db)
+            .modify_tx_env(|tx| {
+                tx.caller = SENDER;
+                tx.transact_to = TxKind::Call(PRECOMPILE);
+                tx.data = bytes!("2a");
+                tx.gas_limit = 100_000;
+            })
+            .append_handler_register_box(registry.handler_register())
+            .build();
+        let res = evm.transact().unwrap();
+        match res.result {
+            ExecutionResult::Success { gas_used, output, .. } => {
+                // the intrinsic gas, one byte of calldata and the gas of the call
+                assert_eq!(gas_used, 21_000 + 16 + 100);
+                assert_eq!(output.into_data(), bytes!("2a"));
+            }
+            result => panic!("unexpected result {result:?}"),
+        }
+        let slot = &res.state[&PRECOMPILE].storage[&U256::from(1)];
+        assert_eq!(slot.present_value, U256::from(0x2a));
+
+        evm.tx_mut().data = Bytes::new();
+        let res = evm.transact().unwrap();
+        assert!(matches!(res.result, ExecutionResult::Revert { .. }));
+        let storage = res.state.get(&PRECOMPILE).map(|account| account.storage.len());
+        assert_eq!(storage.unwrap_or_default(), 0);
+    }
+}
@@ -0,0 +1,184 @@
+use std::{path::Path, ptr, slice, sync::Arc};
+
+use alloy_primitives::Address;
+use libloading::Library;
+use revm::primitives::EVMError;
+
+use crate::{
+    error::BackendError,
+    v1::types::{PrecompileCall, PrecompileOutput},
+};
+
+use super::CustomPrecompile;
+
+/// Version of the plugin ABI, the `abi_version` of the plugins loaded by this build.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Symbol of the entry point of a plugin, an `extern "C" fn() -> *const PrecompilePlugin`. The
+/// returned plugin must stay valid while the library is loaded.
+pub const PLUGIN_ENTRY_POINT: &[u8] = b"revm_precompile_plugin";
+
+/// Output of a plugin precompile, allocated by the plugin and given back to its `free`.
+#[repr(C)]
+pub struct PluginBuffer {
+    pub ptr: *mut u8,
+    pub len: usize,
+}
+
+/// A precompile of a plugin, in version 1 of the ABI.
+#[repr(C)]
+pub struct PluginPrecompile {
+    pub address: [u8; 20],
+    /// Gas of a call with the input.
+    pub gas: extern "C" fn(input: *const u8, input_len: usize) -> u64,
+    /// Runs a call, writing its output to `output`. Returns zero on success, otherwise the call
+    /// fails and consumes its gas, like a standard precompile failing.
+    pub run: extern "C" fn(input: *const u8, input_len: usize, output: *mut PluginBuffer) -> i32,
+}
+
+/// The precompiles of a plugin. Only `abi_version` is common to every version of the ABI, the
+/// other fields are the ones of version 1.
+#[repr(C)]
+pub struct PrecompilePlugin {
+    pub abi_version: u32,
+    pub precompiles: *const PluginPrecompile,
+    pub precompiles_len: usize,
+    /// Frees the outputs of the precompiles.
+    pub free: extern "C" fn(PluginBuffer),
+}
+
+/// A precompile of a loaded plugin, keeping the library loaded.
+struct Loaded {
+    gas: extern "C" fn(*const u8, usize) -> u64,
+    run: extern "C" fn(*const u8, usize, *mut PluginBuffer) -> i32,
+    free: extern "C" fn(PluginBuffer),
+    _library: Arc<Library>,
+}
+
+impl CustomPrecompile for Loaded {
+    fn call(&self, call: &PrecompileCall) -> Result<PrecompileOutput, BackendError> {
+        let input = &call.input;
+        let gas_used = (self.gas)(input.as_ptr(), input.len());
+        if gas_used > call.gas_limit {
+            return Ok(PrecompileOutput { gas_used, ..Default::default() });
+        }
+
+        let mut buffer = PluginBuffer { ptr: ptr::null_mut(), len: 0 };
+        let status = (self.run)(input.as_ptr(), input.len(), &mut buffer);
+        let mut output = Vec::new();
+        if !buffer.ptr.is_null() {
+            output.extend_from_slice(unsafe { slice::from_raw_parts(buffer.ptr, buffer.len) });
+            (self.free)(buffer);
+        }
+        Ok(PrecompileOutput { output, gas_used, failed: status != 0, ..Default::default() })
+    }
+}
+
+/// Loads the plugin at `path` and returns its precompiles with their addresses.
+///
+/// Loading a library runs its initializers: plugins are trusted like this crate.
+pub fn load_plugin(
+    path: &Path,
+) -> Result<Vec<(Address, Arc<dyn CustomPrecompile>)>, EVMError<BackendError>> {
+    let plugin_error = |msg: String| EVMError::Custom(format!("Plugin {}: {msg}", path.display()));
+
+    let library = unsafe { Library::new(path) }.map_err(|err| plugin_error(err.to_string()))?;
+    let plugin = unsafe {
+        let entry_point = library
+            .get::<extern "C" fn() -> *const PrecompilePlugin>(PLUGIN_ENTRY_POINT)
+            .map_err(|err| plugin_error(err.to_string()))?;
+        entry_point().as_ref()
+    };
+    let Some(plugin) = plugin else {
+        return Err(plugin_error("no precompiles returned".to_owned()));
+    };
+    if plugin.abi_version != PLUGIN_ABI_VERSION {
+        return Err(plugin_error(format!(
+            "ABI version {} is not supported, expected {PLUGIN_ABI_VERSION}",
+            plugin.abi_version
+        )));
+    }
+    let precompiles: &[PluginPrecompile] = if plugin.precompiles.is_null() {
+        &[]
+    } else {
+        unsafe { slice::from_raw_parts(plugin.precompiles, plugin.precompiles_len) }
+    };
+
+    let free = plugin.free;
+    let library = Arc::new(library);
+    Ok(precompiles
+        .iter()
+        .map(|precompile| {
+            let loaded = Loaded {
+                gas: precompile.gas,
+                run: precompile.run,
+                free,
+                _library: library.clone(),
+            };
+            (Address::from(precompile.address), Arc::new(loaded) as Arc<dyn CustomPrecompile>)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static FREED: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn gas(_input: *const u8, input_len: usize) -> u64 {
+        100 + input_len as u64
+    }
+
+    /// Returns its input, fails without input.
+    extern "C" fn run(input: *const u8, input_len: usize, output: *mut PluginBuffer) -> i32 {
+        if input_len == 0 {
+            return 1;
+        }
+        let data = unsafe { slice::from_raw_parts(input, input_len) }.to_vec().into_boxed_slice();
+        let len = data.len();
+        let ptr = Box::into_raw(data) as *mut u8;
+        unsafe { *output = PluginBuffer { ptr, len } };
+        0
+    }
+
+    extern "C" fn free(buffer: PluginBuffer) {
+        drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(buffer.ptr, buffer.len)) });
+        FREED.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn precompile_call(input: &[u8], gas_limit: u64) -> PrecompileCall {
+        PrecompileCall { input: input.to_vec(), gas_limit, ..Default::default() }
+    }
+
+    #[test]
+    fn calls_plugin_precompiles() {
+        // the precompile is in the test binary, which stands in for the library of a plugin
+        let library = Arc::new(Library::from(libloading::os::unix::Library::this()));
+        let loaded = Loaded { gas, run, free, _library: library };
+
+        // over the gas limit, the call is not run
+        let output = loaded.call(&precompile_call(&[1, 2, 3], 102)).unwrap();
+        assert_eq!((output.gas_used, output.failed), (103, false));
+        assert!(output.output.is_empty());
+        assert_eq!(FREED.load(Ordering::SeqCst), 0);
+
+        let output = loaded.call(&precompile_call(&[], 1_000)).unwrap();
+        assert_eq!((output.gas_used, output.failed), (100, true));
+        assert!(output.output.is_empty());
+
+        // the output is copied, then given back to the plugin
+        let output = loaded.call(&precompile_call(&[1, 2, 3], 1_000)).unwrap();
+        assert_eq!((output.gas_used, output.failed), (103, false));
+        assert_eq!(output.output, vec![1, 2, 3]);
+        assert_eq!(FREED.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn rejects_missing_libraries() {
+        let err = load_plugin(Path::new("/nonexistent/libplugin.so")).err().unwrap();
+        assert!(err.to_string().contains("/nonexistent/libplugin.so"));
+    }
+}
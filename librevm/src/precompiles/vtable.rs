@@ -0,0 +1,75 @@
+use prost::Message;
+
+use crate::{
+    error::{BackendError, GoError},
+    memory::{U8SliceView, UnmanagedVector},
+    v1::types::{PrecompileCall, PrecompileOutput},
+};
+
+use super::CustomPrecompile;
+
+// this represents the precompiles passed in from the caller side of FFI
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct precompile_t {
+    _private: [u8; 0],
+}
+
+// Like the callbacks of `Db_vtable`, the return value is treated as i32 and checked when
+// converting it to GoError
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub struct Precompile_vtable {
+    /// Runs the custom precompile of a call.
+    ///
+    /// # Parameters
+    /// - `precompile_t`: A mutable pointer to the precompiles.
+    /// - `U8SliceView`: The encoded `PrecompileCall`, with the address of the precompile.
+    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the encoded
+    ///   `PrecompileOutput` will be stored.
+    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
+    ///   be stored. An error aborts the transaction, while a call which fails or reverts returns
+    ///   an output saying so, see `CustomPrecompile::call`.
+    ///
+    /// # Returns
+    /// - `i32`: Status code indicating success or failure.
+    pub call: extern "C" fn(
+        *mut precompile_t,
+        U8SliceView,          // call
+        *mut UnmanagedVector, // output
+        *mut UnmanagedVector, // error message output
+    ) -> i32,
+}
+
+/// Precompiles implemented in Go.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct GoPrecompiles {
+    pub state: *mut precompile_t,
+    pub vtable: Precompile_vtable,
+}
+
+// The Go precompiles are called from the thread of the call they run in, like the Go state db.
+unsafe impl Send for GoPrecompiles {}
+unsafe impl Sync for GoPrecompiles {}
+
+impl CustomPrecompile for GoPrecompiles {
+    fn call(&self, call: &PrecompileCall) -> Result<PrecompileOutput, BackendError> {
+        let call = call.encode_to_vec();
+        let mut error_msg = UnmanagedVector::default();
+        let mut output = UnmanagedVector::default();
+        let go_error: GoError = (self.vtable.call)(
+            self.state,
+            U8SliceView::new(Some(&call)),
+            &mut output as *mut UnmanagedVector,
+            &mut error_msg as *mut UnmanagedVector,
+        )
+        .into();
+        unsafe {
+            go_error.into_result(error_msg, || "Failed to call the Go precompile".to_owned())?;
+        }
+        PrecompileOutput::decode(output.consume().unwrap_or_default().as_slice())
+            .map_err(|err| BackendError::unknown(err.to_string()))
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use revm::primitives::{BlockEnv, EVMError};
+
+use crate::{
+    error::BackendError,
+    states::{Db, StateDB},
+    types::{result_with_receipt, TypedTxEnv},
+    v1::types::{CommitResult, EvmResult},
+    vm::{next_handle, AnyVm, Vm},
+};
+
+/// Opaque handle of a session created through the FFI. Like VM handles, session handles are never
+/// reused and zero is never a handle.
+#[allow(non_camel_case_types)]
+pub type session_t = u64;
+
+/// Transactions executed on top of each other in one block, on a VM of their own. Their changes
+/// are kept in an overlay of the state db, which can be reverted to a checkpoint, until they are
+/// committed to the state db at once.
+pub struct Session {
+    vm: AnyVm,
+    /// Gas used by the transactions executed so far, for their receipts.
+    cumulative_gas_used: u64,
+    checkpoints: Vec<Checkpoint>,
+}
+
+struct Checkpoint {
+    /// Id handed out for the checkpoint, never reused so that a dropped checkpoint stays invalid.
+    id: u64,
+    overlay: usize,
+    cumulative_gas_used: u64,
+}
+
+impl Session {
+    /// Starts a session on `vm` over `db`, unless `vm` was created over a state db of its own, in
+    /// block `block`.
+    pub fn new(mut vm: AnyVm, db: Db, block: BlockEnv) -> Self {
+        match &mut vm {
+            AnyVm::Interpreted(vm) => begin(vm, db, block),
+            AnyVm::Compiled(vm) => begin(vm, db, block),
+        }
+        Session { vm, cumulative_gas_used: 0, checkpoints: Vec::new() }
+    }
+
+    /// Executes the transaction on the changes of the previous ones, returning an `EvmResult` with
+    /// its receipt. A transaction which cannot be executed changes nothing.
+    pub fn execute(&mut self, tx: TypedTxEnv) -> EvmResult {
+        match &mut self.vm {
+            AnyVm::Interpreted(vm) => execute(vm, tx, &mut self.cumulative_gas_used),
+            AnyVm::Compiled(vm) => execute(vm, tx, &mut self.cumulative_gas_used),
+        }
+    }
+
+    /// A checkpoint to revert the session to with [`Session::revert_to`]. Like handles, checkpoint
+    /// ids are never reused and zero is never an id.
+    pub fn checkpoint(&mut self) -> u64 {
+        let overlay = self.db_mut().overlay_mut().expect("sessions keep an overlay").checkpoint();
+        let id = next_handle();
+        let cumulative_gas_used = self.cumulative_gas_used;
+        self.checkpoints.push(Checkpoint { id, overlay, cumulative_gas_used });
+        id
+    }
+
+    /// Reverts the transactions executed since `checkpoint`. The checkpoints taken after it are
+    /// dropped, `checkpoint` itself can be reverted to again.
+    pub fn revert_to(&mut self, checkpoint: u64) -> Result<(), EVMError<BackendError>> {
+        let Some(index) = self.checkpoints.iter().position(|taken| taken.id == checkpoint) else {
+            return Err(EVMError::Custom(format!(
+                "Unknown checkpoint {checkpoint}, the session was reverted before it or it was \
+                 never taken"
+            )));
+        };
+        self.checkpoints.truncate(index + 1);
+        let Checkpoint { overlay, cumulative_gas_used, .. } = self.checkpoints[index];
+        self.cumulative_gas_used = cumulative_gas_used;
+        self.db_mut().overlay_mut().expect("sessions keep an overlay").revert_to(overlay);
+        Ok(())
+    }
+
+    /// Writes the changes of the session to the state db with one call of the commit callback.
+    /// The session must not be used afterwards.
+    pub fn commit(&mut self) -> Result<CommitResult, EVMError<BackendError>> {
+        self.db_mut().flush_overlay().map_err(EVMError::Database)
+    }
+
+    fn db_mut(&mut self) -> &mut StateDB {
+        match &mut self.vm {
+            AnyVm::Interpreted(vm) => &mut vm.evm.context.evm.db,
+            AnyVm::Compiled(vm) => &mut vm.evm.context.evm.db,
+        }
+    }
+}
+
+fn begin<EXT>(vm: &mut Vm<EXT>, db: Db, block: BlockEnv) {
+    let db = vm.db_or(db);
+    let evm = &mut vm.evm;
+    evm.context.evm.db.bind(db, block.number.saturating_to());
+    evm.context.evm.db.begin_overlay();
+    evm.context.evm.inner.env.block = block;
+}
+
+fn execute<EXT>(vm: &mut Vm<EXT>, tx: TypedTxEnv, cumulative_gas_used: &mut u64) -> EvmResult {
+    let evm = &mut vm.evm;
+    evm.context.evm.db.cache.begin_tx();
+    evm.context.evm.inner.env.tx = tx.env;
+
+    match evm.transact_commit() {
+        Ok(res) => {
+            *cumulative_gas_used += res.gas_used();
+            let env = &evm.context.evm.inner.env;
+            result_with_receipt(res, env, tx.tx_type, *cumulative_gas_used)
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// A registered session. Calls on the same session are serialized by its lock.
+pub struct SessionSlot {
+    handle: session_t,
+    session: Mutex<Session>,
+}
+
+// SAFETY: the session is only reached through its lock, and nothing it holds is bound to the
+// thread which created it.
+unsafe impl Send for SessionSlot {}
+unsafe impl Sync for SessionSlot {}
+
+impl SessionSlot {
+    /// Locks the session for a call. A session which panicked in a previous call may be left
+    /// inconsistent and is not used again.
+    pub fn lock(&self) -> Result<MutexGuard<'_, Session>, EVMError<BackendError>> {
+        self.session.lock().map_err(|_| {
+            EVMError::Custom(format!("Session {} panicked in a previous call", self.handle))
+        })
+    }
+}
+
+static SESSIONS: Mutex<BTreeMap<session_t, Arc<SessionSlot>>> = Mutex::new(BTreeMap::new());
+
+pub fn register(session: Session) -> session_t {
+    let handle = next_handle();
+    let slot = Arc::new(SessionSlot { handle, session: Mutex::new(session) });
+    SESSIONS.lock().unwrap_or_else(|err| err.into_inner()).insert(handle, slot);
+    handle
+}
+
+/// Removes the session of `handle`, returning it to be committed or dropped.
+pub fn unregister(handle: session_t) -> Result<Arc<SessionSlot>, EVMError<BackendError>> {
+    let slot = SESSIONS.lock().unwrap_or_else(|err| err.into_inner()).remove(&handle);
+    slot.ok_or_else(|| unknown_handle(handle))
+}
+
+pub fn get(handle: session_t) -> Result<Arc<SessionSlot>, EVMError<BackendError>> {
+    let sessions = SESSIONS.lock().unwrap_or_else(|err| err.into_inner());
+    sessions.get(&handle).cloned().ok_or_else(|| unknown_handle(handle))
+}
+
+/// Runs `f` on the session of `handle`, once the calls running on it return.
+pub fn with_session<T>(
+    handle: session_t,
+    f: impl FnOnce(&mut Session) -> Result<T, EVMError<BackendError>>,
+) -> Result<T, EVMError<BackendError>> {
+    let slot = get(handle)?;
+    let mut session = slot.lock()?;
+    f(&mut session)
+}
+
+fn unknown_handle(handle: session_t) -> EVMError<BackendError> {
+    EVMError::Custom(format!(
+        "Unknown session handle {handle}, the session was committed, freed or never created"
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::{address, keccak256, Address, B256, U256};
+    use revm::{
+        primitives::{SpecId, TxEnv, TxKind, KECCAK_EMPTY},
+        Database,
+    };
+
+    use super::*;
+    use crate::{
+        states::{free_native, into_db, MemoryDb},
+        v1::types::{Genesis, GenesisAccount},
+        vm::interpreted_vm,
+    };
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+    const RECEIVER: Address = address!("2000000000000000000000000000000000000002");
+    const FACTORY: Address = address!("3000000000000000000000000000000000000003");
+
+    fn transfer(nonce: u64) -> TypedTxEnv {
+        let env = TxEnv {
+            caller: SENDER,
+            transact_to: TxKind::Call(RECEIVER),
+            value: U256::from(7),
+            gas_limit: 21_000,
+            nonce: Some(nonce),
+            ..Default::default()
+        };
+        TypedTxEnv { tx_type: 0, env }
+    }
+
+    fn call(to: Address, nonce: u64) -> TypedTxEnv {
+        let env = TxEnv {
+            caller: SENDER,
+            transact_to: TxKind::Call(to),
+            gas_limit: 100_000,
+            nonce: Some(nonce),
+            ..Default::default()
+        };
+        TypedTxEnv { tx_type: 0, env }
+    }
+
+    #[test]
+    fn commits_what_was_not_reverted() {
+        let alloc = [(
+            SENDER.to_string(),
+            GenesisAccount {
+                balance: U256::from(1_000_000).to_be_bytes_vec(),
+                ..Default::default()
+            },
+        )];
+        let genesis = Genesis { alloc: alloc.into() };
+        let db = into_db(Box::new(MemoryDb::from_genesis(genesis).unwrap()));
+        let vm = AnyVm::Interpreted(interpreted_vm(
+            SpecId::CANCUN,
+            Default::default(),
+            Default::default(),
+        ));
+        let mut session = Session::new(vm, db, BlockEnv::default());
+
+        assert!(session.execute(transfer(0)).receipt.is_some());
+        let checkpoint = session.checkpoint();
+        let result = session.execute(transfer(1));
+        assert_eq!(result.receipt.unwrap().cumulative_gas_used, 42_000);
+        // the state db is left untouched until the commit
+        let mut state = StateDB::new(db);
+        assert_eq!(state.basic(RECEIVER).unwrap().unwrap_or_default().balance, U256::ZERO);
+
+        let dropped = session.checkpoint();
+        session.revert_to(checkpoint).unwrap();
+        assert!(session.revert_to(dropped).is_err());
+        // the checkpoints taken afterwards do not reuse the id of a dropped one
+        assert_ne!(session.checkpoint(), dropped);
+        assert!(session.revert_to(dropped).is_err());
+        let result = session.execute(transfer(1));
+        assert_eq!(result.receipt.unwrap().cumulative_gas_used, 42_000);
+        session.revert_to(checkpoint).unwrap();
+        session.commit().unwrap();
+
+        assert_eq!(state.basic(SENDER).unwrap().unwrap().nonce, 1);
+        assert_eq!(state.basic(RECEIVER).unwrap().unwrap().balance, U256::from(7));
+
+        drop(session);
+        unsafe { free_native(db) };
+    }
+
+    #[test]
+    fn commits_destroyed_then_recreated_accounts() {
+        // CREATE2(0, 0, 1, 0) STOP, the init code being the STOP in zeroed memory
+        let factory = vec![0x60, 0x00, 0x60, 0x01, 0x60, 0x00, 0x60, 0x00, 0xf5, 0x00];
+        let created = FACTORY.create2(B256::ZERO, keccak256([0x00]));
+        let balance = U256::from(1_000_000).to_be_bytes_vec();
+        let alloc = [
+            (SENDER.to_string(), GenesisAccount { balance, ..Default::default() }),
+            (FACTORY.to_string(), GenesisAccount { code: factory, ..Default::default() }),
+            (
+                created.to_string(),
+                GenesisAccount {
+                    // CALLER SELFDESTRUCT
+                    code: vec![0x33, 0xff],
+                    storage: [(B256::ZERO.to_string(), vec![5])].into(),
+                    ..Default::default()
+                },
+            ),
+        ];
+        let genesis = Genesis { alloc: alloc.into() };
+        let db = into_db(Box::new(MemoryDb::from_genesis(genesis).unwrap()));
+        // destroyed accounts are only deleted before Cancun
+        let vm = AnyVm::Interpreted(interpreted_vm(
+            SpecId::SHANGHAI,
+            Default::default(),
+            Default::default(),
+        ));
+        let mut session = Session::new(vm, db, BlockEnv::default());
+
+        assert!(session.execute(call(created, 0)).receipt.unwrap().status);
+        assert!(session.execute(call(FACTORY, 1)).receipt.unwrap().status);
+        session.commit().unwrap();
+
+        let mut state = StateDB::new(db);
+        let info = state.basic(created).unwrap().unwrap();
+        assert_eq!((info.nonce, info.code_hash), (1, KECCAK_EMPTY));
+        assert_eq!(state.storage(created, U256::ZERO).unwrap(), U256::ZERO);
+
+        drop(session);
+        unsafe { free_native(db) };
+    }
+}
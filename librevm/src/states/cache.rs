@@ -0,0 +1,401 @@
+use alloy_primitives::{
+    map::{AddressHashMap, B256HashMap, HashMap},
+    Address, B256, U256,
+};
+use revm::primitives::{AccountInfo, Bytecode, EvmState};
+
+use crate::v1::types::{CacheConfig, CacheScope, CacheStats};
+
+/// Reads of the Go state db, kept for the configured [`CacheScope`] so that reading the same key
+/// again does not cross cgo.
+///
+/// Committed changes are written through, so the cached values stay those of the state db.
+///
+/// The values prefetched for the next transaction are kept apart, and read by that transaction
+/// whatever the scope, including when the cache is disabled.
+#[derive(Debug, Default)]
+pub struct StateCache {
+    config: CacheConfig,
+    // version of the state db set by Go, see `StateCache::set_version`
+    version: Option<u64>,
+    // version of the state db and block number the values were read in
+    context: Option<(u64, u64)>,
+    accounts: AddressHashMap<AccountInfo>,
+    storage: AddressHashMap<HashMap<U256, U256>>,
+    codes: B256HashMap<Bytecode>,
+    block_hashes: HashMap<u64, B256>,
+    stats: CacheStats,
+    prefetched: Prefetched,
+    // the prefetched values are for the next transaction
+    prefetched_for_next_tx: bool,
+}
+
+/// Values read ahead of a transaction, see [`StateCache::keep_for_next_tx`].
+#[derive(Debug, Default)]
+struct Prefetched {
+    accounts: AddressHashMap<AccountInfo>,
+    storage: HashMap<(Address, U256), U256>,
+    codes: B256HashMap<Bytecode>,
+}
+
+impl StateCache {
+    /// Replaces the config, dropping the cached values and the counters.
+    pub fn configure(&mut self, config: CacheConfig) {
+        *self = StateCache {
+            config,
+            version: self.version,
+            prefetched: std::mem::take(&mut self.prefetched),
+            prefetched_for_next_tx: self.prefetched_for_next_tx,
+            ..Default::default()
+        };
+    }
+
+    /// Sets the version of the state db the next calls read from. Go changes it whenever the state
+    /// db may have changed outside of the VM, e.g. when it was written or reverted to a snapshot.
+    pub fn set_version(&mut self, version: u64) {
+        self.version = Some(version);
+    }
+
+    pub fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+
+    /// Drops the cached values unless they were read from the same version of the state db in the
+    /// same block. Without a version, nothing tells whether the state db changed since the previous
+    /// call, and the values are always dropped: they are then kept across the transactions of one
+    /// call only.
+    pub fn begin_block(&mut self, number: u64) {
+        let context = self.version.map(|version| (version, number));
+        if context.is_none() || self.context != context {
+            self.clear_cached();
+            self.context = context;
+        }
+    }
+
+    /// Drops the cached values if they are scoped to a transaction, and the prefetched values
+    /// unless they were prefetched for it.
+    pub fn begin_tx(&mut self) {
+        if !std::mem::take(&mut self.prefetched_for_next_tx) {
+            self.prefetched = Prefetched::default();
+        }
+        if self.config.scope() == CacheScope::Transaction {
+            self.clear_cached();
+        }
+    }
+
+    /// Drops the cached and the prefetched values, e.g. when the state db may not have applied a
+    /// commit.
+    pub fn clear(&mut self) {
+        self.clear_cached();
+        self.prefetched = Prefetched::default();
+        self.prefetched_for_next_tx = false;
+    }
+
+    fn clear_cached(&mut self) {
+        self.accounts.clear();
+        self.storage.clear();
+        self.codes.clear();
+        self.block_hashes.clear();
+    }
+
+    pub fn account<E>(
+        &mut self,
+        address: Address,
+        load: impl FnOnce() -> Result<AccountInfo, E>,
+    ) -> Result<AccountInfo, E> {
+        if let Some(info) = self.prefetched.accounts.get(&address) {
+            return Ok(info.clone());
+        }
+        if !self.is_enabled() {
+            return load();
+        }
+        if let Some(info) = self.accounts.get(&address) {
+            self.stats.account_hits += 1;
+            return Ok(info.clone());
+        }
+
+        self.stats.account_misses += 1;
+        let info = load()?;
+        self.accounts.insert(address, info.clone());
+        Ok(info)
+    }
+
+    pub fn storage<E>(
+        &mut self,
+        address: Address,
+        index: U256,
+        load: impl FnOnce() -> Result<U256, E>,
+    ) -> Result<U256, E> {
+        if let Some(value) = self.prefetched.storage.get(&(address, index)) {
+            return Ok(*value);
+        }
+        if !self.is_enabled() {
+            return load();
+        }
+        if let Some(value) = self.storage.get(&address).and_then(|slots| slots.get(&index)) {
+            self.stats.storage_hits += 1;
+            return Ok(*value);
+        }
+
+        self.stats.storage_misses += 1;
+        let value = load()?;
+        self.storage.entry(address).or_default().insert(index, value);
+        Ok(value)
+    }
+
+    pub fn code<E>(
+        &mut self,
+        code_hash: B256,
+        load: impl FnOnce() -> Result<Bytecode, E>,
+    ) -> Result<Bytecode, E> {
+        if let Some(code) = self.prefetched.codes.get(&code_hash) {
+            return Ok(code.clone());
+        }
+        if !self.is_enabled() {
+            return load();
+        }
+        if let Some(code) = self.codes.get(&code_hash) {
+            self.stats.code_hits += 1;
+            return Ok(code.clone());
+        }
+
+        self.stats.code_misses += 1;
+        let code = load()?;
+        self.codes.insert(code_hash, code.clone());
+        Ok(code)
+    }
+
+    pub fn block_hash<E>(
+        &mut self,
+        number: u64,
+        load: impl FnOnce() -> Result<B256, E>,
+    ) -> Result<B256, E> {
+        if !self.is_enabled() {
+            return load();
+        }
+        if let Some(hash) = self.block_hashes.get(&number) {
+            self.stats.block_hash_hits += 1;
+            return Ok(*hash);
+        }
+
+        self.stats.block_hash_misses += 1;
+        let hash = load()?;
+        self.block_hashes.insert(number, hash);
+        Ok(hash)
+    }
+
+    /// Keeps the prefetched values for the next transaction, see [`StateCache::begin_tx`].
+    pub fn keep_for_next_tx(&mut self) {
+        self.prefetched_for_next_tx = true;
+    }
+
+    /// The prefetched or cached account of `address`.
+    pub fn cached_account(&self, address: &Address) -> Option<&AccountInfo> {
+        self.prefetched.accounts.get(address).or_else(|| self.accounts.get(address))
+    }
+
+    /// The addresses whose accounts are neither prefetched nor cached yet, without duplicates.
+    pub fn missing_accounts(&self, addresses: &[Address]) -> Vec<Address> {
+        let mut missing: Vec<_> = addresses
+            .iter()
+            .filter(|address| self.cached_account(address).is_none())
+            .copied()
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    /// The slots which are neither prefetched nor cached yet, without duplicates.
+    pub fn missing_storage(&self, slots: &[(Address, U256)]) -> Vec<(Address, U256)> {
+        let mut missing: Vec<_> = slots
+            .iter()
+            .filter(|(address, index)| {
+                !self.prefetched.storage.contains_key(&(*address, *index))
+                    && !self.storage.get(address).is_some_and(|slots| slots.contains_key(index))
+            })
+            .copied()
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    /// The code hashes whose codes are neither prefetched nor cached yet, without duplicates.
+    pub fn missing_codes(&self, code_hashes: &[B256]) -> Vec<B256> {
+        let mut missing: Vec<_> = code_hashes
+            .iter()
+            .filter(|hash| {
+                !self.prefetched.codes.contains_key(*hash) && !self.codes.contains_key(*hash)
+            })
+            .copied()
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    /// Keeps a prefetched account, and caches it when the cache is enabled. Prefetching and reading
+    /// prefetched values are not counted as hits or misses.
+    pub fn insert_account(&mut self, address: Address, info: AccountInfo) {
+        if self.is_enabled() {
+            self.accounts.insert(address, info.clone());
+        }
+        self.prefetched.accounts.insert(address, info);
+    }
+
+    pub fn insert_storage(&mut self, address: Address, index: U256, value: U256) {
+        if self.is_enabled() {
+            self.storage.entry(address).or_default().insert(index, value);
+        }
+        self.prefetched.storage.insert((address, index), value);
+    }
+
+    pub fn insert_code(&mut self, code_hash: B256, code: Bytecode) {
+        if self.is_enabled() {
+            self.codes.insert(code_hash, code.clone());
+        }
+        self.prefetched.codes.insert(code_hash, code);
+    }
+
+    /// Writes the changes committed to the state db through. The prefetched values are those from
+    /// before the changes, and are dropped.
+    pub fn commit(&mut self, changes: &EvmState) {
+        self.prefetched = Prefetched::default();
+        if !self.is_enabled() {
+            return;
+        }
+
+        for (address, account) in changes {
+            if !account.is_touched() {
+                continue;
+            }
+            // the storage of wiped accounts is read again from the state db
+            if account.is_selfdestructed() || account.is_created() {
+                self.storage.remove(address);
+            }
+            if account.is_selfdestructed() {
+                self.accounts.remove(address);
+                continue;
+            }
+
+            let mut info = account.info.clone();
+            if let Some(code) = info.code.take() {
+                self.codes.insert(info.code_hash, code);
+            }
+            self.accounts.insert(*address, info);
+
+            let slots = self.storage.entry(*address).or_default();
+            for (index, slot) in account.storage.iter().filter(|(_, slot)| slot.is_changed()) {
+                slots.insert(*index, slot.present_value);
+            }
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.scope() != CacheScope::Disabled
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use revm::primitives::{Account, EvmStorageSlot};
+
+    use super::*;
+
+    const ADDRESS: Address = Address::repeat_byte(0x11);
+
+    fn block_scoped() -> StateCache {
+        let mut cache = StateCache::default();
+        cache.configure(CacheConfig { scope: CacheScope::Block.into() });
+        cache.set_version(1);
+        cache.begin_block(1);
+        cache
+    }
+
+    fn load(value: u64) -> Result<U256, ()> {
+        Ok(U256::from(value))
+    }
+
+    #[test]
+    fn caches_reads_in_scope() {
+        let mut cache = block_scoped();
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(1)), Ok(U256::from(1)));
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(2)), Ok(U256::from(1)));
+        assert_eq!((cache.stats().storage_hits, cache.stats().storage_misses), (1, 1));
+
+        // kept across transactions of the block, dropped in the next one
+        cache.begin_tx();
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(2)), Ok(U256::from(1)));
+        cache.begin_block(2);
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(2)), Ok(U256::from(2)));
+        assert_eq!((cache.stats().storage_hits, cache.stats().storage_misses), (2, 2));
+    }
+
+    #[test]
+    fn keeps_reads_of_the_same_state_version() {
+        let mut cache = block_scoped();
+        cache.storage(ADDRESS, U256::ZERO, || load(1)).unwrap();
+        cache.begin_block(1);
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(2)), Ok(U256::from(1)));
+
+        // the state db changed
+        cache.set_version(2);
+        cache.begin_block(1);
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(2)), Ok(U256::from(2)));
+
+        // without a version, every call reads the state db again
+        cache.version = None;
+        cache.begin_block(1);
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(3)), Ok(U256::from(3)));
+        cache.begin_tx();
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(4)), Ok(U256::from(3)));
+        cache.begin_block(1);
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(4)), Ok(U256::from(4)));
+    }
+
+    #[test]
+    fn disabled_cache_reads_through() {
+        let mut cache = StateCache::default();
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(1)), Ok(U256::from(1)));
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(2)), Ok(U256::from(2)));
+        assert_eq!(cache.stats(), &CacheStats::default());
+    }
+
+    #[test]
+    fn reads_prefetched_values_in_the_next_transaction() {
+        let mut cache = StateCache::default();
+        cache.insert_storage(ADDRESS, U256::ZERO, U256::from(1));
+        cache.keep_for_next_tx();
+
+        // kept across the binding of the next call, even with the cache disabled
+        cache.begin_block(1);
+        cache.begin_tx();
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(2)), Ok(U256::from(1)));
+        assert_eq!(cache.stats(), &CacheStats::default());
+
+        cache.begin_tx();
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(2)), Ok(U256::from(2)));
+    }
+
+    #[test]
+    fn commit_writes_through() {
+        let mut cache = block_scoped();
+        cache.storage(ADDRESS, U256::ZERO, || load(1)).unwrap();
+        cache.storage(ADDRESS, U256::from(1), || load(1)).unwrap();
+
+        let mut account = Account::from(AccountInfo::default());
+        account.mark_touch();
+        account
+            .storage
+            .insert(U256::ZERO, EvmStorageSlot::new_changed(U256::from(1), U256::from(5)));
+        cache.commit(&EvmState::from_iter([(ADDRESS, account.clone())]));
+        assert_eq!(cache.storage(ADDRESS, U256::ZERO, || load(1)), Ok(U256::from(5)));
+        assert_eq!(cache.storage(ADDRESS, U256::from(1), || load(2)), Ok(U256::from(1)));
+
+        // wiped storage is read again
+        account.mark_selfdestruct();
+        cache.commit(&EvmState::from_iter([(ADDRESS, account)]));
+        assert_eq!(cache.storage(ADDRESS, U256::from(1), || load(0)), Ok(U256::ZERO));
+    }
+}
@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use alloy_primitives::{Address, Bytes, B256, U256};
+use revm::{
+    db::InMemoryDB,
+    primitives::{AccountInfo, Bytecode, EvmState},
+    Database, DatabaseCommit,
+};
+
+use crate::{error::BackendError, v1::types::Genesis};
+
+use super::native::NativeDb;
+
+/// State kept in memory, for tests and sandboxes which need no Go state db.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryDb {
+    db: InMemoryDB,
+}
+
+impl MemoryDb {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the state of the `Genesis` alloc.
+    pub fn from_genesis(genesis: Genesis) -> Result<Self, BackendError> {
+        let mut db = InMemoryDB::default();
+        for (address, account) in genesis.alloc {
+            let address = Address::from_str(&address)
+                .map_err(|_| BackendError::unknown(format!("Invalid genesis address {address}")))?;
+
+            let code = Bytecode::new_raw(Bytes::from(account.code));
+            let info = AccountInfo::new(
+                U256::from_be_slice(&account.balance),
+                account.nonce,
+                code.hash_slow(),
+                code,
+            );
+            db.insert_account_info(address, info);
+
+            for (slot, value) in account.storage {
+                let slot = B256::from_str(&slot)
+                    .map_err(|_| BackendError::unknown(format!("Invalid genesis slot {slot}")))?;
+                db.insert_account_storage(address, slot.into(), U256::from_be_slice(&value))
+                    .map_err(|err| match err {})?;
+            }
+        }
+
+        Ok(Self { db })
+    }
+}
+
+impl Database for MemoryDb {
+    type Error = BackendError;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        self.db.basic(address).map_err(|err| match err {})
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.db.code_by_hash(code_hash).map_err(|err| match err {})
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        self.db.storage(address, index).map_err(|err| match err {})
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        self.db.block_hash(number).map_err(|err| match err {})
+    }
+}
+
+impl DatabaseCommit for MemoryDb {
+    fn commit(&mut self, changes: EvmState) {
+        self.db.commit(changes)
+    }
+}
+
+impl NativeDb for MemoryDb {}
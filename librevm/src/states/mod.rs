@@ -1,5 +1,15 @@
+mod cache;
+mod memory;
+mod native;
+mod overlay;
+mod sled_db;
 mod statedb;
 mod vtable;
 
+pub use cache::*;
+pub use memory::*;
+pub use native::*;
+pub use overlay::*;
+pub use sled_db::*;
 pub use statedb::*;
 pub use vtable::*;
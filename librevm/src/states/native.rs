@@ -0,0 +1,313 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+};
+
+use alloy_primitives::{Address, Bytes, B256, U256};
+use prost::Message;
+use revm::{
+    primitives::{Account, AccountInfo, Bytecode, EvmState, EvmStorageSlot},
+    Database, DatabaseCommit,
+};
+
+use crate::{
+    error::{BackendError, GoError},
+    memory::{U8SliceView, UnmanagedVector},
+    v1::types::{self, Accounts, Deleted, Storages},
+};
+
+use super::vtable::{db_t, Db, Db_vtable};
+
+/// A state db implemented in Rust instead of Go.
+pub trait NativeDb: Database<Error = BackendError> + DatabaseCommit + Send {
+    /// Persists the changes committed since the previous block, as block `number` with `hash`.
+    /// Nothing has to be done by state dbs which do not persist.
+    fn commit_block(&mut self, _number: u64, _hash: B256) -> Result<(), BackendError> {
+        Ok(())
+    }
+}
+
+/// Exposes `native` through the `Db` vtable, like a Go state db, so that every entry point runs on
+/// it unchanged. The returned db owns `native` and must be released with [`free_native`].
+pub fn into_db(native: Box<dyn NativeDb>) -> Db {
+    let state = Box::into_raw(Box::new(native)) as *mut db_t;
+    Db {
+        state,
+        vtable: Db_vtable {
+            commit,
+            get_account,
+            get_code_by_hash,
+            get_storage,
+            get_block_hash,
+            get_accounts_batch: None,
+            get_storage_batch: None,
+            get_codes_batch: None,
+        },
+    }
+}
+
+/// Releases the state db of a `Db` created by [`into_db`].
+///
+/// # Safety
+///
+/// `db` must have been created by [`into_db`] and must not be used afterwards.
+pub unsafe fn free_native(db: Db) {
+    drop(Box::from_raw(db.state as *mut Box<dyn NativeDb>));
+}
+
+/// The state db of a `Db` created by [`into_db`].
+///
+/// # Safety
+///
+/// `db` must have been created by [`into_db`] and not released yet.
+pub unsafe fn native_of<'a>(db: &Db) -> &'a mut dyn NativeDb {
+    native(db.state)
+}
+
+/// # Safety
+///
+/// `state` must be the state of a `Db` created by [`into_db`].
+unsafe fn native<'a>(state: *mut db_t) -> &'a mut dyn NativeDb {
+    (*(state as *mut Box<dyn NativeDb>)).as_mut()
+}
+
+extern "C" fn commit(
+    state: *mut db_t,
+    storages: U8SliceView,
+    accounts: U8SliceView,
+    deleted: U8SliceView,
+    output: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
+) -> i32 {
+    // native state dbs compute no state root, the commit result is left empty
+    respond(output, error_msg, || {
+        let native = unsafe { native(state) };
+        decode_changes(storages, accounts, deleted).map(|changes| native.commit(changes))?;
+        Ok(None)
+    })
+}
+
+extern "C" fn get_account(
+    state: *mut db_t,
+    address: U8SliceView,
+    output: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
+) -> i32 {
+    respond(output, error_msg, || {
+        let native = unsafe { native(state) };
+        let info = native.basic(read_address(&address)?)?.unwrap_or_default();
+        let account = types::Account {
+            balance: info.balance.to_be_bytes_vec(),
+            nonce: info.nonce,
+            code_hash: info.code_hash.to_vec(),
+            code: Vec::new(),
+        };
+        Ok(Some(account.encode_to_vec()))
+    })
+}
+
+extern "C" fn get_code_by_hash(
+    state: *mut db_t,
+    code_hash: U8SliceView,
+    output: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
+) -> i32 {
+    respond(output, error_msg, || {
+        let native = unsafe { native(state) };
+        let code = native.code_by_hash(read_b256(&code_hash)?)?;
+        Ok(Some(code.original_bytes().to_vec()))
+    })
+}
+
+extern "C" fn get_storage(
+    state: *mut db_t,
+    address: U8SliceView,
+    index: U8SliceView,
+    output: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
+) -> i32 {
+    respond(output, error_msg, || {
+        let native = unsafe { native(state) };
+        let index = U256::from_be_slice(index.read().unwrap_or_default());
+        Ok(Some(native.storage(read_address(&address)?, index)?.to_be_bytes_vec()))
+    })
+}
+
+extern "C" fn get_block_hash(
+    state: *mut db_t,
+    number: u64,
+    output: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
+) -> i32 {
+    respond(output, error_msg, || {
+        let native = unsafe { native(state) };
+        Ok(Some(native.block_hash(number)?.to_vec()))
+    })
+}
+
+/// Runs the body of a callback and writes its output or error message, like the Go state db does.
+/// A panic is reported as an error, the callbacks cannot unwind.
+fn respond(
+    output: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
+    body: impl FnOnce() -> Result<Option<Vec<u8>>, BackendError>,
+) -> i32 {
+    let result = panic::catch_unwind(AssertUnwindSafe(body))
+        .unwrap_or_else(|_| Err(BackendError::unknown("Panic in native state db")));
+    match result {
+        Ok(data) => {
+            if let Some(output) = unsafe { output.as_mut() } {
+                *output = UnmanagedVector::new(data);
+            }
+            GoError::None as i32
+        }
+        Err(err) => {
+            if let Some(error_msg) = unsafe { error_msg.as_mut() } {
+                *error_msg = UnmanagedVector::new(Some(err.to_string().into_bytes()));
+            }
+            GoError::Other as i32
+        }
+    }
+}
+
+fn read_address(view: &U8SliceView) -> Result<Address, BackendError> {
+    match view.read() {
+        Some(bytes) if bytes.len() == Address::len_bytes() => Ok(Address::from_slice(bytes)),
+        _ => Err(BackendError::bad_argument()),
+    }
+}
+
+fn read_b256(view: &U8SliceView) -> Result<B256, BackendError> {
+    match view.read() {
+        Some(bytes) if bytes.len() == B256::len_bytes() => Ok(B256::from_slice(bytes)),
+        _ => Err(BackendError::bad_argument()),
+    }
+}
+
+/// Decodes the changes committed through the vtable back into revm's state changes.
+fn decode_changes(
+    storages: U8SliceView,
+    accounts: U8SliceView,
+    deleted: U8SliceView,
+) -> Result<EvmState, BackendError> {
+    let decode_error = |err: prost::DecodeError| BackendError::unknown(err.to_string());
+    let mut storages =
+        Storages::decode(storages.read().unwrap_or_default()).map_err(decode_error)?.storages;
+    let accounts = Accounts::decode(accounts.read().unwrap_or_default()).map_err(decode_error)?;
+    let deleted = Deleted::decode(deleted.read().unwrap_or_default()).map_err(decode_error)?;
+
+    // the deleted accounts are wiped first, those written afterwards are created again
+    let mut changes = EvmState::default();
+    for address in deleted.deleted {
+        let address =
+            Address::try_from(address.as_slice()).map_err(|_| BackendError::bad_argument())?;
+        let mut destroyed = Account::new_not_existing();
+        destroyed.mark_selfdestruct();
+        destroyed.mark_touch();
+        changes.insert(address, destroyed);
+    }
+    for (key, account) in accounts.accounts {
+        let address = Address::from_str(&key).map_err(|_| BackendError::bad_argument())?;
+        let code = Bytecode::new_raw(Bytes::from(account.code.clone()));
+        let mut info = AccountInfo::from(account);
+        info.code = Some(code);
+
+        let mut changed = Account::from(info);
+        changed.mark_touch();
+        if changes.contains_key(&address) {
+            changed.mark_created();
+        }
+        for (slot, value) in
+            storages.remove(&key).map(|storage| storage.storage).unwrap_or_default()
+        {
+            let slot = U256::from_str(&slot).map_err(|_| BackendError::bad_argument())?;
+            let value = U256::from_be_slice(&value);
+            changed.storage.insert(slot, EvmStorageSlot::new_changed(U256::ZERO, value));
+        }
+        changes.insert(address, changed);
+    }
+
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::address;
+    use revm::{primitives::TxKind, Evm};
+
+    use super::*;
+    use crate::{
+        states::{MemoryDb, StateDB},
+        v1::types::{Genesis, GenesisAccount},
+    };
+
+    const SENDER: Address = address!("1000000000000000000000000000000000000001");
+    const CONTRACT: Address = address!("2000000000000000000000000000000000000002");
+
+    #[test]
+    fn executes_on_memory_db() {
+        // PUSH1 0x2a PUSH0 SSTORE STOP
+        let code = vec![0x60, 0x2a, 0x5f, 0x55, 0x00];
+        let genesis = Genesis {
+            alloc: [
+                (
+                    SENDER.to_string(),
+                    GenesisAccount {
+                        balance: U256::from(1_000_000).to_be_bytes_vec(),
+                        ..Default::default()
+                    },
+                ),
+                (CONTRACT.to_string(), GenesisAccount { code, ..Default::default() }),
+            ]
+            .into(),
+        };
+        let db = into_db(Box::new(MemoryDb::from_genesis(genesis).unwrap()));
+
+        let mut evm = Evm::builder()
+            .with_db(StateDB::new(db))
+            .modify_tx_env(|tx| {
+                tx.caller = SENDER;
+                tx.transact_to = TxKind::Call(CONTRACT);
+                tx.value = U256::from(7);
+                tx.gas_limit = 100_000;
+            })
+            .build();
+        assert!(evm.transact_commit().unwrap().is_success());
+
+        let state = evm.db_mut();
+        assert_eq!(state.basic(SENDER).unwrap().unwrap().nonce, 1);
+        assert_eq!(state.basic(CONTRACT).unwrap().unwrap().balance, U256::from(7));
+        assert_eq!(state.storage(CONTRACT, U256::ZERO).unwrap(), U256::from(0x2a));
+
+        drop(evm);
+        unsafe { free_native(db) };
+    }
+
+    #[test]
+    fn wipes_the_storage_of_created_accounts() {
+        let storage = [(B256::ZERO.to_string(), U256::from(1).to_be_bytes_vec())].into();
+        let genesis = Genesis {
+            alloc: [(CONTRACT.to_string(), GenesisAccount { storage, ..Default::default() })]
+                .into(),
+        };
+        let db = into_db(Box::new(MemoryDb::from_genesis(genesis).unwrap()));
+        let mut state = StateDB::new(db);
+        assert_eq!(state.storage(CONTRACT, U256::ZERO).unwrap(), U256::from(1));
+
+        let mut created = Account::from(AccountInfo { nonce: 1, ..Default::default() });
+        created.mark_touch();
+        created.mark_created();
+        created
+            .storage
+            .insert(U256::from(1), EvmStorageSlot::new_changed(U256::ZERO, U256::from(2)));
+        state.commit(EvmState::from_iter([(CONTRACT, created)]));
+        assert!(matches!(state.take_commit(), Some(Ok(_))));
+
+        assert_eq!(state.basic(CONTRACT).unwrap().unwrap().nonce, 1);
+        assert_eq!(state.storage(CONTRACT, U256::ZERO).unwrap(), U256::ZERO);
+        assert_eq!(state.storage(CONTRACT, U256::from(1)).unwrap(), U256::from(2));
+
+        drop(state);
+        unsafe { free_native(db) };
+    }
+}
@@ -0,0 +1,217 @@
+use alloy_primitives::{Address, B256, U256};
+use revm::primitives::{AccountInfo, Bytecode, EvmState, HashMap};
+
+use crate::types::StateChanges;
+
+/// Changes committed in Rust on top of the state db, with a journal to revert them. Reads of the
+/// state db go through the overlay first, so the transactions see the changes of the previous
+/// ones while the state db is left untouched.
+#[derive(Debug, Default)]
+pub struct Overlay {
+    accounts: HashMap<Address, OverlayAccount>,
+    storage: HashMap<Address, HashMap<U256, U256>>,
+    // codes are keyed by their hash and never reverted
+    codes: HashMap<B256, Bytecode>,
+    journal: Vec<Entry>,
+}
+
+#[derive(Clone, Debug)]
+struct OverlayAccount {
+    /// `None` once the account self-destructed.
+    info: Option<AccountInfo>,
+    /// The storage of the state db is hidden, the account was created or destroyed in the overlay.
+    cleared: bool,
+}
+
+/// The value a change replaced, restored when the change is reverted.
+#[derive(Debug)]
+enum Entry {
+    Account(Address, Option<OverlayAccount>),
+    Slot(Address, U256, Option<U256>),
+    Storage(Address, HashMap<U256, U256>),
+}
+
+impl Overlay {
+    /// The account if it was changed in the overlay, `Some(None)` when it was destroyed.
+    pub fn account(&self, address: &Address) -> Option<Option<&AccountInfo>> {
+        self.accounts.get(address).map(|account| account.info.as_ref())
+    }
+
+    pub fn code(&self, code_hash: &B256) -> Option<&Bytecode> {
+        self.codes.get(code_hash)
+    }
+
+    /// The value of the slot if it was changed in the overlay, zero for the slots of the accounts
+    /// created or destroyed in the overlay.
+    pub fn storage(&self, address: &Address, index: &U256) -> Option<U256> {
+        if let Some(value) = self.storage.get(address).and_then(|slots| slots.get(index)) {
+            return Some(*value);
+        }
+        match self.accounts.get(address) {
+            Some(account) if account.cleared => Some(U256::ZERO),
+            _ => None,
+        }
+    }
+
+    /// Applies the changes of a transaction, like a commit to the state db.
+    pub fn commit(&mut self, changes: EvmState) {
+        for (address, account) in changes {
+            if !account.is_touched() {
+                continue;
+            }
+            if account.is_selfdestructed() {
+                self.clear_storage(address);
+                self.set_account(address, OverlayAccount { info: None, cleared: true });
+                continue;
+            }
+            let mut cleared = self.accounts.get(&address).is_some_and(|account| account.cleared);
+            if account.is_created() {
+                self.clear_storage(address);
+                cleared = true;
+            }
+            if let Some(code) = &account.info.code {
+                self.codes.insert(account.info.code_hash, code.clone());
+            }
+            for (index, slot) in account.storage {
+                if slot.is_changed() {
+                    self.set_slot(address, index, slot.present_value);
+                }
+            }
+            self.set_account(address, OverlayAccount { info: Some(account.info), cleared });
+        }
+    }
+
+    /// A point to revert the overlay to, valid until the overlay is reverted before it.
+    pub fn checkpoint(&self) -> usize {
+        self.journal.len()
+    }
+
+    /// Reverts the changes made since `checkpoint`.
+    pub fn revert_to(&mut self, checkpoint: usize) {
+        while self.journal.len() > checkpoint {
+            match self.journal.pop().expect("the journal is longer than the checkpoint") {
+                Entry::Account(address, Some(account)) => {
+                    self.accounts.insert(address, account);
+                }
+                Entry::Account(address, None) => {
+                    self.accounts.remove(&address);
+                }
+                Entry::Slot(address, index, Some(value)) => {
+                    self.storage.entry(address).or_default().insert(index, value);
+                }
+                Entry::Slot(address, index, None) => {
+                    if let Some(slots) = self.storage.get_mut(&address) {
+                        slots.remove(&index);
+                    }
+                }
+                Entry::Storage(address, slots) => {
+                    self.storage.insert(address, slots);
+                }
+            }
+        }
+    }
+
+    /// The changes of the overlay, in the format of a commit to the state db. The accounts whose
+    /// code is not known yet are left without code.
+    ///
+    /// The accounts created or destroyed in the overlay are deleted, which the state db applies
+    /// before the writes: an account destroyed then created again is written from empty storage.
+    pub fn into_changes(self) -> StateChanges {
+        let mut changes = StateChanges::default();
+        let mut storage = self.storage;
+        for (address, account) in self.accounts {
+            if account.cleared {
+                changes.deleted.push(address);
+            }
+            let Some(mut info) = account.info else {
+                continue;
+            };
+            if info.code.is_none() {
+                info.code = self.codes.get(&info.code_hash).cloned();
+            }
+            changes.accounts.insert(address, info);
+            changes.storages.insert(address, storage.remove(&address).unwrap_or_default());
+        }
+        changes
+    }
+
+    fn set_account(&mut self, address: Address, account: OverlayAccount) {
+        let previous = self.accounts.insert(address, account);
+        self.journal.push(Entry::Account(address, previous));
+    }
+
+    fn set_slot(&mut self, address: Address, index: U256, value: U256) {
+        let previous = self.storage.entry(address).or_default().insert(index, value);
+        self.journal.push(Entry::Slot(address, index, previous));
+    }
+
+    fn clear_storage(&mut self, address: Address) {
+        if let Some(slots) = self.storage.remove(&address) {
+            self.journal.push(Entry::Storage(address, slots));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::address;
+    use revm::primitives::{Account, EvmStorageSlot};
+
+    use super::*;
+
+    const ADDRESS: Address = address!("1000000000000000000000000000000000000001");
+
+    fn changes(nonce: u64, slot: u64, destroyed: bool) -> EvmState {
+        let mut account = Account::from(AccountInfo { nonce, ..Default::default() });
+        account.mark_touch();
+        if destroyed {
+            account.mark_selfdestruct();
+        }
+        account
+            .storage
+            .insert(U256::ZERO, EvmStorageSlot::new_changed(U256::ZERO, U256::from(slot)));
+        [(ADDRESS, account)].into_iter().collect()
+    }
+
+    #[test]
+    fn reverts_to_checkpoints() {
+        let mut overlay = Overlay::default();
+        assert_eq!(overlay.account(&ADDRESS), None);
+        assert_eq!(overlay.storage(&ADDRESS, &U256::ZERO), None);
+
+        overlay.commit(changes(1, 7, false));
+        let checkpoint = overlay.checkpoint();
+        overlay.commit(changes(2, 8, false));
+        assert_eq!(overlay.account(&ADDRESS).unwrap().unwrap().nonce, 2);
+        assert_eq!(overlay.storage(&ADDRESS, &U256::ZERO), Some(U256::from(8)));
+
+        overlay.commit(changes(3, 9, true));
+        assert_eq!(overlay.account(&ADDRESS), Some(None));
+        // the slots of the state db are hidden too
+        assert_eq!(overlay.storage(&ADDRESS, &U256::from(1)), Some(U256::ZERO));
+
+        overlay.revert_to(checkpoint);
+        assert_eq!(overlay.account(&ADDRESS).unwrap().unwrap().nonce, 1);
+        assert_eq!(overlay.storage(&ADDRESS, &U256::ZERO), Some(U256::from(7)));
+        assert_eq!(overlay.storage(&ADDRESS, &U256::from(1)), None);
+
+        let changes = overlay.into_changes();
+        assert_eq!(changes.accounts[&ADDRESS].nonce, 1);
+        assert_eq!(changes.storages[&ADDRESS][&U256::ZERO], U256::from(7));
+        assert!(changes.deleted.is_empty());
+    }
+
+    #[test]
+    fn deletes_recreated_accounts() {
+        let mut overlay = Overlay::default();
+        overlay.commit(changes(1, 7, true));
+        let mut recreated = changes(2, 8, false);
+        recreated.get_mut(&ADDRESS).unwrap().mark_created();
+        overlay.commit(recreated);
+
+        let changes = overlay.into_changes();
+        assert_eq!(changes.deleted, vec![ADDRESS]);
+        assert_eq!(changes.accounts[&ADDRESS].nonce, 2);
+        assert_eq!(changes.storages[&ADDRESS][&U256::ZERO], U256::from(8));
+    }
+}
@@ -0,0 +1,254 @@
+use std::{collections::HashSet, path::Path};
+
+use alloy_primitives::{map::HashMap, Address, Bytes, B256, U256};
+use prost::Message;
+use revm::{
+    primitives::{AccountInfo, Bytecode, EvmState, KECCAK_EMPTY},
+    Database, DatabaseCommit,
+};
+use sled::{transaction::ConflictableTransactionError, IVec, Transactional, Tree};
+
+use crate::{error::BackendError, v1::types::Account};
+
+use super::native::NativeDb;
+
+/// State persisted with sled, for nodes which run without a Go state db.
+///
+/// Accounts, storage, codes and block hashes are kept in separate trees. Committed changes stay in
+/// memory until [`NativeDb::commit_block`] writes the changes of the whole block in one
+/// transaction, so a crash never leaves a partially applied block behind.
+pub struct SledDb {
+    db: sled::Db,
+    // address => account without code
+    accounts: Tree,
+    // address ++ slot => value
+    storage: Tree,
+    // code hash => code
+    codes: Tree,
+    // block number => block hash
+    block_hashes: Tree,
+    pending: PendingBlock,
+}
+
+/// Changes committed since the last block was written.
+#[derive(Default)]
+struct PendingBlock {
+    // None for deleted accounts
+    accounts: HashMap<Address, Option<AccountInfo>>,
+    storage: HashMap<(Address, U256), U256>,
+    // accounts whose storage is wiped before `storage` is applied
+    wiped: HashSet<Address>,
+    codes: HashMap<B256, Bytecode>,
+}
+
+impl SledDb {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, BackendError> {
+        Self::new(sled::open(path).map_err(sled_error)?)
+    }
+
+    pub fn new(db: sled::Db) -> Result<Self, BackendError> {
+        Ok(Self {
+            accounts: db.open_tree("accounts").map_err(sled_error)?,
+            storage: db.open_tree("storage").map_err(sled_error)?,
+            codes: db.open_tree("codes").map_err(sled_error)?,
+            block_hashes: db.open_tree("block_hashes").map_err(sled_error)?,
+            db,
+            pending: PendingBlock::default(),
+        })
+    }
+}
+
+impl Database for SledDb {
+    type Error = BackendError;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        if let Some(info) = self.pending.accounts.get(&address) {
+            return Ok(info.clone());
+        }
+
+        let Some(value) = self.accounts.get(address).map_err(sled_error)? else {
+            return Ok(None);
+        };
+        let account = Account::decode(value.as_ref())
+            .map_err(|err| BackendError::unknown(err.to_string()))?;
+        Ok(Some(account.into()))
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        if let Some(code) = self.pending.codes.get(&code_hash) {
+            return Ok(code.clone());
+        }
+        if code_hash == KECCAK_EMPTY {
+            return Ok(Bytecode::default());
+        }
+
+        match self.codes.get(code_hash).map_err(sled_error)? {
+            Some(code) => Ok(Bytecode::new_raw(Bytes::copy_from_slice(&code))),
+            None => Err(BackendError::unknown(format!("Code {code_hash} not found"))),
+        }
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        if let Some(value) = self.pending.storage.get(&(address, index)) {
+            return Ok(*value);
+        }
+        if self.pending.wiped.contains(&address) {
+            return Ok(U256::ZERO);
+        }
+
+        let value = self.storage.get(storage_key(address, index)).map_err(sled_error)?;
+        Ok(value.map(|value| U256::from_be_slice(&value)).unwrap_or_default())
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        let hash = self.block_hashes.get(number.to_be_bytes()).map_err(sled_error)?;
+        Ok(hash.map(|hash| B256::from_slice(&hash)).unwrap_or_default())
+    }
+}
+
+impl DatabaseCommit for SledDb {
+    fn commit(&mut self, changes: EvmState) {
+        let pending = &mut self.pending;
+        for (address, account) in changes {
+            if !account.is_touched() {
+                continue;
+            }
+            if account.is_selfdestructed() || account.is_created() {
+                pending.wiped.insert(address);
+                pending.storage.retain(|(owner, _), _| *owner != address);
+            }
+            if account.is_selfdestructed() {
+                pending.accounts.insert(address, None);
+                continue;
+            }
+
+            let mut info = account.info;
+            if let Some(code) = info.code.take() {
+                if !code.is_empty() {
+                    pending.codes.insert(info.code_hash, code);
+                }
+            }
+            pending.accounts.insert(address, Some(info));
+            for (index, slot) in account.storage.into_iter().filter(|(_, slot)| slot.is_changed()) {
+                pending.storage.insert((address, index), slot.present_value);
+            }
+        }
+    }
+}
+
+impl NativeDb for SledDb {
+    fn commit_block(&mut self, number: u64, hash: B256) -> Result<(), BackendError> {
+        // the changes are kept until they are written, a failed block can be written again
+        let pending = &self.pending;
+
+        // transactions cannot scan, the wiped slots are listed beforehand
+        let mut wiped_keys = Vec::new();
+        for address in &pending.wiped {
+            for key in self.storage.scan_prefix(address).keys() {
+                wiped_keys.push(key.map_err(sled_error)?);
+            }
+        }
+
+        let trees = (&self.accounts, &self.storage, &self.codes, &self.block_hashes);
+        trees
+            .transaction(|(accounts, storage, codes, block_hashes)| {
+                for key in &wiped_keys {
+                    storage.remove(key.clone())?;
+                }
+                for (address, info) in &pending.accounts {
+                    match info {
+                        Some(info) => accounts.insert(address.as_slice(), encode_account(info))?,
+                        None => accounts.remove(address.as_slice())?,
+                    };
+                }
+                for ((address, index), value) in &pending.storage {
+                    let key = storage_key(*address, *index);
+                    if value.is_zero() {
+                        storage.remove(key)?;
+                    } else {
+                        storage.insert(key, value.to_be_bytes_vec())?;
+                    }
+                }
+                for (code_hash, code) in &pending.codes {
+                    codes.insert(code_hash.as_slice(), code.original_bytes().to_vec())?;
+                }
+                block_hashes.insert(&number.to_be_bytes()[..], hash.as_slice())?;
+                Ok::<_, ConflictableTransactionError<()>>(())
+            })
+            .map_err(|err| BackendError::unknown(format!("{err:?}")))?;
+
+        self.db.flush().map_err(sled_error)?;
+        self.pending = PendingBlock::default();
+        Ok(())
+    }
+}
+
+fn storage_key(address: Address, index: U256) -> IVec {
+    let mut key = address.to_vec();
+    key.extend_from_slice(&index.to_be_bytes::<32>());
+    key.into()
+}
+
+fn encode_account(info: &AccountInfo) -> Vec<u8> {
+    Account {
+        balance: info.balance.to_be_bytes_vec(),
+        nonce: info.nonce,
+        code_hash: info.code_hash.to_vec(),
+        code: Vec::new(),
+    }
+    .encode_to_vec()
+}
+
+fn sled_error(err: sled::Error) -> BackendError {
+    BackendError::unknown(err.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use revm::primitives::{Account as EvmAccount, EvmStorageSlot};
+
+    use super::*;
+
+    const ADDRESS: Address = Address::repeat_byte(0x11);
+
+    fn changed_account(balance: u64, slots: &[(u64, u64)]) -> EvmState {
+        let mut account = EvmAccount::from(AccountInfo::from_balance(U256::from(balance)));
+        account.mark_touch();
+        for (index, value) in slots {
+            account.storage.insert(
+                U256::from(*index),
+                EvmStorageSlot::new_changed(U256::ZERO, U256::from(*value)),
+            );
+        }
+        EvmState::from_iter([(ADDRESS, account)])
+    }
+
+    #[test]
+    fn writes_committed_blocks() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        let mut sled_db = SledDb::new(db.clone()).unwrap();
+
+        sled_db.commit(changed_account(5, &[(0, 1), (1, 2)]));
+        assert_eq!(sled_db.basic(ADDRESS).unwrap().unwrap().balance, U256::from(5));
+        assert_eq!(sled_db.storage(ADDRESS, U256::from(1)).unwrap(), U256::from(2));
+
+        // nothing is written until the block is
+        assert_eq!(SledDb::new(db.clone()).unwrap().basic(ADDRESS).unwrap(), None);
+        sled_db.commit_block(1, B256::repeat_byte(0xbb)).unwrap();
+
+        let mut reopened = SledDb::new(db.clone()).unwrap();
+        assert_eq!(reopened.basic(ADDRESS).unwrap().unwrap().balance, U256::from(5));
+        assert_eq!(reopened.storage(ADDRESS, U256::ZERO).unwrap(), U256::from(1));
+        assert_eq!(reopened.block_hash(1).unwrap(), B256::repeat_byte(0xbb));
+
+        // a deleted account loses its storage
+        let mut destroyed = changed_account(0, &[]);
+        destroyed.get_mut(&ADDRESS).unwrap().mark_selfdestruct();
+        sled_db.commit(destroyed);
+        sled_db.commit_block(2, B256::repeat_byte(0xcc)).unwrap();
+
+        let mut reopened = SledDb::new(db).unwrap();
+        assert_eq!(reopened.basic(ADDRESS).unwrap(), None);
+        assert_eq!(reopened.storage(ADDRESS, U256::from(1)).unwrap(), U256::ZERO);
+    }
+}
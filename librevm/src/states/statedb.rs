@@ -1,156 +1,434 @@
 use alloy_primitives::{Address, BlockHash, Bytes, B256, U256};
+use prost::Message;
 use revm::{
-    primitives::{Account, AccountInfo, Bytecode, HashMap},
+    primitives::{Account, AccountInfo, Bytecode, EvmState, HashMap},
     Database, DatabaseCommit,
 };
 
 use crate::{
     error::{BackendError, GoError},
     memory::{U8SliceView, UnmanagedVector},
-    types::{DeletedAccounts, UpdatedAccounts, UpdatedStorages},
+    types::StateChanges,
+    v1::types::{
+        AccountList, Addresses, CodeHashes, Codes, CommitResult, StorageKeys, StorageSlot,
+        StorageValues,
+    },
 };
 
-use super::vtable::Db;
+use super::{
+    cache::StateCache,
+    overlay::Overlay,
+    vtable::{db_t, Db},
+};
+
+/// Signature of the optional batch callbacks of `Db_vtable`.
+type BatchFn =
+    extern "C" fn(*mut db_t, U8SliceView, *mut UnmanagedVector, *mut UnmanagedVector) -> i32;
 
-pub struct StateDB<'r> {
-    pub db: &'r Db,
+pub struct StateDB {
+    pub db: Db,
+    pub cache: StateCache,
+    /// Outcome of the commits not taken yet, see [`StateDB::take_commit`].
+    commit: Option<Result<CommitResult, BackendError>>,
+    /// Keeps the commits in Rust instead of the state db, see [`StateDB::begin_overlay`].
+    overlay: Option<Overlay>,
 }
 
-impl<'r> StateDB<'r> {
-    pub fn new(db: &'r Db) -> Self {
-        StateDB { db }
+impl StateDB {
+    pub fn new(db: Db) -> Self {
+        StateDB { db, cache: StateCache::default(), commit: None, overlay: None }
+    }
+
+    /// Points the state db at the `db` of a new call in block `number`, keeping the cache if it was
+    /// filled from the same version of the state in the same block, see
+    /// [`StateCache::begin_block`].
+    pub fn bind(&mut self, db: Db, number: u64) {
+        self.db = db;
+        self.cache.begin_block(number);
+        self.commit = None;
+    }
+
+    /// The outcome of the commits since the previous call, which `DatabaseCommit` cannot return:
+    /// the result of the last commit, or the error of the first one which failed. `None` when
+    /// nothing was committed.
+    pub fn take_commit(&mut self) -> Option<Result<CommitResult, BackendError>> {
+        self.commit.take()
+    }
+
+    /// Starts keeping the commits in an overlay instead of writing them to the state db, until
+    /// [`StateDB::flush_overlay`]. The reads see the changes of the overlay.
+    pub fn begin_overlay(&mut self) {
+        self.overlay = Some(Overlay::default());
+    }
+
+    pub fn overlay_mut(&mut self) -> Option<&mut Overlay> {
+        self.overlay.as_mut()
+    }
+
+    /// Writes the changes of the overlay to the state db with one call of the commit callback, and
+    /// commits to the state db again afterwards.
+    pub fn flush_overlay(&mut self) -> Result<CommitResult, BackendError> {
+        let Some(overlay) = self.overlay.take() else {
+            return Ok(CommitResult::default());
+        };
+        let mut changes = overlay.into_changes();
+        for info in changes.accounts.values_mut() {
+            if info.code.is_none() {
+                info.code = Some(self.code_by_hash(info.code_hash)?);
+            }
+        }
+        // the cache holds the state from before the overlay
+        self.cache.clear();
+        self.write(changes)
+    }
+
+    /// Reads the accounts, their codes and the slots ahead of the next transaction, with one call
+    /// per kind of key when the state db implements the batch callbacks. The values are kept for
+    /// that transaction whatever the config of the cache, see [`StateCache::keep_for_next_tx`].
+    pub fn prefetch(
+        &mut self,
+        addresses: &[Address],
+        slots: &[(Address, U256)],
+    ) -> Result<(), BackendError> {
+        let db = &self.db;
+
+        let missing = self.cache.missing_accounts(addresses);
+        for (address, info) in missing.iter().zip(get_accounts(db, &missing)?) {
+            self.cache.insert_account(*address, info);
+        }
+
+        let code_hashes: Vec<B256> = addresses
+            .iter()
+            .filter_map(|address| self.cache.cached_account(address))
+            .filter(|info| !info.is_empty_code_hash() && !info.code_hash.is_zero())
+            .map(|info| info.code_hash)
+            .collect();
+        let missing = self.cache.missing_codes(&code_hashes);
+        for (code_hash, code) in missing.iter().zip(get_codes(db, &missing)?) {
+            self.cache.insert_code(*code_hash, code);
+        }
+
+        let missing = self.cache.missing_storage(slots);
+        for ((address, index), value) in missing.iter().zip(get_storages(db, &missing)?) {
+            self.cache.insert_storage(*address, *index, value);
+        }
+
+        self.cache.keep_for_next_tx();
+        Ok(())
     }
 }
 
-impl Database for StateDB<'_> {
+// The Go state db is not thread safe. It may be moved to another thread, but it is only ever
+// accessed by one thread at a time, e.g. behind the lock of the parallel executor's base state.
+unsafe impl Send for StateDB {}
+
+impl Database for StateDB {
     type Error = BackendError;
 
     #[doc = " Get basic account information."]
     fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, BackendError> {
-        let mut error_msg = UnmanagedVector::default();
-        let mut output = UnmanagedVector::default();
-        let go_error: GoError = (self.db.vtable.get_account)(
-            self.db.state,
-            U8SliceView::new(Some(address.as_slice())),
-            &mut output as *mut UnmanagedVector,
-            &mut error_msg as *mut UnmanagedVector,
-        )
-        .into();
-        unsafe {
-            go_error
-                .into_result(error_msg, || "Failed to get account info from the db".to_owned())?;
+        if let Some(account) = self.overlay.as_ref().and_then(|overlay| overlay.account(&address)) {
+            return Ok(account.cloned());
         }
-        let account_info: AccountInfo = output.try_into().unwrap();
+        let db = &self.db;
+        let account_info = self.cache.account(address, || get_account(db, address))?;
         Ok(Some(account_info))
     }
 
     #[doc = " Get account code by its hash."]
     fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
-        let mut error_msg = UnmanagedVector::default();
-        let mut output = UnmanagedVector::default();
-        let go_error: GoError = (self.db.vtable.get_code_by_hash)(
-            self.db.state,
-            U8SliceView::new(Some(code_hash.as_slice())),
-            &mut output as *mut UnmanagedVector,
-            &mut error_msg as *mut UnmanagedVector,
-        )
-        .into();
-        unsafe {
-            go_error.into_result(error_msg, || "Failed to get code from the db".to_owned())?;
+        if let Some(code) = self.overlay.as_ref().and_then(|overlay| overlay.code(&code_hash)) {
+            return Ok(code.clone());
         }
-        let bytecode_bytes = output.consume().unwrap();
-        let bytecode = Bytecode::new_raw(Bytes::from(bytecode_bytes));
-        Ok(bytecode)
+        let db = &self.db;
+        self.cache.code(code_hash, || get_code_by_hash(db, code_hash))
     }
 
     #[doc = " Get storage value of address at index."]
     fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
-        let mut error_msg = UnmanagedVector::default();
-        let mut output = UnmanagedVector::default();
-        let go_error: GoError = (self.db.vtable.get_storage)(
-            self.db.state,
-            U8SliceView::new(Some(address.as_slice())),
-            U8SliceView::new(Some(&index.to_be_bytes_vec())),
-            &mut output as *mut UnmanagedVector,
-            &mut error_msg as *mut UnmanagedVector,
-        )
-        .into();
-        unsafe {
-            go_error.into_result(error_msg, || "Failed to get storage from the db".to_owned())?;
+        if let Some(value) =
+            self.overlay.as_ref().and_then(|overlay| overlay.storage(&address, &index))
+        {
+            return Ok(value);
         }
-        let value_bytes = output.consume().unwrap();
-        let value = U256::from_be_slice(value_bytes.as_slice());
-        Ok(value)
+        let db = &self.db;
+        self.cache.storage(address, index, || get_storage(db, address, index))
     }
 
     #[doc = " Get block hash by block number."]
     fn block_hash(&mut self, number: u64) -> Result<BlockHash, Self::Error> {
-        let mut error_msg = UnmanagedVector::default();
-        let mut output = UnmanagedVector::default();
-        let go_error: GoError = (self.db.vtable.get_block_hash)(
-            self.db.state,
-            number,
-            &mut output as *mut UnmanagedVector,
-            &mut error_msg as *mut UnmanagedVector,
-        )
-        .into();
+        let db = &self.db;
+        self.cache.block_hash(number, || get_block_hash(db, number))
+    }
+}
 
-        unsafe {
-            go_error
-                .into_result(error_msg, || "Failed to get block hash from the db".to_owned())?;
+fn get_account(db: &Db, address: Address) -> Result<AccountInfo, BackendError> {
+    let mut error_msg = UnmanagedVector::default();
+    let mut output = UnmanagedVector::default();
+    let go_error: GoError = (db.vtable.get_account)(
+        db.state,
+        U8SliceView::new(Some(address.as_slice())),
+        &mut output as *mut UnmanagedVector,
+        &mut error_msg as *mut UnmanagedVector,
+    )
+    .into();
+    unsafe {
+        go_error.into_result(error_msg, || "Failed to get account info from the db".to_owned())?;
+    }
+    let account_info: AccountInfo = output.try_into().unwrap();
+    Ok(account_info)
+}
+
+fn get_code_by_hash(db: &Db, code_hash: B256) -> Result<Bytecode, BackendError> {
+    let mut error_msg = UnmanagedVector::default();
+    let mut output = UnmanagedVector::default();
+    let go_error: GoError = (db.vtable.get_code_by_hash)(
+        db.state,
+        U8SliceView::new(Some(code_hash.as_slice())),
+        &mut output as *mut UnmanagedVector,
+        &mut error_msg as *mut UnmanagedVector,
+    )
+    .into();
+    unsafe {
+        go_error.into_result(error_msg, || "Failed to get code from the db".to_owned())?;
+    }
+    let bytecode_bytes = output.consume().unwrap();
+    let bytecode = Bytecode::new_raw(Bytes::from(bytecode_bytes));
+    Ok(bytecode)
+}
+
+fn get_storage(db: &Db, address: Address, index: U256) -> Result<U256, BackendError> {
+    let mut error_msg = UnmanagedVector::default();
+    let mut output = UnmanagedVector::default();
+    let go_error: GoError = (db.vtable.get_storage)(
+        db.state,
+        U8SliceView::new(Some(address.as_slice())),
+        U8SliceView::new(Some(&index.to_be_bytes_vec())),
+        &mut output as *mut UnmanagedVector,
+        &mut error_msg as *mut UnmanagedVector,
+    )
+    .into();
+    unsafe {
+        go_error.into_result(error_msg, || "Failed to get storage from the db".to_owned())?;
+    }
+    let value_bytes = output.consume().unwrap();
+    let value = U256::from_be_slice(value_bytes.as_slice());
+    Ok(value)
+}
+
+fn get_block_hash(db: &Db, number: u64) -> Result<BlockHash, BackendError> {
+    let mut error_msg = UnmanagedVector::default();
+    let mut output = UnmanagedVector::default();
+    let go_error: GoError = (db.vtable.get_block_hash)(
+        db.state,
+        number,
+        &mut output as *mut UnmanagedVector,
+        &mut error_msg as *mut UnmanagedVector,
+    )
+    .into();
+
+    unsafe {
+        go_error.into_result(error_msg, || "Failed to get block hash from the db".to_owned())?;
+    }
+
+    let block_hash = BlockHash::from_slice(&output.consume().unwrap());
+    Ok(block_hash)
+}
+
+fn get_accounts(db: &Db, addresses: &[Address]) -> Result<Vec<AccountInfo>, BackendError> {
+    let batch = match db.vtable.get_accounts_batch {
+        Some(batch) if !addresses.is_empty() => batch,
+        _ => return addresses.iter().map(|address| get_account(db, *address)).collect(),
+    };
+
+    let keys = Addresses { addresses: addresses.iter().map(|address| address.to_vec()).collect() };
+    let output = call_batch(db, batch, &keys, || "Failed to get accounts from the db".to_owned())?;
+    let accounts = AccountList::decode(output.as_slice())
+        .map_err(|err| BackendError::unknown(err.to_string()))?
+        .accounts;
+    check_batch_len(addresses.len(), accounts.len())?;
+    Ok(accounts.into_iter().map(AccountInfo::from).collect())
+}
+
+fn get_storages(db: &Db, slots: &[(Address, U256)]) -> Result<Vec<U256>, BackendError> {
+    let batch = match db.vtable.get_storage_batch {
+        Some(batch) if !slots.is_empty() => batch,
+        _ => {
+            return slots.iter().map(|(address, index)| get_storage(db, *address, *index)).collect()
         }
+    };
+
+    let keys = StorageKeys {
+        keys: slots
+            .iter()
+            .map(|(address, index)| StorageSlot {
+                address: address.to_vec(),
+                key: index.to_be_bytes_vec(),
+            })
+            .collect(),
+    };
+    let output = call_batch(db, batch, &keys, || "Failed to get storages from the db".to_owned())?;
+    let values = StorageValues::decode(output.as_slice())
+        .map_err(|err| BackendError::unknown(err.to_string()))?
+        .values;
+    check_batch_len(slots.len(), values.len())?;
+    Ok(values.iter().map(|value| U256::from_be_slice(value)).collect())
+}
+
+fn get_codes(db: &Db, code_hashes: &[B256]) -> Result<Vec<Bytecode>, BackendError> {
+    let batch = match db.vtable.get_codes_batch {
+        Some(batch) if !code_hashes.is_empty() => batch,
+        _ => return code_hashes.iter().map(|code_hash| get_code_by_hash(db, *code_hash)).collect(),
+    };
+
+    let keys = CodeHashes { code_hashes: code_hashes.iter().map(|hash| hash.to_vec()).collect() };
+    let output = call_batch(db, batch, &keys, || "Failed to get codes from the db".to_owned())?;
+    let codes = Codes::decode(output.as_slice())
+        .map_err(|err| BackendError::unknown(err.to_string()))?
+        .codes;
+    check_batch_len(code_hashes.len(), codes.len())?;
+    Ok(codes.into_iter().map(|code| Bytecode::new_raw(Bytes::from(code))).collect())
+}
 
-        let block_hash = BlockHash::from_slice(&output.consume().unwrap());
-        Ok(block_hash)
+fn call_batch(
+    db: &Db,
+    batch: BatchFn,
+    keys: &impl Message,
+    default_error_msg: impl FnOnce() -> String,
+) -> Result<Vec<u8>, BackendError> {
+    let keys = keys.encode_to_vec();
+    let mut error_msg = UnmanagedVector::default();
+    let mut output = UnmanagedVector::default();
+    let go_error: GoError = batch(
+        db.state,
+        U8SliceView::new(Some(&keys)),
+        &mut output as *mut UnmanagedVector,
+        &mut error_msg as *mut UnmanagedVector,
+    )
+    .into();
+    unsafe {
+        go_error.into_result(error_msg, default_error_msg)?;
     }
+    Ok(output.consume().unwrap_or_default())
 }
 
-impl DatabaseCommit for StateDB<'_> {
-    #[doc = " Commit changes to the database."]
-    fn commit(&mut self, changes: HashMap<Address, Account>) {
-        let mut updated_storages: UpdatedStorages = HashMap::default();
-        let mut updated_accounts: UpdatedAccounts = HashMap::default();
-        let mut deleted_accounts: DeletedAccounts = Vec::default();
+fn check_batch_len(keys: usize, values: usize) -> Result<(), BackendError> {
+    if keys != values {
+        return Err(BackendError::unknown(format!(
+            "Batch callback returned {values} values for {keys} keys"
+        )));
+    }
+    Ok(())
+}
 
-        for (address, account) in changes {
+impl StateDB {
+    /// The changes of `state` as they are committed to the state db: the touched accounts with
+    /// their code, the slots whose value changed, and the self-destructed or created accounts,
+    /// whose previous storage is wiped. Neither the state db nor the cache are changed.
+    pub fn changeset(&mut self, state: &EvmState) -> Result<StateChanges, BackendError> {
+        let mut changes = StateChanges::default();
+
+        for (address, account) in state {
             if !account.is_touched() {
                 continue;
             }
             if account.is_selfdestructed() {
                 // Update Deleted Accounts
-                deleted_accounts.push(address);
+                changes.deleted.push(*address);
                 continue;
             }
-            let mut info = account.clone().info;
+            if account.is_created() {
+                // wiped before it is written again
+                changes.deleted.push(*address);
+            }
+            let mut info = account.info.clone();
             if info.code.is_none() {
-                info.code = Some(self.code_by_hash(info.code_hash).unwrap());
+                info.code = Some(self.code_by_hash(info.code_hash)?);
             }
             // Update Accounts
-            updated_accounts.insert(address, info);
+            changes.accounts.insert(*address, info);
 
             // Update Storages
             let mut updated_storages_by_address = HashMap::default();
-            for (key, evm_storage_slot) in account.storage {
+            for (key, evm_storage_slot) in &account.storage {
                 if evm_storage_slot.original_value != evm_storage_slot.present_value {
-                    updated_storages_by_address.insert(key, evm_storage_slot.present_value);
+                    updated_storages_by_address.insert(*key, evm_storage_slot.present_value);
                 }
             }
-            updated_storages.insert(address, updated_storages_by_address);
+            changes.storages.insert(*address, updated_storages_by_address);
         }
+        Ok(changes)
+    }
+
+    /// Writes `changes` to the state db through the commit callback.
+    fn write(&self, changes: StateChanges) -> Result<CommitResult, BackendError> {
+        // the encoded changes must outlive the views passed to the db
+        let updated_storages: Vec<u8> = changes.storages.try_into().unwrap();
+        let updated_accounts: Vec<u8> = changes.accounts.try_into().unwrap();
+        let deleted_accounts: Vec<u8> = changes.deleted.try_into().unwrap();
         // Commited by ffi call in extended state database
         let mut error_msg = UnmanagedVector::default();
+        let mut output = UnmanagedVector::default();
         let go_error: GoError = (self.db.vtable.commit)(
             self.db.state,
-            updated_storages.try_into().unwrap(),
-            updated_accounts.try_into().unwrap(),
-            deleted_accounts.try_into().unwrap(),
+            U8SliceView::new(Some(&updated_storages)),
+            U8SliceView::new(Some(&updated_accounts)),
+            U8SliceView::new(Some(&deleted_accounts)),
+            &mut output as *mut UnmanagedVector,
             &mut error_msg as *mut UnmanagedVector,
         )
         .into();
 
         unsafe {
-            let _ = go_error
-                .into_result(error_msg, || "Failed to commit changes in the state db".to_owned());
+            go_error
+                .into_result(error_msg, || "Failed to commit changes in the state db".to_owned())
+        }
+        .and_then(|()| {
+            CommitResult::decode(output.consume().unwrap_or_default().as_slice())
+                .map_err(|err| BackendError::unknown(err.to_string()))
+        })
+    }
+
+    fn record_commit(&mut self, result: Result<CommitResult, BackendError>) {
+        // the state db may not hold what was written through
+        if result.is_err() {
+            self.cache.clear();
         }
+        // a failure is kept until it is taken, later commits do not hide it
+        if !matches!(self.commit, Some(Err(_))) {
+            self.commit = Some(result);
+        }
+    }
+}
+
+impl DatabaseCommit for StateDB {
+    #[doc = " Commit changes to the database."]
+    fn commit(&mut self, changes: HashMap<Address, Account>) {
+        if let Some(overlay) = &mut self.overlay {
+            return overlay.commit(changes);
+        }
+        let result = self.changeset(&changes).and_then(|changeset| self.write(changeset));
+        // the cache is written through once the state db holds the changes
+        if result.is_ok() {
+            self.cache.commit(&changes);
+        }
+        self.record_commit(result);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keeps_failed_commits_until_taken() {
+        let mut state = StateDB::new(Db::default());
+        assert_eq!(state.take_commit(), None);
+
+        // the default db fails every commit
+        state.commit(HashMap::default());
+        state.commit(HashMap::default());
+        assert!(matches!(state.take_commit(), Some(Err(BackendError::Unknown { .. }))));
+        assert_eq!(state.take_commit(), None);
     }
 }
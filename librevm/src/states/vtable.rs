@@ -1,4 +1,7 @@
-use crate::memory::{U8SliceView, UnmanagedVector};
+use crate::{
+    error::GoError,
+    memory::{U8SliceView, UnmanagedVector},
+};
 
 //use crate::{ iterator::GoIter, memory::{ U8SliceView, UnmanagedVector } };
 //
@@ -13,20 +16,20 @@ pub struct db_t {
 // value as i32 and then check it when converting to GoError manually
 // https://github.com/ethereum/go-ethereum/blob/08e6bdb550712503873fb2a138b30132cc36c481/core/vm/interface.go#L32
 #[repr(C)]
+#[derive(Clone, Copy)]
 #[allow(non_camel_case_types)]
 pub struct Db_vtable {
     /// Commits the state mutations into the configured data stores.
     ///
     /// # Parameters
     /// - `db_t`: A mutable pointer to the database.
-    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the codes will be
-    ///   stored.
-    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the storages will be
-    ///   stored.
-    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the accounts will be
-    ///   stored.
-    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the deleted accounts
-    ///   will be stored.
+    /// - `U8SliceView`: The encoded `Storages` which were updated.
+    /// - `U8SliceView`: The encoded `Accounts` which were updated.
+    /// - `U8SliceView`: The encoded `Deleted` accounts, whose account and storage are wiped before
+    ///   the updates are applied. Created accounts are listed as well, so that the storage they
+    ///   had before is wiped.
+    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the encoded
+    ///   `CommitResult`, e.g. the new state root, will be stored. It may be left empty.
     /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
     ///   be stored.
     ///
@@ -37,6 +40,7 @@ pub struct Db_vtable {
         U8SliceView,          // storages
         U8SliceView,          // accounts
         U8SliceView,          // deleted accounts and storages
+        *mut UnmanagedVector, // commit result output
         *mut UnmanagedVector, // error message output
     ) -> i32,
 
@@ -117,9 +121,73 @@ pub struct Db_vtable {
         *mut UnmanagedVector, // result output
         *mut UnmanagedVector, // error message output
     ) -> i32,
+
+    /// Optionally retrieves the accounts of many addresses in one call.
+    ///
+    /// # Parameters
+    /// - `db_t`: A mutable pointer to the database.
+    /// - `U8SliceView`: The `Addresses` proto of the accounts being retrieved.
+    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the `AccountList`
+    ///   proto, in the order of the addresses, will be stored.
+    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
+    ///   be stored.
+    ///
+    /// # Returns
+    /// - `i32`: Status code indicating success or failure.
+    pub get_accounts_batch: Option<
+        extern "C" fn(
+            *mut db_t,
+            U8SliceView,          // addresses
+            *mut UnmanagedVector, // result output
+            *mut UnmanagedVector, // error message output
+        ) -> i32,
+    >,
+
+    /// Optionally retrieves many storage slots in one call.
+    ///
+    /// # Parameters
+    /// - `db_t`: A mutable pointer to the database.
+    /// - `U8SliceView`: The `StorageKeys` proto of the slots being retrieved.
+    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the `StorageValues`
+    ///   proto, in the order of the keys, will be stored.
+    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
+    ///   be stored.
+    ///
+    /// # Returns
+    /// - `i32`: Status code indicating success or failure.
+    pub get_storage_batch: Option<
+        extern "C" fn(
+            *mut db_t,
+            U8SliceView,          // storage keys
+            *mut UnmanagedVector, // result output
+            *mut UnmanagedVector, // error message output
+        ) -> i32,
+    >,
+
+    /// Optionally retrieves many codes by their hashes in one call.
+    ///
+    /// # Parameters
+    /// - `db_t`: A mutable pointer to the database.
+    /// - `U8SliceView`: The `CodeHashes` proto of the codes being retrieved.
+    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where the `Codes` proto, in
+    ///   the order of the hashes, will be stored.
+    /// - `UnmanagedVector`: A mutable pointer to an unmanaged vector where any error message will
+    ///   be stored.
+    ///
+    /// # Returns
+    /// - `i32`: Status code indicating success or failure.
+    pub get_codes_batch: Option<
+        extern "C" fn(
+            *mut db_t,
+            U8SliceView,          // code hashes
+            *mut UnmanagedVector, // result output
+            *mut UnmanagedVector, // error message output
+        ) -> i32,
+    >,
 }
 
 #[repr(C)]
+#[derive(Clone, Copy)]
 pub struct Db {
     pub state: *mut db_t,
     pub vtable: Db_vtable,
@@ -138,6 +206,9 @@ impl Default for Db {
             get_code_by_hash: default_read_db,
             get_storage: default_read_db2,
             get_block_hash: default_read_db3,
+            get_accounts_batch: None,
+            get_storage_batch: None,
+            get_codes_batch: None,
         };
 
         Db { state: _state, vtable: _vtable }
@@ -150,17 +221,18 @@ extern "C" fn default_write_db(
     _: U8SliceView,
     _: U8SliceView,
     _: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
 ) -> i32 {
-    panic!("Default write_db called");
+    no_db(error_msg)
 }
 
 extern "C" fn default_read_db(
     _: *mut db_t,
     _: U8SliceView,
     _: *mut UnmanagedVector,
-    _: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
 ) -> i32 {
-    panic!("Default read_db called");
+    no_db(error_msg)
 }
 
 extern "C" fn default_read_db2(
@@ -168,16 +240,25 @@ extern "C" fn default_read_db2(
     _: U8SliceView,
     _: U8SliceView,
     _: *mut UnmanagedVector,
-    _: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
 ) -> i32 {
-    panic!("Default read_db called");
+    no_db(error_msg)
 }
 
 extern "C" fn default_read_db3(
     _: *mut db_t,
     _: u64,
     _: *mut UnmanagedVector,
-    _: *mut UnmanagedVector,
+    error_msg: *mut UnmanagedVector,
 ) -> i32 {
-    panic!("Default read_db called");
+    no_db(error_msg)
+}
+
+/// Fails the callback of the default db. Panicking instead would abort the process, since these
+/// functions cannot unwind.
+fn no_db(error_msg: *mut UnmanagedVector) -> i32 {
+    if let Some(error_msg) = unsafe { error_msg.as_mut() } {
+        *error_msg = UnmanagedVector::new(Some(b"No state db given".to_vec()));
+    }
+    GoError::Other as i32
 }
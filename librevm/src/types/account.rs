@@ -10,11 +10,17 @@ impl TryFrom<UnmanagedVector> for AccountInfo {
     fn try_from(value: UnmanagedVector) -> Result<Self, Self::Error> {
         let account_bytes = value.consume().unwrap();
         let account = Account::decode(account_bytes.as_slice()).unwrap();
-        Ok(AccountInfo {
+        Ok(account.into())
+    }
+}
+
+impl From<Account> for AccountInfo {
+    fn from(account: Account) -> Self {
+        AccountInfo {
             balance: U256::from_be_slice(&account.balance),
             nonce: account.nonce,
             code_hash: B256::from_slice(&account.code_hash),
             code: None,
-        })
+        }
     }
 }
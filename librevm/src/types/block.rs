@@ -57,7 +57,11 @@ impl TryFrom<ByteSliceView> for BlockEnv {
     type Error = DecodeError;
 
     fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
-        let block_bytes = value.read().unwrap();
-        Ok(BlockEnv::try_from(BlockProto::from(Block::decode(block_bytes).unwrap())).unwrap())
+        let block_bytes = value.read().ok_or_else(|| DecodeError::new("nil block"))?;
+        let block = Block::decode(block_bytes)?;
+        if block.coinbase.len() != 20 || block.prevrandao.len() != 32 {
+            return Err(DecodeError::new("invalid coinbase or prevrandao length"));
+        }
+        BlockEnv::try_from(BlockProto::from(block)).map_err(|_| DecodeError::new("invalid block"))
     }
 }
@@ -0,0 +1,15 @@
+use prost::{DecodeError, Message};
+
+use crate::{memory::ByteSliceView, v1::types::ChainConfig};
+
+impl TryFrom<ByteSliceView> for ChainConfig {
+    type Error = DecodeError;
+
+    fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
+        // nil config keeps the defaults of revm, for chain id 1
+        match value.read() {
+            Some(config_bytes) => ChainConfig::decode(config_bytes),
+            None => Ok(ChainConfig::default()),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use prost::{DecodeError, Message};
+
+use crate::{memory::ByteSliceView, v1::types::CompilerConfig};
+
+impl TryFrom<ByteSliceView> for CompilerConfig {
+    type Error = DecodeError;
+
+    fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
+        // nil config compiles every contract with the default options
+        match value.read() {
+            Some(config_bytes) => CompilerConfig::decode(config_bytes),
+            None => Ok(CompilerConfig::default()),
+        }
+    }
+}
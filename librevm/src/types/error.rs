@@ -0,0 +1,137 @@
+use revm::primitives::{EVMError, InvalidHeader, InvalidTransaction};
+
+use crate::{
+    error::{error_message, BackendError},
+    v1::types::{
+        evm_result::Result as ResultType, BackendErrorKind, Error, ErrorKind, EvmResult,
+        InvalidHeaderReason, InvalidTransactionReason,
+    },
+};
+
+impl From<EVMError<BackendError>> for Error {
+    fn from(err: EVMError<BackendError>) -> Self {
+        let mut error = Error::default();
+        match &err {
+            EVMError::Transaction(invalid) => {
+                error.kind = ErrorKind::InvalidTransaction.into();
+                error.transaction = transaction_reason(invalid).into();
+                match invalid {
+                    InvalidTransaction::LackOfFundForMaxFee { fee, balance } => {
+                        error.fee = fee.to_be_bytes_vec();
+                        error.balance = balance.to_be_bytes_vec();
+                    }
+                    InvalidTransaction::NonceTooHigh { tx, state }
+                    | InvalidTransaction::NonceTooLow { tx, state } => {
+                        error.tx_nonce = *tx;
+                        error.state_nonce = *state;
+                    }
+                    InvalidTransaction::TooManyBlobs { max, have } => {
+                        error.max_blobs = *max as u64;
+                        error.have_blobs = *have as u64;
+                    }
+                    _ => {}
+                }
+            }
+            EVMError::Header(invalid) => {
+                error.kind = ErrorKind::InvalidHeader.into();
+                error.header = match invalid {
+                    InvalidHeader::PrevrandaoNotSet => InvalidHeaderReason::PrevrandaoNotSet,
+                    InvalidHeader::ExcessBlobGasNotSet => InvalidHeaderReason::ExcessBlobGasNotSet,
+                }
+                .into();
+            }
+            EVMError::Database(err) => {
+                error.kind = ErrorKind::Database.into();
+                error.database = backend_error_kind(err).into();
+            }
+            EVMError::Custom(_) => error.kind = ErrorKind::Custom.into(),
+            EVMError::Precompile(_) => error.kind = ErrorKind::Precompile.into(),
+        }
+        error.message = String::from_utf8_lossy(&error_message(err)).into_owned();
+        error
+    }
+}
+
+impl From<EVMError<BackendError>> for EvmResult {
+    fn from(err: EVMError<BackendError>) -> Self {
+        EvmResult {
+            result: Some(ResultType::Error(err.into())),
+            receipt: None,
+            commit: None,
+            changeset: None,
+        }
+    }
+}
+
+fn transaction_reason(invalid: &InvalidTransaction) -> InvalidTransactionReason {
+    use InvalidTransactionReason as Reason;
+
+    match invalid {
+        InvalidTransaction::PriorityFeeGreaterThanMaxFee => Reason::PriorityFeeGreaterThanMaxFee,
+        InvalidTransaction::GasPriceLessThanBasefee => Reason::GasPriceLessThanBasefee,
+        InvalidTransaction::CallerGasLimitMoreThanBlock => Reason::CallerGasLimitMoreThanBlock,
+        InvalidTransaction::CallGasCostMoreThanGasLimit => Reason::CallGasCostMoreThanGasLimit,
+        InvalidTransaction::RejectCallerWithCode => Reason::RejectCallerWithCode,
+        InvalidTransaction::LackOfFundForMaxFee { .. } => Reason::LackOfFundForMaxFee,
+        InvalidTransaction::OverflowPaymentInTransaction => Reason::OverflowPaymentInTransaction,
+        InvalidTransaction::NonceOverflowInTransaction => Reason::NonceOverflowInTransaction,
+        InvalidTransaction::NonceTooHigh { .. } => Reason::NonceTooHigh,
+        InvalidTransaction::NonceTooLow { .. } => Reason::NonceTooLow,
+        InvalidTransaction::CreateInitCodeSizeLimit => Reason::CreateInitCodeSizeLimit,
+        InvalidTransaction::InvalidChainId => Reason::InvalidChainId,
+        InvalidTransaction::AccessListNotSupported => Reason::AccessListNotSupported,
+        InvalidTransaction::MaxFeePerBlobGasNotSupported => Reason::MaxFeePerBlobGasNotSupported,
+        InvalidTransaction::BlobVersionedHashesNotSupported => {
+            Reason::BlobVersionedHashesNotSupported
+        }
+        InvalidTransaction::BlobGasPriceGreaterThanMax => Reason::BlobGasPriceGreaterThanMax,
+        InvalidTransaction::EmptyBlobs => Reason::EmptyBlobs,
+        InvalidTransaction::BlobCreateTransaction => Reason::BlobCreateTransaction,
+        InvalidTransaction::TooManyBlobs { .. } => Reason::TooManyBlobs,
+        InvalidTransaction::BlobVersionNotSupported => Reason::BlobVersionNotSupported,
+        InvalidTransaction::EofCrateShouldHaveToAddress => Reason::EofCreateShouldHaveToAddress,
+        InvalidTransaction::AuthorizationListNotSupported => Reason::AuthorizationListNotSupported,
+        InvalidTransaction::AuthorizationListInvalidFields => {
+            Reason::AuthorizationListInvalidFields
+        }
+        InvalidTransaction::EmptyAuthorizationList => Reason::EmptyAuthorizationList,
+    }
+}
+
+fn backend_error_kind(err: &BackendError) -> BackendErrorKind {
+    match err {
+        BackendError::ForeignPanic {} => BackendErrorKind::ForeignPanic,
+        BackendError::BadArgument {} | BackendError::Decode { .. } => BackendErrorKind::BadArgument,
+        BackendError::InvalidUtf8 {} => BackendErrorKind::InvalidUtf8,
+        BackendError::Unimplemented {} => BackendErrorKind::Unimplemented,
+        BackendError::Unknown { .. } => BackendErrorKind::Unknown,
+        BackendError::UserErr { .. } => BackendErrorKind::User,
+        BackendError::UnreachableCall {} => BackendErrorKind::UnreachableCall,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::U256;
+
+    use super::*;
+
+    #[test]
+    fn keeps_the_fields_of_the_reason() {
+        let err = EVMError::Transaction(InvalidTransaction::LackOfFundForMaxFee {
+            fee: Box::new(U256::from(100)),
+            balance: Box::new(U256::from(7)),
+        });
+        let error = Error::from(err);
+        assert_eq!(error.kind(), ErrorKind::InvalidTransaction);
+        assert_eq!(error.transaction(), InvalidTransactionReason::LackOfFundForMaxFee);
+        assert_eq!(U256::from_be_slice(&error.fee), U256::from(100));
+        assert_eq!(U256::from_be_slice(&error.balance), U256::from(7));
+        assert_eq!(error.message, "Lack of fund for max fee: fee = 100, balance = 7");
+
+        let error = Error::from(EVMError::Database(BackendError::user_err("no such account")));
+        assert_eq!(error.kind(), ErrorKind::Database);
+        assert_eq!(error.database(), BackendErrorKind::User);
+        assert_eq!(error.transaction(), InvalidTransactionReason::Unspecified);
+    }
+}
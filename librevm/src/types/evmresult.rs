@@ -13,12 +13,38 @@ pub trait TryIntoVec {
     fn try_into_vec(self) -> Result<Vec<u8>, Self::Error>;
 }
 
-impl TryIntoVec for ExecutionResult {
+impl<T: Into<EvmResult>> TryIntoVec for T {
     type Error = EncodeError;
 
     fn try_into_vec(self) -> Result<Vec<u8>, Self::Error> {
-        let evm_result = EvmResult {
-            result: match self {
+        let evm_result: EvmResult = self.into();
+        let mut buf = Vec::new();
+        evm_result.encode(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+impl From<alloy_primitives::Log> for Log {
+    fn from(log: alloy_primitives::Log) -> Self {
+        Log {
+            address: log.address.to_vec(),
+            data: Some(LogData {
+                topics: log
+                    .data
+                    .topics()
+                    .iter()
+                    .map(|topic| Topic { value: topic.to_vec() })
+                    .collect(),
+                data: log.data.data.to_vec(),
+            }),
+        }
+    }
+}
+
+impl From<ExecutionResult> for EvmResult {
+    fn from(value: ExecutionResult) -> Self {
+        EvmResult {
+            result: match value {
                 ExecutionResult::Success { reason, gas_used, gas_refunded, logs, output } => {
                     Some(ResultType::Success(Success {
                         reason: match reason {
@@ -35,21 +61,7 @@ impl TryIntoVec for ExecutionResult {
                         },
                         gas_used,
                         gas_refunded,
-                        logs: logs
-                            .into_iter()
-                            .map(|log| Log {
-                                address: log.address.to_vec(),
-                                data: Some(LogData {
-                                    topics: log
-                                        .data
-                                        .topics()
-                                        .iter()
-                                        .map(|topic| Topic { value: topic.to_vec() })
-                                        .collect(),
-                                    data: log.data.data.to_vec(),
-                                }),
-                            })
-                            .collect(),
+                        logs: logs.into_iter().map(Log::from).collect(),
                         output: match output {
                             revm::primitives::Output::Call(bytes) => Some(Output {
                                 output: Some(crate::v1::types::output::Output::Call(Call {
@@ -162,9 +174,9 @@ impl TryIntoVec for ExecutionResult {
                     gas_used,
                 })),
             },
-        };
-        let mut buf = Vec::new();
-        evm_result.encode(&mut buf).unwrap();
-        Ok(buf)
+            receipt: None,
+            commit: None,
+            changeset: None,
+        }
     }
 }
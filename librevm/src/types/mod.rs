@@ -1,8 +1,15 @@
 mod account;
 mod block;
+mod chain;
+mod compiler;
+mod error;
 mod evmresult;
+mod receipt;
 mod state;
+mod trace;
 mod transaction;
 
 pub use evmresult::*;
+pub use receipt::*;
 pub use state::*;
+pub use transaction::TypedTxEnv;
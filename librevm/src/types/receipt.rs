@@ -0,0 +1,140 @@
+use alloy_primitives::{Bloom, Log, U256};
+use alloy_rlp::{BufMut, Encodable, Header};
+use revm::primitives::{Env, ExecutionResult, Output};
+
+use crate::v1::types::{EvmResult, Receipt};
+
+/// The result of a transaction of `tx_type` executed in `env`, with its receipt.
+/// `cumulative_gas_used` includes the gas of the transaction.
+pub fn result_with_receipt(
+    result: ExecutionResult,
+    env: &Env,
+    tx_type: u8,
+    cumulative_gas_used: u64,
+) -> EvmResult {
+    let receipt = receipt(&result, env, tx_type, cumulative_gas_used);
+    EvmResult { receipt: Some(receipt), ..result.into() }
+}
+
+fn receipt(result: &ExecutionResult, env: &Env, tx_type: u8, cumulative_gas_used: u64) -> Receipt {
+    let logs = result.logs();
+    let mut bloom = Bloom::ZERO;
+    for log in logs {
+        bloom.accrue_log(log);
+    }
+    let contract_address = match result {
+        ExecutionResult::Success { output: Output::Create(_, Some(address)), .. } => {
+            address.to_vec()
+        }
+        _ => Vec::new(),
+    };
+    let blob_gas_price = match &env.block.blob_excess_gas_and_price {
+        Some(blob) if !env.tx.blob_hashes.is_empty() => {
+            U256::from(blob.blob_gasprice).to_be_bytes_vec()
+        }
+        _ => Vec::new(),
+    };
+
+    Receipt {
+        tx_type: tx_type.into(),
+        status: result.is_success(),
+        cumulative_gas_used,
+        gas_used: result.gas_used(),
+        logs_bloom: bloom.to_vec(),
+        effective_gas_price: env.effective_gas_price().to_be_bytes_vec(),
+        blob_gas_used: env.tx.get_total_blob_gas(),
+        blob_gas_price,
+        contract_address,
+        encoded: encode_2718(tx_type, result.is_success(), cumulative_gas_used, &bloom, logs),
+    }
+}
+
+/// The EIP-2718 encoding of a receipt: `rlp([status, cumulative gas, bloom, logs])`, prefixed with
+/// the type for typed transactions.
+fn encode_2718(
+    tx_type: u8,
+    status: bool,
+    cumulative_gas_used: u64,
+    bloom: &Bloom,
+    logs: &[Log],
+) -> Vec<u8> {
+    let logs_length: usize = logs.iter().map(log_length).sum();
+    let payload_length = status.length()
+        + cumulative_gas_used.length()
+        + bloom.as_slice().length()
+        + Header { list: true, payload_length: logs_length }.length()
+        + logs_length;
+
+    let mut out = Vec::new();
+    if tx_type != 0 {
+        out.put_u8(tx_type);
+    }
+    Header { list: true, payload_length }.encode(&mut out);
+    status.encode(&mut out);
+    cumulative_gas_used.encode(&mut out);
+    bloom.as_slice().encode(&mut out);
+    Header { list: true, payload_length: logs_length }.encode(&mut out);
+    for log in logs {
+        encode_log(log, &mut out);
+    }
+    out
+}
+
+/// `rlp([address, [topics], data])`
+fn encode_log(log: &Log, out: &mut dyn BufMut) {
+    let (topics_length, payload_length) = log_payload_length(log);
+    Header { list: true, payload_length }.encode(out);
+    log.address.as_slice().encode(out);
+    Header { list: true, payload_length: topics_length }.encode(out);
+    for topic in log.topics() {
+        topic.as_slice().encode(out);
+    }
+    log.data.data.as_ref().encode(out);
+}
+
+fn log_length(log: &Log) -> usize {
+    let (_, payload_length) = log_payload_length(log);
+    Header { list: true, payload_length }.length() + payload_length
+}
+
+/// The length of the topics list and of the whole log, without their headers.
+fn log_payload_length(log: &Log) -> (usize, usize) {
+    let topics_length: usize = log.topics().iter().map(|topic| topic.as_slice().length()).sum();
+    let payload_length = log.address.as_slice().length()
+        + Header { list: true, payload_length: topics_length }.length()
+        + topics_length
+        + log.data.data.as_ref().length();
+    (topics_length, payload_length)
+}
+
+#[cfg(test)]
+mod test {
+    use alloy_primitives::{address, b256, bytes, hex, LogData};
+
+    use super::*;
+
+    #[test]
+    fn encodes_like_the_consensus_receipts() {
+        // a legacy receipt without logs
+        let encoded = encode_2718(0, true, 21_000, &Bloom::ZERO, &[]);
+        let mut expected = hex::decode("f9010801825208b9010000").unwrap();
+        expected.resize(expected.len() + 255, 0);
+        expected.push(0xc0);
+        assert_eq!(encoded, expected);
+
+        // a typed receipt is prefixed with its type
+        let log = Log {
+            address: address!("1000000000000000000000000000000000000001"),
+            data: LogData::new_unchecked(
+                vec![b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")],
+                bytes!("2a"),
+            ),
+        };
+        let mut bloom = Bloom::ZERO;
+        bloom.accrue_log(&log);
+        let encoded = encode_2718(2, false, 50_000, &bloom, &[log.clone()]);
+        assert_eq!(encoded[0], 2);
+        assert_eq!(encoded.len(), 1 + 3 + 1 + 3 + 3 + 256 + 2 + log_length(&log));
+        assert_eq!(log_length(&log), 2 + 21 + 1 + 33 + 1);
+    }
+}
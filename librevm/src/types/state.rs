@@ -1,20 +1,20 @@
 use alloy_primitives::{map::AddressHashMap, Address, U256};
-use prost::{EncodeError, Message};
+use prost::{DecodeError, EncodeError, Message};
 use revm::primitives::{AccountInfo, HashMap};
 
 use crate::{
-    memory::U8SliceView,
-    v1::types::{Account, Accounts, Deleted, Storage, Storages},
+    memory::ByteSliceView,
+    v1::types::{
+        Account, Accounts, CacheConfig, Deleted, StateChangeset, Storage, StorageKeys, Storages,
+    },
 };
 
 // Updated Accounts
 pub type UpdatedAccounts = AddressHashMap<AccountInfo>;
 
-impl TryFrom<UpdatedAccounts> for U8SliceView {
-    type Error = EncodeError;
-
-    fn try_from(value: UpdatedAccounts) -> Result<Self, Self::Error> {
-        let accounts = Accounts {
+impl From<UpdatedAccounts> for Accounts {
+    fn from(value: UpdatedAccounts) -> Self {
+        Accounts {
             accounts: value
                 .into_iter()
                 .map(|(addr, acc)| {
@@ -33,22 +33,28 @@ impl TryFrom<UpdatedAccounts> for U8SliceView {
                     )
                 })
                 .collect(),
-        };
+        }
+    }
+}
+
+impl TryFrom<UpdatedAccounts> for Vec<u8> {
+    type Error = EncodeError;
+
+    fn try_from(value: UpdatedAccounts) -> Result<Self, Self::Error> {
+        let accounts = Accounts::from(value);
         // build proto message
         let mut buf = Vec::new();
-        let _ = accounts.encode(&mut buf);
-        Ok(U8SliceView::new(Some(&buf)))
+        accounts.encode(&mut buf)?;
+        Ok(buf)
     }
 }
 
 // Storages
 pub type UpdatedStorages = AddressHashMap<HashMap<U256, U256>>;
 
-impl TryFrom<UpdatedStorages> for U8SliceView {
-    type Error = EncodeError;
-
-    fn try_from(value: UpdatedStorages) -> Result<Self, Self::Error> {
-        let upated_storages = Storages {
+impl From<UpdatedStorages> for Storages {
+    fn from(value: UpdatedStorages) -> Self {
+        Storages {
             storages: value
                 .into_iter()
                 .map(|(addr, keys)| {
@@ -60,25 +66,81 @@ impl TryFrom<UpdatedStorages> for U8SliceView {
                     (addr_str, Storage { storage })
                 })
                 .collect(),
-        };
+        }
+    }
+}
+
+impl TryFrom<UpdatedStorages> for Vec<u8> {
+    type Error = EncodeError;
+
+    fn try_from(value: UpdatedStorages) -> Result<Self, Self::Error> {
+        let upated_storages = Storages::from(value);
         // build proto message
         let mut buf = Vec::new();
-        upated_storages.encode(&mut buf).unwrap();
-        Ok(U8SliceView::new(Some(&buf)))
+        upated_storages.encode(&mut buf)?;
+        Ok(buf)
     }
 }
 
 // Deleted Account
 pub type DeletedAccounts = Vec<Address>;
 
-impl TryFrom<DeletedAccounts> for U8SliceView {
+impl From<DeletedAccounts> for Deleted {
+    fn from(value: DeletedAccounts) -> Self {
+        Deleted { deleted: value.into_iter().map(|addr| addr.to_vec()).collect() }
+    }
+}
+
+impl TryFrom<DeletedAccounts> for Vec<u8> {
     type Error = EncodeError;
 
     fn try_from(value: DeletedAccounts) -> Result<Self, Self::Error> {
-        let deleted = Deleted { deleted: value.into_iter().map(|addr| addr.to_vec()).collect() };
+        let deleted = Deleted::from(value);
         // build proto message
         let mut buf = Vec::new();
-        deleted.encode(&mut buf).unwrap();
-        Ok(U8SliceView::new(Some(&buf)))
+        deleted.encode(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// The state changes of a transaction, as committed to the state db. The `deleted` accounts are
+/// wiped first, so that the created accounts listed there too start from empty storage.
+#[derive(Debug, Default)]
+pub struct StateChanges {
+    pub storages: UpdatedStorages,
+    pub accounts: UpdatedAccounts,
+    pub deleted: DeletedAccounts,
+}
+
+impl From<StateChanges> for StateChangeset {
+    fn from(changes: StateChanges) -> Self {
+        StateChangeset {
+            storages: Some(changes.storages.into()),
+            accounts: Some(changes.accounts.into()),
+            deleted: Some(changes.deleted.into()),
+        }
+    }
+}
+
+impl TryFrom<ByteSliceView> for CacheConfig {
+    type Error = DecodeError;
+
+    fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
+        // nil config disables the cache
+        match value.read() {
+            Some(config_bytes) => CacheConfig::decode(config_bytes),
+            None => Ok(CacheConfig::default()),
+        }
+    }
+}
+
+impl TryFrom<ByteSliceView> for StorageKeys {
+    type Error = DecodeError;
+
+    fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
+        match value.read() {
+            Some(keys_bytes) => StorageKeys::decode(keys_bytes),
+            None => Ok(StorageKeys::default()),
+        }
     }
 }
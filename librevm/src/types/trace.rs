@@ -0,0 +1,30 @@
+use prost::{DecodeError, Message};
+
+use crate::{
+    memory::ByteSliceView,
+    v1::types::{PrestateTracerConfig, StructLoggerConfig},
+};
+
+impl TryFrom<ByteSliceView> for StructLoggerConfig {
+    type Error = DecodeError;
+
+    fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
+        // nil config means the default struct logger options
+        match value.read() {
+            Some(config_bytes) => StructLoggerConfig::decode(config_bytes),
+            None => Ok(StructLoggerConfig::default()),
+        }
+    }
+}
+
+impl TryFrom<ByteSliceView> for PrestateTracerConfig {
+    type Error = DecodeError;
+
+    fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
+        // nil config means prestate mode without diff
+        match value.read() {
+            Some(config_bytes) => PrestateTracerConfig::decode(config_bytes),
+            None => Ok(PrestateTracerConfig::default()),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use revm::primitives::{
 
 use crate::{
     memory::ByteSliceView,
-    v1::types::{authorization_list, Transaction},
+    v1::types::{self, authorization_list, Transaction, Transactions},
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -34,7 +34,7 @@ impl From<TransactionProto> for TxEnv {
         let transaction = transaction.into_inner();
         let transact_to = Address::from_slice(&transaction.transact_to);
         Self {
-            chain_id: None,
+            chain_id: transaction.chain_id,
             caller: Address::from_slice(&transaction.caller),
             gas_limit: transaction.gas_limit,
             gas_price: U256::from_be_slice(&transaction.gas_price),
@@ -128,7 +128,64 @@ impl From<TransactionProto> for TxEnv {
 impl TryFrom<ByteSliceView> for TxEnv {
     type Error = DecodeError;
     fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
-        let tx_bytes = value.read().unwrap();
-        Ok(TxEnv::from(TransactionProto::from(Transaction::decode(tx_bytes).unwrap())))
+        let tx_bytes = value.read().ok_or_else(|| DecodeError::new("nil transaction"))?;
+        Ok(TxEnv::from(TransactionProto::from(Transaction::decode(tx_bytes)?)))
+    }
+}
+
+/// A transaction with its EIP-2718 type, which the `TxEnv` has no room for.
+#[derive(Clone, Debug)]
+pub struct TypedTxEnv {
+    pub tx_type: u8,
+    pub env: TxEnv,
+}
+
+impl TryFrom<Transaction> for TypedTxEnv {
+    type Error = DecodeError;
+    fn try_from(transaction: Transaction) -> Result<Self, Self::Error> {
+        let tx_type = u8::try_from(transaction.tx_type)
+            .map_err(|_| DecodeError::new("invalid transaction type"))?;
+        Ok(Self { tx_type, env: TxEnv::from(TransactionProto::from(transaction)) })
+    }
+}
+
+impl TryFrom<ByteSliceView> for TypedTxEnv {
+    type Error = DecodeError;
+    fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
+        let tx_bytes = value.read().ok_or_else(|| DecodeError::new("nil transaction"))?;
+        Transaction::decode(tx_bytes)?.try_into()
+    }
+}
+
+impl TryFrom<ByteSliceView> for Vec<TypedTxEnv> {
+    type Error = DecodeError;
+    fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
+        let txs_bytes = value.read().ok_or_else(|| DecodeError::new("nil transactions"))?;
+        Transactions::decode(txs_bytes)?.transactions.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+impl TryFrom<ByteSliceView> for Vec<TxEnv> {
+    type Error = DecodeError;
+    fn try_from(value: ByteSliceView) -> Result<Self, Self::Error> {
+        let txs_bytes = value.read().ok_or_else(|| DecodeError::new("nil transactions"))?;
+        Ok(Transactions::decode(txs_bytes)?
+            .transactions
+            .into_iter()
+            .map(|tx| TxEnv::from(TransactionProto::from(tx)))
+            .collect())
+    }
+}
+
+impl From<AccessListItem> for types::AccessListItem {
+    fn from(item: AccessListItem) -> Self {
+        Self {
+            address: item.address.to_vec(),
+            storage_keys: item
+                .storage_keys
+                .iter()
+                .map(|key| types::StorageKey { value: key.to_vec() })
+                .collect(),
+        }
     }
 }
@@ -0,0 +1,250 @@
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+use revm::{
+    primitives::{EVMError, SpecId},
+    Evm, EvmBuilder,
+};
+
+use crate::{
+    chain::ChainRules,
+    compiler::{register_handler, EXTCompileWorker},
+    error::BackendError,
+    precompiles::PrecompileRegistry,
+    states::{Db, StateDB},
+};
+
+/// Opaque handle of a VM created through the FFI. Handles are never reused, so the handle of a
+/// freed VM stays invalid. Zero is never a handle.
+#[allow(non_camel_case_types)]
+pub type vm_t = u64;
+
+pub struct Vm<EXT> {
+    pub evm: Evm<'static, EXT, StateDB>,
+    /// Workers of the parallel block executor, blocks are executed sequentially when `None`.
+    pub parallel_workers: Option<NonZeroUsize>,
+    /// State db the VM was created over, used instead of the db passed to the entry points.
+    pub db: Option<Db>,
+    /// Custom precompiles called by `evm`, and by the EVMs built for the VM.
+    pub precompiles: PrecompileRegistry,
+    /// Chain config applied to the config of `evm`, and of the EVMs built for the VM.
+    pub chain: ChainRules,
+}
+
+impl<EXT> Vm<EXT> {
+    pub fn new(
+        evm: Evm<'static, EXT, StateDB>,
+        precompiles: PrecompileRegistry,
+        chain: ChainRules,
+    ) -> Self {
+        Vm { evm, parallel_workers: None, db: None, precompiles, chain }
+    }
+
+    /// The state db the VM was created over, if any, otherwise `db`.
+    pub fn db_or(&self, db: Db) -> Db {
+        self.db.unwrap_or(db)
+    }
+}
+
+/// A VM interpreting every contract, calling the custom precompiles of `precompiles`, on the chain
+/// of `chain`.
+pub fn interpreted_vm(
+    spec_id: SpecId,
+    precompiles: PrecompileRegistry,
+    chain: ChainRules,
+) -> Vm<()> {
+    let evm = EvmBuilder::default()
+        .with_db(StateDB::new(Db::default()))
+        .with_spec_id(spec_id)
+        .modify_cfg_env(|cfg| chain.apply(cfg))
+        .append_handler_register_box(precompiles.handler_register())
+        .append_handler_register_box(chain.handler_register())
+        .build();
+    Vm::new(evm, precompiles, chain)
+}
+
+/// A VM running the contracts compiled by `worker` once they are, interpreting them until then.
+pub fn compiled_vm(
+    spec_id: SpecId,
+    worker: EXTCompileWorker,
+    precompiles: PrecompileRegistry,
+    chain: ChainRules,
+) -> Vm<EXTCompileWorker> {
+    let evm = EvmBuilder::default()
+        .with_db(StateDB::new(Db::default()))
+        .with_spec_id(spec_id)
+        .modify_cfg_env(|cfg| chain.apply(cfg))
+        .with_external_context::<EXTCompileWorker>(worker)
+        .append_handler_register(register_handler::<StateDB>)
+        .append_handler_register_box(precompiles.handler_register())
+        .append_handler_register_box(chain.handler_register())
+        .build();
+    Vm::new(evm, precompiles, chain)
+}
+
+/// A VM, tagged with the external context it was created with.
+pub enum AnyVm {
+    Interpreted(Vm<()>),
+    Compiled(Vm<EXTCompileWorker>),
+}
+
+impl AnyVm {
+    /// A new VM of the same spec over the same state db, sharing the compile worker, the custom
+    /// precompiles and the chain config.
+    pub fn fresh(&self) -> AnyVm {
+        match self {
+            AnyVm::Interpreted(vm) => {
+                let (precompiles, chain) = (vm.precompiles.clone(), vm.chain.clone());
+                AnyVm::Interpreted(Vm {
+                    db: vm.db,
+                    ..interpreted_vm(vm.evm.spec_id(), precompiles, chain)
+                })
+            }
+            AnyVm::Compiled(vm) => {
+                let (worker, precompiles, chain) =
+                    (vm.evm.context.external.clone(), vm.precompiles.clone(), vm.chain.clone());
+                AnyVm::Compiled(Vm {
+                    db: vm.db,
+                    ..compiled_vm(vm.evm.spec_id(), worker, precompiles, chain)
+                })
+            }
+        }
+    }
+}
+
+/// A registered VM. Calls on the same VM are serialized by its lock.
+pub struct VmSlot {
+    handle: vm_t,
+    vm: Mutex<AnyVm>,
+}
+
+// SAFETY: the VM is only reached through its lock, and nothing it holds is bound to the thread
+// which created it.
+unsafe impl Send for VmSlot {}
+unsafe impl Sync for VmSlot {}
+
+impl VmSlot {
+    /// Locks the VM for a call. A VM which panicked in a previous call may be left inconsistent
+    /// and is not used again.
+    pub fn lock(&self) -> Result<MutexGuard<'_, AnyVm>, EVMError<BackendError>> {
+        self.vm.lock().map_err(|_| {
+            EVMError::Custom(format!("VM {} panicked in a previous call", self.handle))
+        })
+    }
+}
+
+static VMS: Mutex<BTreeMap<vm_t, Arc<VmSlot>>> = Mutex::new(BTreeMap::new());
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// A handle which was never given out, for a VM or any other object of the FFI.
+pub fn next_handle() -> u64 {
+    NEXT_HANDLE.fetch_add(1, Ordering::Relaxed)
+}
+
+pub fn register(vm: AnyVm) -> vm_t {
+    let handle = next_handle();
+    let slot = Arc::new(VmSlot { handle, vm: Mutex::new(vm) });
+    VMS.lock().unwrap_or_else(|err| err.into_inner()).insert(handle, slot);
+    handle
+}
+
+/// Removes the VM of `handle`. It is dropped once the calls running on it return.
+pub fn unregister(handle: vm_t) -> Result<(), EVMError<BackendError>> {
+    let slot = VMS.lock().unwrap_or_else(|err| err.into_inner()).remove(&handle);
+    match slot {
+        // dropped outside of the registry lock, it may join the compile threads
+        Some(slot) => {
+            drop(slot);
+            Ok(())
+        }
+        None => Err(unknown_handle(handle)),
+    }
+}
+
+pub fn get(handle: vm_t) -> Result<Arc<VmSlot>, EVMError<BackendError>> {
+    let vms = VMS.lock().unwrap_or_else(|err| err.into_inner());
+    vms.get(&handle).cloned().ok_or_else(|| unknown_handle(handle))
+}
+
+fn unknown_handle(handle: vm_t) -> EVMError<BackendError> {
+    EVMError::Custom(format!("Unknown VM handle {handle}, the VM was freed or never created"))
+}
+
+/// Evaluates `$body` with `$vm` bound to the [`Vm`] of the handle, whatever its external context.
+/// Errors are written to `$errmsg` and evaluate to the default value.
+macro_rules! with_vm {
+    ($handle:expr, $errmsg:ident, | $vm:ident | $body:expr) => {
+        match $crate::vm::get($handle) {
+            Ok(slot) => match slot.lock() {
+                Ok(mut vm) => match &mut *vm {
+                    $crate::vm::AnyVm::Interpreted($vm) => $body,
+                    $crate::vm::AnyVm::Compiled($vm) => $body,
+                },
+                Err(err) => {
+                    $crate::error::set_error(err, $errmsg);
+                    Default::default()
+                }
+            },
+            Err(err) => {
+                $crate::error::set_error(err, $errmsg);
+                Default::default()
+            }
+        }
+    };
+}
+
+/// Like [`with_vm`], for the VMs created with the AOT compiler only.
+macro_rules! with_compiled_vm {
+    ($handle:expr, $errmsg:ident, | $vm:ident | $body:expr) => {
+        match $crate::vm::get($handle) {
+            Ok(slot) => match slot.lock() {
+                Ok(mut vm) => match &mut *vm {
+                    $crate::vm::AnyVm::Compiled($vm) => $body,
+                    $crate::vm::AnyVm::Interpreted(_) => {
+                        let msg = format!("VM {} was created without the AOT compiler", $handle);
+                        $crate::error::set_error(revm::primitives::EVMError::Custom(msg), $errmsg);
+                        Default::default()
+                    }
+                },
+                Err(err) => {
+                    $crate::error::set_error(err, $errmsg);
+                    Default::default()
+                }
+            },
+            Err(err) => {
+                $crate::error::set_error(err, $errmsg);
+                Default::default()
+            }
+        }
+    };
+}
+
+pub(crate) use with_compiled_vm;
+pub(crate) use with_vm;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn interpreted() -> AnyVm {
+        AnyVm::Interpreted(interpreted_vm(SpecId::CANCUN, Default::default(), Default::default()))
+    }
+
+    #[test]
+    fn rejects_freed_handles() {
+        let handle = register(interpreted());
+        assert!(matches!(*get(handle).unwrap().lock().unwrap(), AnyVm::Interpreted(_)));
+
+        unregister(handle).unwrap();
+        assert!(get(handle).is_err());
+        assert!(unregister(handle).is_err());
+        // handles are not reused
+        assert_ne!(register(interpreted()), handle);
+    }
+}